@@ -0,0 +1,38 @@
+use crate::core::types::SensorError;
+
+/// Lectura combinada de **temperatura y humedad relativa**.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClimateReading {
+    /// Temperatura en grados Celsius.
+    pub temperature: f32,
+    /// Humedad relativa en porcentaje (0–100 %).
+    pub humidity: f32,
+}
+
+/// Trait para sensores capaces de medir **temperatura y humedad relativa** a la vez.
+///
+/// Complementa a [`Sensor`](crate::core::traits::sensor::Sensor): mientras `read()`
+/// devuelve una salida genérica (normalmente texto formateado), este trait entrega
+/// los valores numéricos para que otros componentes puedan procesarlos, por ejemplo
+/// para calcular métricas psicrométricas derivadas.
+///
+/// # Ejemplo
+/// ```
+/// use lince::core::traits::climate::{ClimateReading, ClimateSensor};
+/// use lince::core::types::SensorError;
+///
+/// struct Fijo;
+///
+/// impl ClimateSensor for Fijo {
+///     fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+///         Ok(ClimateReading { temperature: 24.5, humidity: 60.0 })
+///     }
+/// }
+/// ```
+pub trait ClimateSensor {
+    /// Lee la temperatura y la humedad relativa actuales.
+    ///
+    /// Retorna un [`ClimateReading`] si la lectura fue exitosa,
+    /// o un [`SensorError`] si ocurrió algún problema durante la operación.
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError>;
+}
@@ -5,8 +5,6 @@
 ///
 /// Cualquier estructura que implemente este trait podrá actuar como un canal de salida
 /// de información desde el gateway hacia otros sistemas.
-///
-
 pub trait Communicator {
     /// Envía un mensaje a través del medio de comunicación.
     ///
@@ -1,3 +1,4 @@
+pub mod climate;
 pub mod communicator;
 pub mod sensor;
-pub mod storage;
\ No newline at end of file
+pub mod storage;
@@ -14,16 +14,16 @@ use crate::core::types::{SensorOutput, SensorError};
 ///
 /// # Ejemplo
 /// ```
-/// use crate::core::traits::sensor::Sensor;
-/// use crate::core::types::{SensorOutput, SensorError};
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::core::types::{SensorOutput, SensorError};
 ///
 /// struct DummySensor;
 ///
 /// impl Sensor for DummySensor {
-///     type Output = f32;
+///     type Output = SensorOutput;
 ///
 ///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
-///         Ok(SensorOutput::new("temperatura".into(), 24.5))
+///         Ok(SensorOutput::Float(24.5))
 ///     }
 /// }
 /// ```
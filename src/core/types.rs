@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Representa los **datos producidos por un sensor** en el framework IoT.
 ///
@@ -17,7 +17,7 @@ use serde::Serialize;
 ///
 /// # Ejemplo
 /// ```
-/// use crate::core::types::SensorOutput;
+/// use lince::core::types::SensorOutput;
 ///
 /// let lectura = SensorOutput::Float(23.7);
 ///
@@ -26,7 +26,7 @@ use serde::Serialize;
 ///     _ => println!("Tipo de dato no esperado"),
 /// }
 /// ```
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SensorOutput {
     /// Valor booleano (por ejemplo: presencia detectada o no).
     Bool(bool),
@@ -54,16 +54,17 @@ pub enum SensorOutput {
 /// - `Timeout`: El sensor no respondió dentro del tiempo esperado.
 /// - `InvalidData`: Los datos recibidos son inválidos o corruptos.
 /// - `InitializationError`: El sensor no pudo inicializarse correctamente.
+/// - `DeviceFault`: El sensor entregó un valor de fallo conocido (por ejemplo, el valor de reset).
 ///
 /// # Ejemplo
 /// ```
-/// use crate::core::types::SensorError;
+/// use lince::core::types::SensorError;
 ///
 /// fn leer_sensor() -> Result<f32, SensorError> {
 ///     Err(SensorError::Timeout)
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SensorError {
     /// Error de entrada/salida al acceder al sensor.
     IoError,
@@ -73,4 +74,7 @@ pub enum SensorError {
     InvalidData,
     /// El sensor falló al inicializarse o no está disponible.
     InitializationError,
+    /// El sensor respondió con un valor que coincide con una firma de fallo conocida
+    /// (por ejemplo, el valor de power-on reset del DS18B20).
+    DeviceFault,
 }
@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::SensorError;
+use crate::core::SensorOutput;
+use crate::drivers::adc::AnalogInput;
+
+/// Calibración que convierte una tensión (V) en una magnitud física.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnalogCalibration {
+    /// `valor = slope * voltios + offset`.
+    Linear {
+        /// Unidades de la magnitud por voltio.
+        slope: f32,
+        /// Valor de la magnitud a 0 V.
+        offset: f32,
+    },
+    /// Tabla de puntos `(voltios, valor)` con interpolación lineal entre ellos.
+    ///
+    /// Fuera del rango de la tabla se devuelve el valor del extremo más cercano.
+    Table(Vec<(f32, f32)>),
+}
+
+impl AnalogCalibration {
+    /// Calibración lineal que pasa por dos puntos `(voltios, valor)`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use lince::devices::sensors::analog::AnalogCalibration;
+    ///
+    /// // Sonda capacitiva: 2.8 V en seco (0 %), 1.2 V en agua (100 %)
+    /// let cal = AnalogCalibration::two_point((2.8, 0.0), (1.2, 100.0)).unwrap();
+    /// assert!((cal.apply(2.0) - 50.0).abs() < 1e-3);
+    /// ```
+    ///
+    /// # Retorna
+    /// - `None` si ambos puntos tienen la misma tensión.
+    pub fn two_point(a: (f32, f32), b: (f32, f32)) -> Option<Self> {
+        if a.0 == b.0 {
+            return None;
+        }
+        let slope = (b.1 - a.1) / (b.0 - a.0);
+        Some(AnalogCalibration::Linear { slope, offset: a.1 - slope * a.0 })
+    }
+
+    /// Tabla de calibración a partir de puntos `(voltios, valor)` en cualquier orden.
+    ///
+    /// # Retorna
+    /// - `None` si hay menos de dos puntos o tensiones repetidas.
+    pub fn table(mut points: Vec<(f32, f32)>) -> Option<Self> {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if points.len() < 2 || points.windows(2).any(|w| w[0].0 == w[1].0) {
+            return None;
+        }
+        Some(AnalogCalibration::Table(points))
+    }
+
+    /// Convierte una tensión en el valor calibrado.
+    ///
+    /// Devuelve `NaN` si la tensión es `NaN` o la tabla tiene menos de dos puntos
+    /// (posible si se construye o deserializa sin pasar por [`AnalogCalibration::table`]).
+    pub fn apply(&self, volts: f32) -> f32 {
+        match self {
+            AnalogCalibration::Linear { slope, offset } => slope * volts + offset,
+            AnalogCalibration::Table(points) => {
+                if volts.is_nan() || points.len() < 2 {
+                    return f32::NAN;
+                }
+                let (first, last) = (points[0], points[points.len() - 1]);
+                if volts <= first.0 {
+                    return first.1;
+                }
+                if volts >= last.0 {
+                    return last.1;
+                }
+                let i = points.partition_point(|p| p.0 <= volts).clamp(1, points.len() - 1);
+                let (v0, q0) = points[i - 1];
+                let (v1, q1) = points[i];
+                q0 + (volts - v0) / (v1 - v0) * (q1 - q0)
+            }
+        }
+    }
+
+    /// Comprueba que la calibración sea utilizable.
+    fn is_valid(&self) -> bool {
+        match self {
+            AnalogCalibration::Linear { slope, offset } => slope.is_finite() && offset.is_finite(),
+            AnalogCalibration::Table(points) => {
+                points.len() >= 2 && points.windows(2).all(|w| w[0].0 < w[1].0)
+            }
+        }
+    }
+}
+
+/// AnalogSensor: sensor genérico sobre un canal de ADC.
+///
+/// Lee la tensión de cualquier [`AnalogInput`] (MCP3008, ADS1x15...) y la convierte en
+/// una magnitud física mediante una [`AnalogCalibration`] lineal o por tabla.
+/// Sirve para sondas de humedad de suelo, transductores de presión 0.5–4.5 V,
+/// sensores de nivel, etc.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::analog::{AnalogCalibration, AnalogSensor};
+/// use lince::drivers::ads1x15::{Ads1x15Channel, Ads1x15Driver, Ads1x15Model, DEFAULT_ADDRESS};
+///
+/// let adc = Ads1x15Driver::new(1, DEFAULT_ADDRESS, Ads1x15Model::Ads1115, Ads1x15Channel::Single(0)).unwrap();
+/// let cal = AnalogCalibration::table(vec![(2.8, 0.0), (2.1, 40.0), (1.5, 80.0), (1.2, 100.0)]).unwrap();
+/// let mut suelo = AnalogSensor::new(Box::new(adc), cal, "%").unwrap();
+/// println!("{:?}", suelo.read());
+/// ```
+pub struct AnalogSensor {
+    /// Canal del ADC.
+    adc: Box<dyn AnalogInput>,
+    /// Conversión de voltios a la magnitud física.
+    calibration: AnalogCalibration,
+    /// Unidad de la magnitud (solo informativa).
+    unit: String,
+    /// Número de muestras promediadas por lectura.
+    samples: u8,
+}
+
+impl AnalogSensor {
+    /// Crea un sensor analógico.
+    ///
+    /// # Parámetros
+    /// - `adc`: canal del ADC donde está conectada la sonda.
+    /// - `calibration`: conversión de voltios a la magnitud.
+    /// - `unit`: unidad de la magnitud (por ejemplo `"%"` o `"bar"`).
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si la calibración no es válida.
+    pub fn new(
+        adc: Box<dyn AnalogInput>,
+        calibration: AnalogCalibration,
+        unit: &str,
+    ) -> Result<Self, SensorError> {
+        if !calibration.is_valid() {
+            return Err(SensorError::InitializationError);
+        }
+        Ok(Self { adc, calibration, unit: unit.to_string(), samples: 1 })
+    }
+
+    /// Número de muestras promediadas en cada lectura (mínimo 1).
+    pub fn set_samples(&mut self, samples: u8) {
+        self.samples = samples.max(1);
+    }
+
+    /// Sustituye la calibración.
+    pub fn set_calibration(&mut self, calibration: AnalogCalibration) -> Result<(), SensorError> {
+        if !calibration.is_valid() {
+            return Err(SensorError::InitializationError);
+        }
+        self.calibration = calibration;
+        Ok(())
+    }
+
+    /// Calibración actual.
+    pub fn calibration(&self) -> &AnalogCalibration {
+        &self.calibration
+    }
+
+    /// Unidad de la magnitud.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Lee la tensión promediada (V), útil para obtener los puntos de calibración.
+    pub fn read_voltage(&mut self) -> Result<f32, SensorError> {
+        let mut sum = 0.0;
+        for _ in 0..self.samples {
+            sum += self.adc.read_voltage()?;
+        }
+        Ok(sum / self.samples as f32)
+    }
+
+    /// Lee la magnitud calibrada.
+    pub fn read_value(&mut self) -> Result<f32, SensorError> {
+        let volts = self.read_voltage()?;
+        Ok(self.calibration.apply(volts))
+    }
+}
+
+impl Sensor for AnalogSensor {
+    type Output = SensorOutput;
+
+    /// Lee la magnitud calibrada y la devuelve como `SensorOutput::Float`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Float(self.read_value()?))
+    }
+}
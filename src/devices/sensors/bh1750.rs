@@ -0,0 +1,263 @@
+use std::thread;
+use std::time::Duration;
+
+use embedded_hal::blocking::i2c::{Read, Write};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto (ADDR a GND).
+pub const DEFAULT_ADDRESS: u8 = 0x23;
+/// Dirección I2C alternativa (ADDR a VCC).
+pub const SECONDARY_ADDRESS: u8 = 0x5C;
+
+/// Valor por defecto del registro de tiempo de medida (MTreg).
+pub const DEFAULT_MEASUREMENT_TIME: u8 = 69;
+/// Valor mínimo de MTreg (mayor rango, menor sensibilidad).
+pub const MIN_MEASUREMENT_TIME: u8 = 31;
+/// Valor máximo de MTreg (menor rango, mayor sensibilidad).
+pub const MAX_MEASUREMENT_TIME: u8 = 254;
+
+const POWER_DOWN: u8 = 0x00;
+const POWER_ON: u8 = 0x01;
+const RESET: u8 = 0x07;
+
+/// Cuentas por encima de las cuales la lectura se considera saturada al auto-ajustar.
+const AUTO_RANGE_HIGH: u16 = 60_000;
+/// Cuentas por debajo de las cuales se aumenta la sensibilidad al auto-ajustar.
+const AUTO_RANGE_LOW: u16 = 1_000;
+
+/// Resolución de la medición.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bh1750Resolution {
+    /// 1 lx, 120 ms (con MTreg por defecto).
+    High,
+    /// 0.5 lx, 120 ms.
+    High2,
+    /// 4 lx, 16 ms.
+    Low,
+}
+
+impl Bh1750Resolution {
+    /// Comando de medición única (el sensor se apaga al terminar).
+    fn one_time_command(self) -> u8 {
+        match self {
+            Bh1750Resolution::High => 0x20,
+            Bh1750Resolution::High2 => 0x21,
+            Bh1750Resolution::Low => 0x23,
+        }
+    }
+
+    /// Tiempo máximo de medición con MTreg = 69 (datasheet).
+    fn max_time_ms(self) -> u32 {
+        match self {
+            Bh1750Resolution::High | Bh1750Resolution::High2 => 180,
+            Bh1750Resolution::Low => 24,
+        }
+    }
+}
+
+/// Escalones de sensibilidad recorridos por el auto-ajuste, de más sensible a menos.
+const AUTO_RANGE_STEPS: [(Bh1750Resolution, u8); 3] = [
+    (Bh1750Resolution::High2, MAX_MEASUREMENT_TIME),
+    (Bh1750Resolution::High, DEFAULT_MEASUREMENT_TIME),
+    (Bh1750Resolution::High, MIN_MEASUREMENT_TIME),
+];
+
+/// Convierte cuentas crudas a lux según la resolución y el MTreg.
+///
+/// `lux = cuentas / 1.2 · (69 / MTreg)`, y la mitad en [`Bh1750Resolution::High2`].
+///
+/// # Ejemplo
+/// ```
+/// use lince::devices::sensors::bh1750::{raw_to_lux, Bh1750Resolution};
+///
+/// assert!((raw_to_lux(1200, Bh1750Resolution::High, 69) - 1000.0).abs() < 1e-3);
+/// ```
+pub fn raw_to_lux(raw: u16, resolution: Bh1750Resolution, measurement_time: u8) -> f32 {
+    let mut lux = raw as f32 / 1.2 * (DEFAULT_MEASUREMENT_TIME as f32 / measurement_time as f32);
+    if resolution == Bh1750Resolution::High2 {
+        lux /= 2.0;
+    }
+    lux
+}
+
+/// Implementación del sensor de luz ambiental **ROHM BH1750** sobre I2C.
+///
+/// Cada lectura es una medición única: el sensor se apaga entre mediciones.
+/// El tiempo de medida (MTreg) ajusta la sensibilidad, y el auto-ajuste de rango
+/// recorre desde 0.11 lx de resolución (penumbra) hasta ~120 000 lx (sol directo).
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::bh1750::{Bh1750Sensor, DEFAULT_ADDRESS};
+///
+/// let mut sensor = Bh1750Sensor::new(1, DEFAULT_ADDRESS).unwrap();
+/// sensor.set_auto_range(true);
+/// println!("{:?}", sensor.read().unwrap()); // Float(532.5)
+/// ```
+pub struct Bh1750Sensor<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el sensor.
+    i2c: I2C,
+    /// Dirección I2C del sensor.
+    address: u8,
+    /// Resolución de la medición.
+    resolution: Bh1750Resolution,
+    /// Valor del registro de tiempo de medida.
+    measurement_time: u8,
+    /// Ajusta resolución y MTreg automáticamente según el nivel de luz.
+    auto_range: bool,
+}
+
+impl Bh1750Sensor<I2cDriver> {
+    /// Crea una nueva instancia del sensor en un bus I2C del sistema.
+    ///
+    /// # Parámetros
+    /// - `bus`: número del bus I2C (`1` en la Raspberry Pi).
+    /// - `address`: dirección del sensor ([`DEFAULT_ADDRESS`] o [`SECONDARY_ADDRESS`]).
+    pub fn new(bus: u8, address: u8) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address)
+    }
+}
+
+impl<I2C> Bh1750Sensor<I2C>
+where
+    I2C: Read + Write,
+{
+    /// Crea una nueva instancia del sensor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Enciende y reinicia el sensor. Queda en resolución alta, MTreg 69 y sin auto-ajuste.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el sensor no responde.
+    pub fn with_i2c(i2c: I2C, address: u8) -> Result<Self, SensorError> {
+        let mut sensor = Self {
+            i2c,
+            address,
+            resolution: Bh1750Resolution::High,
+            measurement_time: DEFAULT_MEASUREMENT_TIME,
+            auto_range: false,
+        };
+
+        sensor.command(POWER_ON)?;
+        sensor.command(RESET)?;
+        Ok(sensor)
+    }
+
+    /// Resolución actual.
+    pub fn resolution(&self) -> Bh1750Resolution {
+        self.resolution
+    }
+
+    /// Cambia la resolución de la medición.
+    pub fn set_resolution(&mut self, resolution: Bh1750Resolution) {
+        self.resolution = resolution;
+    }
+
+    /// Valor actual del registro de tiempo de medida.
+    pub fn measurement_time(&self) -> u8 {
+        self.measurement_time
+    }
+
+    /// Ajusta el registro de tiempo de medida (MTreg, 31–254).
+    ///
+    /// Valores mayores aumentan la sensibilidad (útil tras un cristal o difusor) a costa
+    /// de un rango menor y mediciones más largas.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si el valor está fuera de rango.
+    pub fn set_measurement_time(&mut self, measurement_time: u8) -> Result<(), SensorError> {
+        if !(MIN_MEASUREMENT_TIME..=MAX_MEASUREMENT_TIME).contains(&measurement_time) {
+            return Err(SensorError::InitializationError);
+        }
+        self.command(0x40 | (measurement_time >> 5))?;
+        self.command(0x60 | (measurement_time & 0x1F))?;
+        self.measurement_time = measurement_time;
+        Ok(())
+    }
+
+    /// Activa o desactiva el auto-ajuste de rango.
+    pub fn set_auto_range(&mut self, enabled: bool) {
+        self.auto_range = enabled;
+    }
+
+    /// Apaga el sensor (consumo < 1 µA). La siguiente lectura lo enciende de nuevo.
+    pub fn power_down(&mut self) -> Result<(), SensorError> {
+        self.command(POWER_DOWN)
+    }
+
+    /// Devuelve el bus I2C, consumiendo el sensor.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Realiza una medición y devuelve las cuentas crudas.
+    pub fn read_raw(&mut self) -> Result<u16, SensorError> {
+        self.command(self.resolution.one_time_command())?;
+        let wait = self.resolution.max_time_ms() * self.measurement_time as u32
+            / DEFAULT_MEASUREMENT_TIME as u32;
+        thread::sleep(Duration::from_millis(wait as u64));
+
+        let mut buf = [0u8; 2];
+        self.i2c
+            .read(self.address, &mut buf)
+            .map_err(|_| SensorError::IoError)?;
+        Ok(u16::from_be_bytes(buf))
+    }
+
+    /// Mide la iluminancia en lux.
+    ///
+    /// Con auto-ajuste activo, si la lectura queda saturada o con muy pocas cuentas
+    /// se cambia de escalón de sensibilidad y se repite la medición.
+    pub fn read_lux(&mut self) -> Result<f32, SensorError> {
+        let mut raw = self.read_raw()?;
+
+        if self.auto_range {
+            let mut step = AUTO_RANGE_STEPS
+                .iter()
+                .position(|&(r, mt)| r == self.resolution && mt == self.measurement_time)
+                .unwrap_or(1);
+
+            for _ in 0..AUTO_RANGE_STEPS.len() {
+                let next = if raw >= AUTO_RANGE_HIGH && step + 1 < AUTO_RANGE_STEPS.len() {
+                    step + 1
+                } else if raw < AUTO_RANGE_LOW && step > 0 {
+                    step - 1
+                } else {
+                    break;
+                };
+                let (resolution, measurement_time) = AUTO_RANGE_STEPS[next];
+                self.resolution = resolution;
+                self.set_measurement_time(measurement_time)?;
+                raw = self.read_raw()?;
+                step = next;
+            }
+        }
+
+        Ok(raw_to_lux(raw, self.resolution, self.measurement_time))
+    }
+
+    fn command(&mut self, command: u8) -> Result<(), SensorError> {
+        self.i2c
+            .write(self.address, &[command])
+            .map_err(|_| SensorError::IoError)
+    }
+}
+
+impl<I2C> Sensor for Bh1750Sensor<I2C>
+where
+    I2C: Read + Write,
+{
+    /// Tipo de salida: [`SensorOutput`] (iluminancia en lux).
+    type Output = SensorOutput;
+
+    /// Lee la iluminancia en lux como `SensorOutput::Float`.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si falla la comunicación I2C.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Float(self.read_lux()?))
+    }
+}
@@ -0,0 +1,548 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+use crate::core::traits::climate::{ClimateReading, ClimateSensor};
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto (SDO a GND).
+pub const DEFAULT_ADDRESS: u8 = 0x76;
+/// Dirección I2C alternativa (SDO a VDDIO).
+pub const SECONDARY_ADDRESS: u8 = 0x77;
+
+// Registros del BME280/BMP280 (datasheet Bosch, sección 5.3)
+const REG_CALIB_00: u8 = 0x88;
+const REG_CHIP_ID: u8 = 0xD0;
+const REG_RESET: u8 = 0xE0;
+const REG_CALIB_26: u8 = 0xE1;
+const REG_CTRL_HUM: u8 = 0xF2;
+const REG_STATUS: u8 = 0xF3;
+const REG_CTRL_MEAS: u8 = 0xF4;
+const REG_CONFIG: u8 = 0xF5;
+const REG_DATA: u8 = 0xF7;
+
+const CHIP_ID_BME280: u8 = 0x60;
+const CHIP_ID_BMP280: u8 = 0x58;
+const CHIP_ID_BMP280_SAMPLES: [u8; 2] = [0x56, 0x57];
+const RESET_COMMAND: u8 = 0xB6;
+
+const STATUS_MEASURING: u8 = 0x08;
+const STATUS_IM_UPDATE: u8 = 0x01;
+
+/// Tiempo máximo de espera para que el sensor termine una medición o una copia de NVM.
+const BUSY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Variante del chip detectada a partir del registro `chip_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bme280Variant {
+    /// BME280: temperatura, presión y humedad.
+    Bme280,
+    /// BMP280: temperatura y presión (sin humedad).
+    Bmp280,
+}
+
+/// Sobremuestreo de una medición (`osrs_x`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Oversampling {
+    /// Medición desactivada.
+    Skipped = 0,
+    X1 = 1,
+    X2 = 2,
+    X4 = 3,
+    X8 = 4,
+    X16 = 5,
+}
+
+impl Oversampling {
+    /// Número de muestras tomadas por medición.
+    fn samples(self) -> f32 {
+        match self {
+            Oversampling::Skipped => 0.0,
+            Oversampling::X1 => 1.0,
+            Oversampling::X2 => 2.0,
+            Oversampling::X4 => 4.0,
+            Oversampling::X8 => 8.0,
+            Oversampling::X16 => 16.0,
+        }
+    }
+}
+
+/// Coeficiente del filtro IIR (`filter`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    Off = 0,
+    X2 = 1,
+    X4 = 2,
+    X8 = 3,
+    X16 = 4,
+}
+
+/// Tiempo de espera entre mediciones en modo normal (`t_sb`, valores del BME280).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Standby {
+    Ms0_5 = 0,
+    Ms62_5 = 1,
+    Ms125 = 2,
+    Ms250 = 3,
+    Ms500 = 4,
+    Ms1000 = 5,
+    Ms10 = 6,
+    Ms20 = 7,
+}
+
+/// Modo de funcionamiento del sensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bme280Mode {
+    /// Una medición por lectura; el sensor duerme entre lecturas (mínimo consumo).
+    Forced,
+    /// Mediciones continuas separadas por el tiempo de `standby`.
+    Normal,
+}
+
+/// Configuración de medición del BME280/BMP280.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bme280Config {
+    /// Sobremuestreo de temperatura.
+    pub temperature_oversampling: Oversampling,
+    /// Sobremuestreo de presión.
+    pub pressure_oversampling: Oversampling,
+    /// Sobremuestreo de humedad (ignorado en el BMP280).
+    pub humidity_oversampling: Oversampling,
+    /// Filtro IIR aplicado a temperatura y presión.
+    pub filter: Filter,
+    /// Tiempo de espera en modo normal.
+    pub standby: Standby,
+    /// Modo de funcionamiento.
+    pub mode: Bme280Mode,
+}
+
+impl Default for Bme280Config {
+    /// Configuración recomendada por Bosch para monitorización meteorológica:
+    /// sobremuestreo x1, sin filtro y modo forzado.
+    fn default() -> Self {
+        Self {
+            temperature_oversampling: Oversampling::X1,
+            pressure_oversampling: Oversampling::X1,
+            humidity_oversampling: Oversampling::X1,
+            filter: Filter::Off,
+            standby: Standby::Ms1000,
+            mode: Bme280Mode::Forced,
+        }
+    }
+}
+
+impl Bme280Config {
+    /// Valor del registro `ctrl_meas` para el modo indicado (`00` sleep, `01` forced, `11` normal).
+    fn ctrl_meas(&self, mode_bits: u8) -> u8 {
+        (self.temperature_oversampling as u8) << 5 | (self.pressure_oversampling as u8) << 2 | mode_bits
+    }
+
+    /// Valor del registro `config`.
+    fn config(&self) -> u8 {
+        (self.standby as u8) << 5 | (self.filter as u8) << 2
+    }
+
+    /// Tiempo máximo de medición según el datasheet (apéndice B).
+    fn max_measurement_time(&self, variant: Bme280Variant) -> Duration {
+        let mut ms = 1.25 + 2.3 * self.temperature_oversampling.samples();
+        if self.pressure_oversampling != Oversampling::Skipped {
+            ms += 2.3 * self.pressure_oversampling.samples() + 0.575;
+        }
+        if variant == Bme280Variant::Bme280 && self.humidity_oversampling != Oversampling::Skipped {
+            ms += 2.3 * self.humidity_oversampling.samples() + 0.575;
+        }
+        Duration::from_micros((ms * 1000.0) as u64)
+    }
+}
+
+/// Coeficientes de calibración de fábrica almacenados en la NVM del sensor.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Bme280Calibration {
+    pub dig_t1: u16,
+    pub dig_t2: i16,
+    pub dig_t3: i16,
+    pub dig_p1: u16,
+    pub dig_p2: i16,
+    pub dig_p3: i16,
+    pub dig_p4: i16,
+    pub dig_p5: i16,
+    pub dig_p6: i16,
+    pub dig_p7: i16,
+    pub dig_p8: i16,
+    pub dig_p9: i16,
+    pub dig_h1: u8,
+    pub dig_h2: i16,
+    pub dig_h3: u8,
+    pub dig_h4: i16,
+    pub dig_h5: i16,
+    pub dig_h6: i8,
+}
+
+impl Bme280Calibration {
+    /// Decodifica los bloques de calibración `0x88..=0xA1` y `0xE1..=0xE7`.
+    ///
+    /// El segundo bloque solo existe en el BME280; para el BMP280 se pasa `None`.
+    pub fn from_registers(block1: &[u8; 26], block2: Option<&[u8; 7]>) -> Self {
+        let u16_at = |i: usize| u16::from_le_bytes([block1[i], block1[i + 1]]);
+        let i16_at = |i: usize| i16::from_le_bytes([block1[i], block1[i + 1]]);
+
+        let mut calib = Self {
+            dig_t1: u16_at(0),
+            dig_t2: i16_at(2),
+            dig_t3: i16_at(4),
+            dig_p1: u16_at(6),
+            dig_p2: i16_at(8),
+            dig_p3: i16_at(10),
+            dig_p4: i16_at(12),
+            dig_p5: i16_at(14),
+            dig_p6: i16_at(16),
+            dig_p7: i16_at(18),
+            dig_p8: i16_at(20),
+            dig_p9: i16_at(22),
+            dig_h1: block1[25],
+            ..Default::default()
+        };
+
+        if let Some(b) = block2 {
+            calib.dig_h2 = i16::from_le_bytes([b[0], b[1]]);
+            calib.dig_h3 = b[2];
+            // dig_H4 y dig_H5 son valores de 12 bits con signo que comparten el registro 0xE5
+            calib.dig_h4 = ((b[3] as i8 as i16) << 4) | (b[4] & 0x0F) as i16;
+            calib.dig_h5 = ((b[5] as i8 as i16) << 4) | (b[4] >> 4) as i16;
+            calib.dig_h6 = b[6] as i8;
+        }
+
+        calib
+    }
+
+    /// Compensa la temperatura cruda (20 bits).
+    ///
+    /// # Retorna
+    /// `(temperatura en °C, t_fine)`; `t_fine` se usa en la compensación de presión y humedad.
+    pub fn compensate_temperature(&self, adc_t: i32) -> (f64, f64) {
+        let adc_t = adc_t as f64;
+        let t1 = self.dig_t1 as f64;
+        let var1 = (adc_t / 16384.0 - t1 / 1024.0) * self.dig_t2 as f64;
+        let var2 = (adc_t / 131072.0 - t1 / 8192.0).powi(2) * self.dig_t3 as f64;
+        let t_fine = var1 + var2;
+        (t_fine / 5120.0, t_fine)
+    }
+
+    /// Compensa la presión cruda (20 bits) y devuelve el resultado en Pa.
+    pub fn compensate_pressure(&self, adc_p: i32, t_fine: f64) -> f64 {
+        let mut var1 = t_fine / 2.0 - 64000.0;
+        let mut var2 = var1 * var1 * self.dig_p6 as f64 / 32768.0;
+        var2 += var1 * self.dig_p5 as f64 * 2.0;
+        var2 = var2 / 4.0 + self.dig_p4 as f64 * 65536.0;
+        var1 = (self.dig_p3 as f64 * var1 * var1 / 524288.0 + self.dig_p2 as f64 * var1) / 524288.0;
+        var1 = (1.0 + var1 / 32768.0) * self.dig_p1 as f64;
+        if var1 == 0.0 {
+            // Evita la división por cero (calibración inválida)
+            return 0.0;
+        }
+        let mut p = 1048576.0 - adc_p as f64;
+        p = (p - var2 / 4096.0) * 6250.0 / var1;
+        var1 = self.dig_p9 as f64 * p * p / 2147483648.0;
+        var2 = p * self.dig_p8 as f64 / 32768.0;
+        p + (var1 + var2 + self.dig_p7 as f64) / 16.0
+    }
+
+    /// Compensa la humedad cruda (16 bits) y devuelve el resultado en % (0–100).
+    pub fn compensate_humidity(&self, adc_h: i32, t_fine: f64) -> f64 {
+        let mut h = t_fine - 76800.0;
+        h = (adc_h as f64 - (self.dig_h4 as f64 * 64.0 + self.dig_h5 as f64 / 16384.0 * h))
+            * (self.dig_h2 as f64 / 65536.0
+                * (1.0 + self.dig_h6 as f64 / 67108864.0 * h * (1.0 + self.dig_h3 as f64 / 67108864.0 * h)));
+        h *= 1.0 - self.dig_h1 as f64 * h / 524288.0;
+        h.clamp(0.0, 100.0)
+    }
+}
+
+/// Medición compensada del BME280/BMP280.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bme280Measurement {
+    /// Temperatura en °C.
+    pub temperature: f32,
+    /// Presión barométrica en hPa.
+    pub pressure: f32,
+    /// Humedad relativa en %, solo disponible en el BME280.
+    pub humidity: Option<f32>,
+}
+
+/// Implementación del **sensor ambiental BME280/BMP280** (Bosch) sobre I2C.
+///
+/// Mide temperatura, presión barométrica y, en el BME280, humedad relativa.
+/// La variante se detecta automáticamente leyendo el registro `chip_id`,
+/// y los coeficientes de calibración de fábrica se leen durante la inicialización.
+///
+/// # Características
+/// - Temperatura: -40 a 85 °C (±1 °C)
+/// - Presión: 300 a 1100 hPa (±1 hPa)
+/// - Humedad: 0–100 % RH (±3 %), solo BME280
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::bme280::{Bme280Config, Bme280Sensor, DEFAULT_ADDRESS};
+///
+/// let mut sensor = Bme280Sensor::new(1, DEFAULT_ADDRESS, Bme280Config::default()).unwrap();
+/// let lectura = sensor.read().unwrap();
+/// println!("{:?}", lectura); // "Temp: 21.3°C, Hum: 45.2%, Pres: 1013.2 hPa"
+/// ```
+pub struct Bme280Sensor<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el sensor.
+    i2c: I2C,
+    /// Dirección I2C del sensor.
+    address: u8,
+    /// Variante detectada.
+    variant: Bme280Variant,
+    /// Coeficientes de calibración de fábrica.
+    calibration: Bme280Calibration,
+    /// Configuración de medición.
+    config: Bme280Config,
+}
+
+impl Bme280Sensor<I2cDriver> {
+    /// Crea una nueva instancia del sensor en un bus I2C del sistema.
+    ///
+    /// # Parámetros
+    /// - `bus`: número del bus I2C (`1` en la Raspberry Pi).
+    /// - `address`: dirección del sensor ([`DEFAULT_ADDRESS`] o [`SECONDARY_ADDRESS`]).
+    /// - `config`: configuración de sobremuestreo, filtro y modo.
+    ///
+    /// # Retorna
+    /// - `Ok(Self)` si el sensor se detecta y configura correctamente.
+    /// - `Err(SensorError)` si el bus no está disponible o el chip no es un BME280/BMP280.
+    pub fn new(bus: u8, address: u8, config: Bme280Config) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address, config)
+    }
+}
+
+impl<I2C> Bme280Sensor<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Crea una nueva instancia del sensor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// # Flujo
+    /// 1. Lee el `chip_id` y determina la variante.
+    /// 2. Envía un soft-reset y espera a que se copie la NVM.
+    /// 3. Lee los coeficientes de calibración.
+    /// 4. Aplica la configuración.
+    ///
+    /// # Retorna
+    /// - `Ok(Self)` si el sensor se detecta y configura correctamente.
+    /// - `Err(SensorError::InitializationError)` si el `chip_id` no corresponde a un BME280/BMP280.
+    /// - `Err(SensorError::IoError)` si falla la comunicación I2C.
+    pub fn with_i2c(i2c: I2C, address: u8, config: Bme280Config) -> Result<Self, SensorError> {
+        let mut sensor = Self {
+            i2c,
+            address,
+            variant: Bme280Variant::Bme280,
+            calibration: Bme280Calibration::default(),
+            config,
+        };
+
+        sensor.variant = match sensor.read_register(REG_CHIP_ID)? {
+            CHIP_ID_BME280 => Bme280Variant::Bme280,
+            CHIP_ID_BMP280 => Bme280Variant::Bmp280,
+            id if CHIP_ID_BMP280_SAMPLES.contains(&id) => Bme280Variant::Bmp280,
+            id => {
+                eprintln!("[BME280] chip_id desconocido: 0x{:02X}", id);
+                return Err(SensorError::InitializationError);
+            }
+        };
+
+        sensor.write_register(REG_RESET, RESET_COMMAND)?;
+        thread::sleep(Duration::from_millis(2));
+        sensor.wait_until_idle(STATUS_IM_UPDATE)?;
+
+        sensor.calibration = sensor.read_calibration()?;
+        sensor.apply_config()?;
+
+        Ok(sensor)
+    }
+
+    /// Variante del chip detectada.
+    pub fn variant(&self) -> Bme280Variant {
+        self.variant
+    }
+
+    /// Coeficientes de calibración leídos del sensor.
+    pub fn calibration(&self) -> &Bme280Calibration {
+        &self.calibration
+    }
+
+    /// Configuración de medición actual.
+    pub fn config(&self) -> &Bme280Config {
+        &self.config
+    }
+
+    /// Cambia la configuración de medición y la aplica inmediatamente.
+    pub fn set_config(&mut self, config: Bme280Config) -> Result<(), SensorError> {
+        self.config = config;
+        self.apply_config()
+    }
+
+    /// Devuelve el bus I2C, consumiendo el sensor.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Realiza una medición y devuelve los valores compensados.
+    ///
+    /// En modo forzado dispara una conversión y espera a que termine;
+    /// en modo normal lee el último resultado disponible.
+    ///
+    /// # Retorna
+    /// - `Ok(Bme280Measurement)` con temperatura, presión y (si aplica) humedad.
+    /// - `Err(SensorError::Timeout)` si la conversión no termina a tiempo.
+    /// - `Err(SensorError::IoError)` si falla la comunicación I2C.
+    pub fn read_measurement(&mut self) -> Result<Bme280Measurement, SensorError> {
+        if self.config.mode == Bme280Mode::Forced {
+            self.write_register(REG_CTRL_MEAS, self.config.ctrl_meas(0b01))?;
+            thread::sleep(self.config.max_measurement_time(self.variant));
+            self.wait_until_idle(STATUS_MEASURING)?;
+        }
+
+        let mut data = [0u8; 8];
+        let len = match self.variant {
+            Bme280Variant::Bme280 => 8,
+            Bme280Variant::Bmp280 => 6,
+        };
+        self.read_registers(REG_DATA, &mut data[..len])?;
+
+        let adc_p = (data[0] as i32) << 12 | (data[1] as i32) << 4 | (data[2] as i32) >> 4;
+        let adc_t = (data[3] as i32) << 12 | (data[4] as i32) << 4 | (data[5] as i32) >> 4;
+        let adc_h = (data[6] as i32) << 8 | data[7] as i32;
+
+        // 0x80000 es el valor que entrega el sensor cuando la medición está desactivada
+        if adc_t == 0x80000 {
+            return Err(SensorError::InvalidData);
+        }
+
+        let (temperature, t_fine) = self.calibration.compensate_temperature(adc_t);
+        let pressure = if adc_p == 0x80000 {
+            0.0
+        } else {
+            self.calibration.compensate_pressure(adc_p, t_fine) / 100.0
+        };
+        let humidity = match self.variant {
+            Bme280Variant::Bme280 if adc_h != 0x8000 => {
+                Some(self.calibration.compensate_humidity(adc_h, t_fine) as f32)
+            }
+            _ => None,
+        };
+
+        Ok(Bme280Measurement {
+            temperature: temperature as f32,
+            pressure: pressure as f32,
+            humidity,
+        })
+    }
+
+    /// Lee los dos bloques de calibración de la NVM.
+    fn read_calibration(&mut self) -> Result<Bme280Calibration, SensorError> {
+        let mut block1 = [0u8; 26];
+        self.read_registers(REG_CALIB_00, &mut block1)?;
+
+        if self.variant == Bme280Variant::Bme280 {
+            let mut block2 = [0u8; 7];
+            self.read_registers(REG_CALIB_26, &mut block2)?;
+            Ok(Bme280Calibration::from_registers(&block1, Some(&block2)))
+        } else {
+            Ok(Bme280Calibration::from_registers(&block1, None))
+        }
+    }
+
+    /// Escribe la configuración en los registros del sensor.
+    ///
+    /// `ctrl_hum` solo tiene efecto tras escribir `ctrl_meas`, y `config` debe
+    /// escribirse en modo sleep, por eso el orden de escritura importa.
+    fn apply_config(&mut self) -> Result<(), SensorError> {
+        self.write_register(REG_CTRL_MEAS, self.config.ctrl_meas(0b00))?;
+        self.write_register(REG_CONFIG, self.config.config())?;
+        if self.variant == Bme280Variant::Bme280 {
+            self.write_register(REG_CTRL_HUM, self.config.humidity_oversampling as u8)?;
+        }
+        let mode_bits = match self.config.mode {
+            Bme280Mode::Forced => 0b00,
+            Bme280Mode::Normal => 0b11,
+        };
+        self.write_register(REG_CTRL_MEAS, self.config.ctrl_meas(mode_bits))
+    }
+
+    /// Espera a que se limpien los bits indicados del registro `status`.
+    fn wait_until_idle(&mut self, mask: u8) -> Result<(), SensorError> {
+        let start = Instant::now();
+        while self.read_register(REG_STATUS)? & mask != 0 {
+            if start.elapsed() > BUSY_TIMEOUT {
+                return Err(SensorError::Timeout);
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        Ok(())
+    }
+
+    fn read_register(&mut self, register: u8) -> Result<u8, SensorError> {
+        let mut buf = [0u8; 1];
+        self.read_registers(register, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    fn read_registers(&mut self, register: u8, buf: &mut [u8]) -> Result<(), SensorError> {
+        self.i2c
+            .write_read(self.address, &[register], buf)
+            .map_err(|_| SensorError::IoError)
+    }
+
+    fn write_register(&mut self, register: u8, value: u8) -> Result<(), SensorError> {
+        self.i2c
+            .write(self.address, &[register, value])
+            .map_err(|_| SensorError::IoError)
+    }
+}
+
+impl<I2C> ClimateSensor for Bme280Sensor<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Lee temperatura y humedad.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` en un BMP280 (sin sensor de humedad)
+    ///   o si la humedad está desactivada.
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        let m = self.read_measurement()?;
+        let humidity = m.humidity.ok_or(SensorError::InvalidData)?;
+        Ok(ClimateReading { temperature: m.temperature, humidity })
+    }
+}
+
+impl<I2C> Sensor for Bme280Sensor<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Tipo de salida: [`SensorOutput`] (texto con los valores medidos).
+    type Output = SensorOutput;
+
+    /// Lee una medición y la devuelve como texto formateado.
+    ///
+    /// # Retorna
+    /// - `Ok(SensorOutput::Text(...))`, por ejemplo
+    ///   `"Temp: 21.3°C, Hum: 45.2%, Pres: 1013.2 hPa"` (BME280) o
+    ///   `"Temp: 21.3°C, Pres: 1013.2 hPa"` (BMP280).
+    /// - `Err(SensorError)` si ocurre un fallo durante la lectura.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let m = self.read_measurement()?;
+
+        let text = match m.humidity {
+            Some(h) => format!("Temp: {:.1}°C, Hum: {:.1}%, Pres: {:.1} hPa", m.temperature, h, m.pressure),
+            None => format!("Temp: {:.1}°C, Pres: {:.1} hPa", m.temperature, m.pressure),
+        };
+        Ok(SensorOutput::Text(text))
+    }
+}
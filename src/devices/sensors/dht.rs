@@ -55,6 +55,9 @@ impl DhtBase {
 
     /// Espera hasta que el pin alcance el nivel lógico deseado o se agote el timeout.
     ///
+    /// Es genérico sobre cualquier pin de entrada de `embedded-hal`, por lo que
+    /// otros drivers temporizados por pulsos (p. ej. HC-SR04) lo reutilizan.
+    ///
     /// # Parámetros
     /// - `pin`: referencia al pin GPIO.
     /// - `nivel`: `true` para HIGH, `false` para LOW.
@@ -63,7 +66,7 @@ impl DhtBase {
     /// # Retorno
     /// - `true` si el nivel fue alcanzado antes del timeout.
     /// - `false` si ocurrió timeout o error de lectura.
-    pub fn esperar_nivel(pin: &GpioDriver, nivel: bool, timeout_us: u64) -> bool {
+    pub fn esperar_nivel<P: InputPin>(pin: &P, nivel: bool, timeout_us: u64) -> bool {
         let start = Instant::now();
         let timeout = Duration::from_micros(timeout_us);
 
@@ -112,7 +115,7 @@ impl DhtBase {
         }
 
         // === Leer los 40 bits ===
-        for byte_idx in 0..5 {
+        for byte in data.iter_mut() {
             for bit_idx in 0..8 {
                 // Esperar pulso alto
                 if !Self::esperar_nivel(&self.pin, true, 100) {
@@ -128,7 +131,7 @@ impl DhtBase {
                 let dur = start.elapsed();
                 // Pulso >40µs = 1, <40µs = 0
                 if dur.as_micros() > 40 {
-                    data[byte_idx] |= 1 << (7 - bit_idx);
+                    *byte |= 1 << (7 - bit_idx);
                 }
             }
         }
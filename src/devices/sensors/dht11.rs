@@ -1,4 +1,5 @@
 use crate::core::{SensorError, SensorOutput};
+use crate::core::traits::climate::{ClimateReading, ClimateSensor};
 use crate::core::traits::sensor::Sensor;
 use crate::devices::sensors::dht::DhtBase;
 
@@ -12,7 +13,8 @@ use crate::devices::sensors::dht::DhtBase;
 /// en una lectura formateada como texto.
 ///
 /// # Ejemplo
-/// ```
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
 /// use lince::devices::sensors::dht11::Dht11Sensor;
 ///
 /// let mut sensor = Dht11Sensor::new(17).unwrap(); // GPIO17
@@ -36,6 +38,54 @@ impl Dht11Sensor {
     pub fn new(pin: u8) -> Result<Self, SensorError> {
         Ok(Self { base: DhtBase::new(pin)? })
     }
+
+    /// Lee la trama cruda de 40 bits del sensor, **sin validar el checksum**.
+    ///
+    /// Útil para registrar tramas en campo y analizarlas o reproducirlas después.
+    ///
+    /// # Flujo
+    /// 1. Envía la señal de inicio al sensor (`iniciar_secuencia`).
+    /// 2. Lee los 40 bits de respuesta (`leer_bits`).
+    ///
+    /// # Retorna
+    /// - `Ok([u8; 5])` con los 5 bytes recibidos.
+    /// - `Err(SensorError)` si ocurre un fallo de comunicación.
+    pub fn read_frame(&mut self) -> Result<[u8; 5], SensorError> {
+        self.base.iniciar_secuencia()?;
+        self.base.leer_bits()
+    }
+
+    /// Valida y decodifica una trama cruda del DHT11.
+    ///
+    /// # Retorna
+    /// - `Ok(ClimateReading)` con temperatura (°C) y humedad (%).
+    /// - `Err(SensorError::InvalidData)` si el checksum no coincide.
+    pub fn decode_frame(frame: &[u8; 5]) -> Result<ClimateReading, SensorError> {
+        DhtBase::validar_checksum(frame)?;
+
+        let humidity = frame[0];
+        let temperature = frame[2] as i8;
+
+        Ok(ClimateReading {
+            temperature: temperature as f32,
+            humidity: humidity as f32,
+        })
+    }
+}
+
+impl ClimateSensor for Dht11Sensor {
+    /// Lee una muestra del DHT11 como valores numéricos.
+    ///
+    /// Equivale a [`read_frame`](Self::read_frame) seguido de
+    /// [`decode_frame`](Self::decode_frame).
+    ///
+    /// # Retorna
+    /// - `Ok(ClimateReading)` con temperatura (°C) y humedad (%).
+    /// - `Err(SensorError)` si ocurre algún fallo durante la lectura o validación.
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        let frame = self.read_frame()?;
+        Self::decode_frame(&frame)
+    }
 }
 
 impl Sensor for Dht11Sensor {
@@ -44,26 +94,18 @@ impl Sensor for Dht11Sensor {
 
     /// Lee una muestra del sensor DHT11.
     ///
-    /// El proceso realiza la secuencia de inicialización, lectura de bits,
-    /// validación de checksum y conversión de los datos crudos en valores legibles.
-    ///
-    /// # Flujo
-    /// 1. Envía la señal de inicio al sensor (`iniciar_secuencia`).
-    /// 2. Lee los 40 bits de respuesta (`leer_bits`).
-    /// 3. Verifica integridad con `validar_checksum`.
-    /// 4. Interpreta los bytes como humedad y temperatura.
+    /// Realiza la lectura mediante [`ClimateSensor::read_climate`] y
+    /// formatea los valores como texto.
     ///
     /// # Retorna
     /// - `Ok(SensorOutput::Text(...))` con la lectura formateada.
     /// - `Err(SensorError)` si ocurre algún fallo durante la lectura.
     fn read(&mut self) -> Result<Self::Output, SensorError> {
-        self.base.iniciar_secuencia()?;
-        let data = self.base.leer_bits()?;
-        DhtBase::validar_checksum(&data)?;
-
-        let humidity = data[0];
-        let temperature = data[2] as i8;
+        let reading = self.read_climate()?;
 
-        Ok(SensorOutput::Text(format!("Temp: {}°C, Hum: {}%", temperature, humidity)))
+        Ok(SensorOutput::Text(format!(
+            "Temp: {}°C, Hum: {}%",
+            reading.temperature, reading.humidity
+        )))
     }
 }
@@ -1,4 +1,5 @@
 use crate::core::{SensorError, SensorOutput};
+use crate::core::traits::climate::{ClimateReading, ClimateSensor};
 use crate::core::traits::sensor::Sensor;
 use crate::devices::sensors::dht::DhtBase;
 
@@ -17,7 +18,8 @@ use crate::devices::sensors::dht::DhtBase;
 /// - Resolución: 0.1 °C / 0.1 %
 ///
 /// # Ejemplo
-/// ```
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
 /// use lince::devices::sensors::dht22::Dht22Sensor;
 ///
 /// let mut sensor = Dht22Sensor::new(23).unwrap(); // GPIO23
@@ -41,41 +43,77 @@ impl Dht22Sensor {
     pub fn new(pin: u8) -> Result<Self, SensorError> {
         Ok(Self { base: DhtBase::new(pin)? })
     }
-}
 
-impl Sensor for Dht22Sensor {
-    /// Tipo de salida del sensor: [`SensorOutput`] (lectura textual formateada).
-    type Output = SensorOutput;
-
-    /// Lee una muestra de temperatura y humedad desde el DHT22.
+    /// Lee la trama cruda de 40 bits del sensor, **sin validar el checksum**.
     ///
-    /// Este método ejecuta la secuencia de comunicación, verifica la integridad
-    /// de los datos mediante checksum y decodifica los valores físicos.
+    /// Útil para registrar tramas en campo y analizarlas o reproducirlas después.
     ///
     /// # Flujo
-    /// 1. Inicia la secuencia de comunicación (`iniciar_secuencia`).
+    /// 1. Envía la señal de inicio al sensor (`iniciar_secuencia`).
     /// 2. Lee los 40 bits de respuesta (`leer_bits`).
-    /// 3. Valida el checksum (`validar_checksum`).
-    /// 4. Interpreta los 16 bits de humedad y 16 bits de temperatura.
-    /// 5. Aplica corrección de signo si la temperatura es negativa.
     ///
     /// # Retorna
-    /// - `Ok(SensorOutput::Text(...))` con los valores formateados.
-    /// - `Err(SensorError)` si ocurre un fallo durante la lectura o validación.
-    fn read(&mut self) -> Result<Self::Output, SensorError> {
+    /// - `Ok([u8; 5])` con los 5 bytes recibidos.
+    /// - `Err(SensorError)` si ocurre un fallo de comunicación.
+    pub fn read_frame(&mut self) -> Result<[u8; 5], SensorError> {
         self.base.iniciar_secuencia()?;
-        let data = self.base.leer_bits()?;
-        DhtBase::validar_checksum(&data)?;
+        self.base.leer_bits()
+    }
+
+    /// Valida y decodifica una trama cruda del DHT22.
+    ///
+    /// # Retorna
+    /// - `Ok(ClimateReading)` con temperatura (°C) y humedad (%).
+    /// - `Err(SensorError::InvalidData)` si el checksum no coincide.
+    pub fn decode_frame(frame: &[u8; 5]) -> Result<ClimateReading, SensorError> {
+        DhtBase::validar_checksum(frame)?;
 
         // DHT22: 16 bits para humedad y 16 bits para temperatura
-        let humidity = ((data[0] as u16) << 8 | data[1] as u16) as f32 / 10.0;
-        let mut temperature = ((data[2] as u16) << 8 | data[3] as u16) as f32 / 10.0;
+        let humidity = ((frame[0] as u16) << 8 | frame[1] as u16) as f32 / 10.0;
+        let mut temperature = (((frame[2] & 0x7F) as u16) << 8 | frame[3] as u16) as f32 / 10.0;
 
         // Bit de signo: si está activo, la temperatura es negativa
-        if data[2] & 0x80 != 0 {
+        if frame[2] & 0x80 != 0 {
             temperature *= -1.0;
         }
 
-        Ok(SensorOutput::Text(format!("Temp: {:.1}°C, Hum: {:.1}%", temperature, humidity)))
+        Ok(ClimateReading { temperature, humidity })
+    }
+}
+
+impl ClimateSensor for Dht22Sensor {
+    /// Lee una muestra del DHT22 como valores numéricos.
+    ///
+    /// Equivale a [`read_frame`](Self::read_frame) seguido de
+    /// [`decode_frame`](Self::decode_frame).
+    ///
+    /// # Retorna
+    /// - `Ok(ClimateReading)` con temperatura (°C) y humedad (%).
+    /// - `Err(SensorError)` si ocurre algún fallo durante la lectura o validación.
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        let frame = self.read_frame()?;
+        Self::decode_frame(&frame)
+    }
+}
+
+impl Sensor for Dht22Sensor {
+    /// Tipo de salida del sensor: [`SensorOutput`] (lectura textual formateada).
+    type Output = SensorOutput;
+
+    /// Lee una muestra de temperatura y humedad desde el DHT22.
+    ///
+    /// Realiza la lectura mediante [`ClimateSensor::read_climate`] y
+    /// formatea los valores con un decimal.
+    ///
+    /// # Retorna
+    /// - `Ok(SensorOutput::Text(...))` con los valores formateados.
+    /// - `Err(SensorError)` si ocurre un fallo durante la lectura o validación.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let reading = self.read_climate()?;
+
+        Ok(SensorOutput::Text(format!(
+            "Temp: {:.1}°C, Hum: {:.1}%",
+            reading.temperature, reading.humidity
+        )))
     }
 }
@@ -0,0 +1,368 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::devices::sensors::bh1750::Bh1750Sensor;
+use crate::devices::sensors::bme280::{Bme280Config, Bme280Sensor, Bme280Variant};
+use crate::devices::sensors::ds18b20::{self, Ds18b20Sensor};
+use crate::devices::sensors::ds2408::{self, Ds2408Sensor};
+use crate::devices::sensors::ds2413::{self, Ds2413Sensor};
+use crate::devices::sensors::ds2438::{self, Ds2438Sensor};
+use crate::devices::sensors::ina::{InaCalibration, InaModel, InaSensor};
+use crate::devices::sensors::sht::{crc8, ShtModel, ShtSensor};
+use crate::devices::sensors::sysfs::{self, SysfsChannel, SysfsSensor};
+use crate::devices::sensors::tsl2561::Tsl2561Sensor;
+use crate::devices::sensors::w1::W1_DEVICES;
+use crate::drivers::i2c::I2cDriver;
+
+/// Rango de direcciones I2C de 7 bits que se exploran (excluye las reservadas).
+const SCAN_FIRST: u8 = 0x08;
+const SCAN_LAST: u8 = 0x77;
+
+/// Shunt y corriente máxima supuestos al construir un INA detectado: el shunt de 0.1 Ω de
+/// los módulos comerciales habituales y una corriente cuya caída en él quepa en el rango de
+/// shunt del modelo (300 mV de ±320 mV en el INA219, 80 mV de ±81.92 mV en el INA226).
+fn default_ina_calibration(model: InaModel) -> InaCalibration {
+    match model {
+        InaModel::Ina219 => InaCalibration { shunt_ohms: 0.1, max_current: 3.0 },
+        InaModel::Ina226 => InaCalibration { shunt_ohms: 0.1, max_current: 0.8 },
+    }
+}
+
+/// Sensor genérico: el que construye un [`SensorSpec`] y el que usa el pipeline principal.
+pub type BoxedSensor = Box<dyn Sensor<Output = SensorOutput>>;
+
+/// SensorSpec: descripción de un sensor detectado, lista para construirlo.
+///
+/// Contiene exactamente los parámetros que necesita el constructor del sensor
+/// (bus, dirección, modelo o identificador del kernel). [`SensorSpec::build`] lo
+/// instancia con la configuración por defecto.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SensorSpec {
+    /// BME280 o BMP280 (identificado por `chip_id`).
+    Bme280 { bus: u8, address: u8, variant: Bme280Variant },
+    /// Sensirion SHT3x (se construye como SHT31) o SHT4x.
+    Sht { bus: u8, address: u8, model: ShtModel },
+    /// Posible BH1750: no tiene registro de identificación, así que es un candidato sin
+    /// confirmar que se asume por la dirección.
+    Bh1750 { bus: u8, address: u8 },
+    /// INA219 o INA226.
+    Ina { bus: u8, address: u8, model: InaModel },
+    /// TSL2561 (identificado por el registro ID).
+    Tsl2561 { bus: u8, address: u8 },
+    /// DS18B20 gestionado por el driver `w1` del kernel.
+    Ds18b20 { device_id: String },
+    /// DS2413 gestionado por el driver `w1` del kernel.
+    Ds2413 { device_id: String },
+    /// DS2438 gestionado por el driver `w1` del kernel.
+    Ds2438 { device_id: String },
+    /// DS2408 gestionado por el driver `w1` del kernel.
+    Ds2408 { device_id: String },
+    /// Canal IIO del kernel.
+    Sysfs(SysfsChannel),
+}
+
+impl SensorSpec {
+    /// Construye el sensor, abriendo el bus I2C que corresponda.
+    ///
+    /// # Retorna
+    /// - `Err(...)` con el error del constructor si el dispositivo ya no responde.
+    pub fn build(&self) -> Result<BoxedSensor, SensorError> {
+        match self.i2c_location() {
+            Some((bus, _)) => self.build_with_i2c(I2cDriver::new(bus)?),
+            None => match self {
+                SensorSpec::Ds18b20 { device_id } => Ok(Box::new(Ds18b20Sensor::new(device_id)?)),
+                SensorSpec::Ds2413 { device_id } => Ok(Box::new(Ds2413Sensor::new(device_id)?)),
+                SensorSpec::Ds2438 { device_id } => Ok(Box::new(Ds2438Sensor::new(device_id)?)),
+                SensorSpec::Ds2408 { device_id } => Ok(Box::new(Ds2408Sensor::new(device_id)?)),
+                SensorSpec::Sysfs(channel) => Ok(Box::new(SysfsSensor::new(channel.clone()))),
+                _ => unreachable!("los sensores I2C tienen bus y dirección"),
+            },
+        }
+    }
+
+    /// Construye un sensor I2C sobre un bus ya abierto (por ejemplo, un `BusProxy` de
+    /// un `SharedBus` o un canal de un multiplexor).
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si la especificación no es de un sensor I2C.
+    pub fn build_with_i2c<I2C>(&self, i2c: I2C) -> Result<BoxedSensor, SensorError>
+    where
+        I2C: Read + Write + WriteRead + 'static,
+    {
+        match *self {
+            SensorSpec::Bme280 { address, .. } => {
+                Ok(Box::new(Bme280Sensor::with_i2c(i2c, address, Bme280Config::default())?))
+            }
+            SensorSpec::Sht { address, model, .. } => Ok(Box::new(ShtSensor::with_i2c(i2c, address, model)?)),
+            SensorSpec::Bh1750 { address, .. } => Ok(Box::new(Bh1750Sensor::with_i2c(i2c, address)?)),
+            SensorSpec::Ina { address, model, .. } => {
+                Ok(Box::new(InaSensor::with_i2c(i2c, address, model, default_ina_calibration(model))?))
+            }
+            SensorSpec::Tsl2561 { address, .. } => Ok(Box::new(Tsl2561Sensor::with_i2c(i2c, address)?)),
+            _ => Err(SensorError::InitializationError),
+        }
+    }
+
+    /// Bus y dirección, si es un sensor I2C.
+    pub fn i2c_location(&self) -> Option<(u8, u8)> {
+        match *self {
+            SensorSpec::Bme280 { bus, address, .. }
+            | SensorSpec::Sht { bus, address, .. }
+            | SensorSpec::Bh1750 { bus, address }
+            | SensorSpec::Ina { bus, address, .. }
+            | SensorSpec::Tsl2561 { bus, address } => Some((bus, address)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SensorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SensorSpec::Bme280 { variant: Bme280Variant::Bme280, .. } => "BME280",
+            SensorSpec::Bme280 { variant: Bme280Variant::Bmp280, .. } => "BMP280",
+            SensorSpec::Sht { model: ShtModel::Sht40, .. } => "SHT4x",
+            SensorSpec::Sht { .. } => "SHT3x",
+            SensorSpec::Bh1750 { .. } => "BH1750?",
+            SensorSpec::Ina { model: InaModel::Ina219, .. } => "INA219",
+            SensorSpec::Ina { model: InaModel::Ina226, .. } => "INA226",
+            SensorSpec::Tsl2561 { .. } => "TSL2561",
+            SensorSpec::Ds18b20 { device_id } => return write!(f, "DS18B20 ({})", device_id),
+            SensorSpec::Ds2413 { device_id } => return write!(f, "DS2413 ({})", device_id),
+            SensorSpec::Ds2438 { device_id } => return write!(f, "DS2438 ({})", device_id),
+            SensorSpec::Ds2408 { device_id } => return write!(f, "DS2408 ({})", device_id),
+            SensorSpec::Sysfs(channel) => return write!(f, "IIO {}", channel.display_name()),
+        };
+        let (bus, address) = self.i2c_location().expect("sensor I2C");
+        write!(f, "{} (i2c-{}, 0x{:02X})", name, bus, address)
+    }
+}
+
+// --------------------------------------------------------------------
+// I2C
+// --------------------------------------------------------------------
+
+/// Explora un bus I2C y devuelve las direcciones que responden (0x08–0x77).
+///
+/// Cada dirección se sondea con una lectura de un byte, como `i2cdetect -r`. Es inocua
+/// para los sensores soportados, aunque algunos chips ajenos pueden reaccionar a ella.
+pub fn scan_i2c<I2C: Read>(i2c: &mut I2C) -> Vec<u8> {
+    let mut buf = [0u8; 1];
+    (SCAN_FIRST..=SCAN_LAST).filter(|&address| i2c.read(address, &mut buf).is_ok()).collect()
+}
+
+/// Identifica el dispositivo que responde en `address` leyendo sus registros de
+/// identificación.
+///
+/// Solo se sondean los chips cuyas direcciones posibles incluyen `address`:
+///
+/// | Direcciones | Chip | Prueba |
+/// |-------------|------|--------|
+/// | 0x76, 0x77 | BME280 / BMP280 | registro `chip_id` (0xD0); un TCA9548A se descarta y se restaura |
+/// | 0x44, 0x45 | SHT3x / SHT4x | número de serie con CRC válido |
+/// | 0x40–0x4F | INA226 / INA219 | IDs de fabricante y chip; para el INA219, forma del registro de configuración y que no sea un ADS1x15 |
+/// | 0x29, 0x39, 0x49 | TSL2561 | registro ID |
+/// | 0x23, 0x5C | BH1750 (candidato) | en 0x23, que no sea un expansor PCF8574 o MCP23017 |
+///
+/// El BH1750 y el INA219 no tienen registro de identificación, por lo que su detección
+/// es heurística; el BH1750 se informa solo como candidato sin confirmar. Ninguna prueba
+/// modifica el estado de los dispositivos: el BH1750 no se enciende y los expansores se
+/// descartan antes de escribir nada que pudiera cambiar sus salidas.
+///
+/// # Retorna
+/// - `None` si el dispositivo no corresponde a ningún sensor conocido.
+pub fn identify_i2c<I2C>(i2c: &mut I2C, bus: u8, address: u8) -> Option<SensorSpec>
+where
+    I2C: Read + Write + WriteRead,
+{
+    match address {
+        0x76 | 0x77 => probe_bme280(i2c, address).map(|variant| SensorSpec::Bme280 { bus, address, variant }),
+        0x44 | 0x45 => probe_sht(i2c, address)
+            .map(|model| SensorSpec::Sht { bus, address, model })
+            .or_else(|| probe_ina(i2c, address).map(|model| SensorSpec::Ina { bus, address, model })),
+        0x29 | 0x39 => probe_tsl2561(i2c, address).map(|_| SensorSpec::Tsl2561 { bus, address }),
+        0x49 => probe_tsl2561(i2c, address)
+            .map(|_| SensorSpec::Tsl2561 { bus, address })
+            .or_else(|| probe_ina(i2c, address).map(|model| SensorSpec::Ina { bus, address, model })),
+        0x40..=0x4F => probe_ina(i2c, address).map(|model| SensorSpec::Ina { bus, address, model }),
+        0x23 => probe_bh1750(i2c, address).map(|_| SensorSpec::Bh1750 { bus, address }),
+        0x5C => Some(SensorSpec::Bh1750 { bus, address }),
+        _ => None,
+    }
+}
+
+/// Explora un bus I2C e identifica los sensores conocidos.
+///
+/// Las direcciones que responden pero no se identifican se informan por `stderr`.
+pub fn discover_i2c<I2C>(i2c: &mut I2C, bus: u8) -> Vec<SensorSpec>
+where
+    I2C: Read + Write + WriteRead,
+{
+    scan_i2c(i2c)
+        .into_iter()
+        .filter_map(|address| {
+            let spec = identify_i2c(i2c, bus, address);
+            if spec.is_none() {
+                eprintln!("[DESCUBRIMIENTO] Dispositivo no identificado en i2c-{}, 0x{:02X}", bus, address);
+            }
+            spec
+        })
+        .collect()
+}
+
+fn read_u8<I2C: WriteRead>(i2c: &mut I2C, address: u8, reg: u8) -> Option<u8> {
+    let mut buf = [0u8; 1];
+    i2c.write_read(address, &[reg], &mut buf).ok().map(|_| buf[0])
+}
+
+fn read_u16<I2C: WriteRead>(i2c: &mut I2C, address: u8, reg: u8) -> Option<u16> {
+    let mut buf = [0u8; 2];
+    i2c.write_read(address, &[reg], &mut buf).ok().map(|_| u16::from_be_bytes(buf))
+}
+
+/// Lee `chip_id`. En 0x76/0x77 también puede haber un multiplexor TCA9548A, cuyo único
+/// registro (la máscara de canales) toma cualquier byte escrito y se devuelve al leer: si
+/// el `chip_id` leído es el propio 0xD0, es un multiplexor y se restaura su máscara.
+fn probe_bme280<I2C: Read + Write + WriteRead>(i2c: &mut I2C, address: u8) -> Option<Bme280Variant> {
+    let mut control = [0u8; 1];
+    i2c.read(address, &mut control).ok()?;
+    let chip_id = read_u8(i2c, address, 0xD0)?;
+    if chip_id == 0xD0 {
+        eprintln!("[DESCUBRIMIENTO] Posible multiplexor TCA9548A en 0x{:02X}", address);
+        if i2c.write(address, &control).is_err() {
+            eprintln!("[DESCUBRIMIENTO] No se pudo restaurar el multiplexor en 0x{:02X}", address);
+        }
+        return None;
+    }
+    match chip_id {
+        0x60 => Some(Bme280Variant::Bme280),
+        0x56..=0x58 => Some(Bme280Variant::Bmp280),
+        _ => None,
+    }
+}
+
+/// Lee el número de serie (dos palabras con CRC) con el comando de cada familia.
+fn probe_sht<I2C: Read + Write>(i2c: &mut I2C, address: u8) -> Option<ShtModel> {
+    let commands: [(&[u8], ShtModel); 2] = [(&[0x37, 0x80], ShtModel::Sht31), (&[0x89], ShtModel::Sht40)];
+    commands.into_iter().find_map(|(command, model)| {
+        i2c.write(address, command).ok()?;
+        thread::sleep(Duration::from_millis(1));
+        let mut buf = [0u8; 6];
+        i2c.read(address, &mut buf).ok()?;
+        (crc8(&buf[0..2]) == buf[2] && crc8(&buf[3..5]) == buf[5]).then_some(model)
+    })
+}
+
+fn probe_ina<I2C: WriteRead>(i2c: &mut I2C, address: u8) -> Option<InaModel> {
+    if read_u16(i2c, address, 0xFE) == Some(0x5449) && read_u16(i2c, address, 0xFF) == Some(0x2260) {
+        return Some(InaModel::Ina226);
+    }
+    // INA219: el bit de reset se lee siempre a 0 y el bit 14 no está implementado
+    let config = read_u16(i2c, address, 0x00)?;
+    if config == 0 || config & 0xC000 != 0 {
+        return None;
+    }
+    // ADS1x15 (0x48–0x4B): su puntero es de 2 bits, así que 0x04/0x05 repiten la
+    // conversión y la configuración de 0x00/0x01; en el INA219 son corriente y calibración
+    let aliased = read_u16(i2c, address, 0x04)? == config
+        && read_u16(i2c, address, 0x05)? == read_u16(i2c, address, 0x01)?;
+    if aliased {
+        eprintln!("[DESCUBRIMIENTO] Posible ADC ADS1x15 en 0x{:02X}", address);
+        return None;
+    }
+    Some(InaModel::Ina219)
+}
+
+/// Descarta los expansores de GPIO que comparten la dirección 0x23 con el BH1750.
+///
+/// Un PCF8574 no tiene registros: cualquier escritura fija sus salidas, así que se
+/// reconoce solo con lecturas (devuelve el mismo byte del puerto una y otra vez). Solo
+/// entonces se direcciona IODIRA, que en un MCP23017 vale 0xFF tras el reset.
+fn probe_bh1750<I2C: Read + WriteRead>(i2c: &mut I2C, address: u8) -> Option<()> {
+    let mut buf = [0u8; 2];
+    i2c.read(address, &mut buf).ok()?;
+    if buf[0] == buf[1] {
+        eprintln!("[DESCUBRIMIENTO] Posible expansor PCF8574 en 0x{:02X}", address);
+        return None;
+    }
+    if read_u16(i2c, address, 0x00)? == 0xFFFF {
+        eprintln!("[DESCUBRIMIENTO] Posible expansor MCP23017 en 0x{:02X}", address);
+        return None;
+    }
+    Some(())
+}
+
+fn probe_tsl2561<I2C: WriteRead>(i2c: &mut I2C, address: u8) -> Option<()> {
+    matches!(read_u8(i2c, address, 0x8A)? >> 4, 0x1 | 0x5).then_some(())
+}
+
+// --------------------------------------------------------------------
+// 1-Wire e IIO
+// --------------------------------------------------------------------
+
+/// Enumera los dispositivos 1-Wire del kernel bajo `root` (normalmente `"/"`).
+///
+/// Las familias sin sensor en Lince se informan por `stderr`.
+pub fn discover_w1<P: AsRef<Path>>(root: P) -> Vec<SensorSpec> {
+    let mut ids: Vec<String> = fs::read_dir(root.as_ref().join(W1_DEVICES))
+        .map(|entries| entries.flatten().map(|e| e.file_name().to_string_lossy().into_owned()).collect())
+        .unwrap_or_default();
+    ids.sort();
+
+    ids.into_iter()
+        .filter(|id| !id.starts_with("w1_bus_master"))
+        .filter_map(|device_id| {
+            let family = device_id.get(..2).and_then(|f| u8::from_str_radix(f, 16).ok());
+            match family {
+                Some(ds18b20::FAMILY_CODE) => Some(SensorSpec::Ds18b20 { device_id }),
+                Some(ds2413::FAMILY_CODE) => Some(SensorSpec::Ds2413 { device_id }),
+                Some(ds2438::FAMILY_CODE) => Some(SensorSpec::Ds2438 { device_id }),
+                Some(ds2408::FAMILY_CODE) => Some(SensorSpec::Ds2408 { device_id }),
+                _ => {
+                    eprintln!("[DESCUBRIMIENTO] Dispositivo 1-Wire no soportado: {}", device_id);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Enumera los canales IIO del kernel bajo `root` (normalmente `"/"`).
+pub fn discover_iio<P: AsRef<Path>>(root: P) -> Vec<SensorSpec> {
+    sysfs::discover_iio(root).into_iter().map(SensorSpec::Sysfs).collect()
+}
+
+/// Descubre todos los sensores del sistema: los buses I2C indicados, los dispositivos
+/// 1-Wire y los canales IIO.
+///
+/// Los buses I2C que no pueden abrirse se informan por `stderr` y se omiten.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::devices::sensors::discovery::discover_all;
+///
+/// for spec in discover_all(&[1]) {
+///     println!("Encontrado: {}", spec);
+///     let mut sensor = spec.build().unwrap();
+///     println!("  {:?}", sensor.read());
+/// }
+/// ```
+pub fn discover_all(i2c_buses: &[u8]) -> Vec<SensorSpec> {
+    let mut specs = Vec::new();
+    for &bus in i2c_buses {
+        match I2cDriver::new(bus) {
+            Ok(mut i2c) => specs.extend(discover_i2c(&mut i2c, bus)),
+            Err(_) => eprintln!("[DESCUBRIMIENTO] No se pudo abrir i2c-{}", bus),
+        }
+    }
+    specs.extend(discover_w1("/"));
+    specs.extend(discover_iio("/"));
+    specs
+}
@@ -1,6 +1,13 @@
 use crate::core::traits::sensor::Sensor;
 use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 use crate::core::{SensorError, SensorOutput};
+use crate::devices::sensors::w1::W1_DEVICES;
+use crate::drivers::gpio::GpioDriver;
+use crate::drivers::onewire::{crc8, OneWireBus, OpenDrainPin, RomId};
 
 /// Implementación del **sensor DS18B20** para el framework IoT.
 ///
@@ -11,40 +18,131 @@ use crate::core::{SensorError, SensorOutput};
 /// El kernel gestiona la comunicación con el dispositivo, por lo que el framework
 /// solo necesita leer y procesar el contenido del archivo correspondiente.
 ///
+/// Si el overlay `w1-gpio` no está disponible, el sensor puede usar en su lugar un
+/// [`OneWireBus`] por software (ver [`Ds18b20Sensor::on_bus`]); el parámetro `P` es el
+/// pin de ese bus y solo importa en ese caso.
+///
 /// # Características
 /// - Rango: −55 °C a +125 °C  
 /// - Resolución: 12 bits (0.0625 °C)  
 /// - Protocolo: OneWire  
 ///
 /// # Ejemplo
-/// ```
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
 /// use lince::devices::sensors::ds18b20::Ds18b20Sensor;
 ///
 /// let mut sensor = Ds18b20Sensor::new("28-00000abcdef").unwrap();
 /// let lectura = sensor.read().unwrap();
 /// println!("{:?}", lectura);
 /// ```
-pub struct Ds18b20Sensor {
+pub struct Ds18b20Sensor<P = GpioDriver> {
+    /// Origen de las lecturas.
+    source: Ds18b20Source<P>,
+
+    /// Número de reintentos cuando la lectura coincide con una firma de fallo conocida.
+    max_retries: u32,
+}
+
+/// Origen de las lecturas del DS18B20.
+enum Ds18b20Source<P> {
     /// Ruta completa del archivo `w1_slave` donde el kernel expone los datos del sensor.
     ///
     /// Ejemplo: `/sys/bus/w1/devices/28-00000abcdef/w1_slave`
-    device_path: String,
+    Sysfs(String),
+    /// Bus 1-Wire por software, compartido con otros dispositivos, y ROM del sensor.
+    Bus(Arc<Mutex<OneWireBus<P>>>, RomId),
+}
+
+/// Código de familia 1-Wire del DS18B20.
+pub const FAMILY_CODE: u8 = 0x28;
+
+/// Comando CONVERT T.
+const CONVERT_T: u8 = 0x44;
+/// Comando READ SCRATCHPAD.
+const READ_SCRATCHPAD: u8 = 0xBE;
+/// Duración máxima de una conversión a 12 bits.
+const CONVERSION_TIME: Duration = Duration::from_millis(750);
+
+/// Número de reintentos por defecto ante una firma de fallo.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Pausa entre reintentos, suficiente para que el sensor complete una nueva conversión.
+const RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Firmas de fallo conocidas del DS18B20.
+///
+/// Estos valores son técnicamente válidos en el formato del kernel (CRC correcto),
+/// pero en la práctica indican que el sensor no realizó una conversión real.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ds18b20Fault {
+    /// 85.000 °C: valor del scratchpad tras un power-on reset (conversión no completada).
+    PowerOnReset,
+    /// 0.000 °C exactos: scratchpad leído en ceros (alimentación parásita insuficiente).
+    Zero,
+    /// 127.94 °C: lectura con todos los bits en alto (bus flotante o sensor desconectado).
+    Disconnected,
+}
+
+impl Ds18b20Fault {
+    /// Clasifica un valor crudo del kernel (milésimas de grado) como firma de fallo.
+    ///
+    /// # Retorna
+    /// - `Some(Ds18b20Fault)` si el valor coincide con una firma conocida.
+    /// - `None` si el valor se considera una lectura normal.
+    pub fn from_raw(temp_raw: i32) -> Option<Self> {
+        match temp_raw {
+            85_000 => Some(Self::PowerOnReset),
+            0 => Some(Self::Zero),
+            // 0x07FF / 16 = 127.9375 °C; el kernel puede truncar o redondear.
+            127_937 | 127_938 => Some(Self::Disconnected),
+            _ => None,
+        }
+    }
 }
 
 impl Ds18b20Sensor {
     /// Crea una nueva instancia del sensor DS18B20 a partir del identificador del dispositivo.
     ///
+    /// Usa [`DEFAULT_MAX_RETRIES`] reintentos ante firmas de fallo.
+    ///
     /// # Parámetros
     /// - `device_id`: ID único asignado por el bus OneWire (ej. `"28-00000abcdef"`).
     ///
     /// # Retorna
     /// - `Ok(Self)` si la ruta se construyó correctamente.
     /// - `Err(SensorError)` solo en caso de error de inicialización futura.
-     pub fn new(device_id: &str) -> Result<Self, SensorError> {
-        let device_path = format!("/sys/bus/w1/devices/{}/w1_slave", device_id);
+    pub fn new(device_id: &str) -> Result<Self, SensorError> {
+        Self::with_retries(device_id, DEFAULT_MAX_RETRIES)
+    }
+
+    /// Crea una nueva instancia del sensor DS18B20 con un número de reintentos personalizado.
+    ///
+    /// # Parámetros
+    /// - `device_id`: ID único asignado por el bus OneWire (ej. `"28-00000abcdef"`).
+    /// - `max_retries`: reintentos adicionales cuando la lectura es una firma de fallo
+    ///   (`0` desactiva los reintentos).
+    ///
+    /// # Retorna
+    /// - `Ok(Self)` si el dispositivo existe y responde con un formato válido.
+    /// - `Err(SensorError)` si el dispositivo no se encuentra o no puede leerse.
+    pub fn with_retries(device_id: &str, max_retries: u32) -> Result<Self, SensorError> {
+        Self::with_root("/", device_id, max_retries)
+    }
+
+    /// Igual que [`Ds18b20Sensor::with_retries`], pero busca el dispositivo bajo `root`
+    /// en lugar de `/` (útil en pruebas).
+    pub fn with_root<P: AsRef<Path>>(root: P, device_id: &str, max_retries: u32) -> Result<Self, SensorError> {
+        let device_path = root
+            .as_ref()
+            .join(W1_DEVICES)
+            .join(device_id)
+            .join("w1_slave")
+            .to_string_lossy()
+            .into_owned();
         
         // Validar que el archivo existe
-        if !std::path::Path::new(&device_path).exists() {
+        if !Path::new(&device_path).exists() {
             eprintln!(
                 "[DS18B20] Dispositivo no encontrado: {}. \
                 Verifica que OneWire está habilitado y el sensor conectado.",
@@ -68,7 +166,33 @@ impl Ds18b20Sensor {
         
         println!("[DS18B20] Sensor {} inicializado correctamente", device_id);
         
-        Ok(Self { device_path })
+        Ok(Self { source: Ds18b20Source::Sysfs(device_path), max_retries })
+    }
+
+    /// Interpreta los 9 bytes del scratchpad y devuelve la temperatura en °C.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use lince::devices::sensors::ds18b20::Ds18b20Sensor;
+    /// use lince::drivers::onewire::crc8;
+    ///
+    /// let mut sp = [0x91, 0x01, 0x4B, 0x46, 0x7F, 0xFF, 0x0F, 0x10, 0];
+    /// sp[8] = crc8(&sp[..8]);
+    /// assert_eq!(Ds18b20Sensor::parse_scratchpad(&sp).unwrap(), 25.0625);
+    /// ```
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el CRC falla o la temperatura está fuera de rango.
+    /// - `Err(SensorError::DeviceFault)` si el valor coincide con una [`Ds18b20Fault`].
+    pub fn parse_scratchpad(scratchpad: &[u8; 9]) -> Result<f32, SensorError> {
+        if crc8(scratchpad) != 0 {
+            eprintln!("[DS18B20] CRC inválido - datos corruptos");
+            return Err(SensorError::InvalidData);
+        }
+        let raw = i16::from_le_bytes([scratchpad[0], scratchpad[1]]) as i32;
+        // Las firmas de fallo se comprueban en el formato del kernel (milésimas de grado)
+        Self::check_temperature(raw * 1000 / 16)?;
+        Ok(raw as f32 / 16.0)
     }
 
     /// Lee directamente el archivo `w1_slave` y obtiene los datos crudos del sensor.
@@ -76,74 +200,168 @@ impl Ds18b20Sensor {
     /// # Retorna
     /// - `Ok(String)` con el contenido del archivo si la lectura fue exitosa.  
     /// - `Err(SensorError::IoError)` si el archivo no puede leerse.
-    fn read_temp_raw(&self) -> Result<String, SensorError> {
-        fs::read_to_string(&self.device_path)
+    fn read_temp_raw(device_path: &str) -> Result<String, SensorError> {
+        fs::read_to_string(device_path)
             .map_err(|e| {
                 eprintln!("[DS18B20] Error de lectura: {}", e);
                 SensorError::IoError
             })
     }
+
+    /// Interpreta el contenido del archivo `w1_slave` y devuelve la temperatura en °C.
+    ///
+    /// # Retorna
+    /// - `Ok(f32)` con la temperatura si la lectura es válida.
+    /// - `Err(SensorError::DeviceFault)` si el valor coincide con una [`Ds18b20Fault`].
+    /// - `Err(SensorError::InvalidData)` si el CRC falla, falta el marcador `t=`
+    ///   o la temperatura está fuera del rango físico.
+    pub fn parse_w1_slave(data: &str) -> Result<f32, SensorError> {
+        // Verificar CRC (checksum del sensor)
+        if !data.contains("YES") {
+            eprintln!("[DS18B20] CRC inválido - datos corruptos");
+            return Err(SensorError::InvalidData);
+        }
+
+        let eq_pos = data.find("t=").ok_or_else(|| {
+            eprintln!("[DS18B20] No se encontró marcador 't=' en los datos");
+            SensorError::InvalidData
+        })?;
+
+        let temp_raw = data[eq_pos + 2..]
+            .trim()
+            .parse::<i32>()
+            .map_err(|e| {
+                eprintln!("[DS18B20] Error al parsear temperatura: {}", e);
+                SensorError::InvalidData
+            })?;
+
+        Self::check_temperature(temp_raw)
+    }
+
+    /// Valida una temperatura en milésimas de grado: firmas de fallo y rango físico.
+    fn check_temperature(temp_raw: i32) -> Result<f32, SensorError> {
+        // Las firmas de fallo se detectan antes del rango: 127.94 °C también está fuera de él.
+        if let Some(fault) = Ds18b20Fault::from_raw(temp_raw) {
+            eprintln!(
+                "[DS18B20] Firma de fallo detectada ({:?}): {:.2}°C",
+                fault,
+                temp_raw as f32 / 1000.0
+            );
+            return Err(SensorError::DeviceFault);
+        }
+
+        let temp_c = temp_raw as f32 / 1000.0;
+
+        // Validar rango físico del DS18B20 (-55°C a 125°C)
+        if !(-55.0..=125.0).contains(&temp_c) {
+            eprintln!(
+                "[DS18B20] Temperatura fuera de rango: {:.2}°C. \
+                Sensor posiblemente desconectado o defectuoso.",
+                temp_c
+            );
+            return Err(SensorError::InvalidData);
+        }
+
+        Ok(temp_c)
+    }
 }
 
-impl Sensor for Ds18b20Sensor {
+impl<P: OpenDrainPin> Ds18b20Sensor<P> {
+    /// Crea un sensor sobre un bus 1-Wire por software.
+    ///
+    /// El bus se comparte entre todos los sensores conectados al mismo pin; cada lectura
+    /// lo bloquea durante la conversión.
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use lince::devices::sensors::ds18b20::{Ds18b20Sensor, FAMILY_CODE};
+    /// use lince::drivers::onewire::OneWireBus;
+    ///
+    /// let bus = Arc::new(Mutex::new(OneWireBus::new(4).unwrap()));
+    /// let roms = bus.lock().unwrap().search_family(FAMILY_CODE).unwrap();
+    /// let sensores: Vec<_> = roms
+    ///     .into_iter()
+    ///     .map(|rom| Ds18b20Sensor::on_bus(bus.clone(), rom).unwrap())
+    ///     .collect();
+    /// ```
+    ///
+    /// Acepta cualquier [`OpenDrainPin`], de modo que el bus puede simularse en pruebas.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si la ROM no es de un DS18B20 o su CRC es inválido.
+    pub fn on_bus(bus: Arc<Mutex<OneWireBus<P>>>, rom: RomId) -> Result<Self, SensorError> {
+        if rom.family() != FAMILY_CODE || !rom.is_valid() {
+            eprintln!("[DS18B20] La ROM {} no corresponde a un DS18B20", rom);
+            return Err(SensorError::InitializationError);
+        }
+        Ok(Self { source: Ds18b20Source::Bus(bus, rom), max_retries: DEFAULT_MAX_RETRIES })
+    }
+
+    /// Cambia el número de reintentos ante firmas de fallo.
+    pub fn set_max_retries(&mut self, max_retries: u32) {
+        self.max_retries = max_retries;
+    }
+
+    /// Realiza una lectura en °C por el origen configurado.
+    fn read_celsius(&self) -> Result<f32, SensorError> {
+        match &self.source {
+            Ds18b20Source::Sysfs(device_path) => {
+                Ds18b20Sensor::parse_w1_slave(&Ds18b20Sensor::read_temp_raw(device_path)?)
+            }
+            Ds18b20Source::Bus(bus, rom) => {
+                let scratchpad = Self::read_scratchpad(&mut bus.lock().unwrap(), rom)?;
+                Ds18b20Sensor::parse_scratchpad(&scratchpad)
+            }
+        }
+    }
+
+    /// Lanza una conversión en el bus por software y lee el scratchpad.
+    fn read_scratchpad(bus: &mut OneWireBus<P>, rom: &RomId) -> Result<[u8; 9], SensorError> {
+        bus.select(rom)?;
+        bus.write_byte(CONVERT_T);
+        bus.wait_ready(CONVERSION_TIME)?;
+
+        bus.select(rom)?;
+        bus.write_byte(READ_SCRATCHPAD);
+        let mut scratchpad = [0u8; 9];
+        bus.read_bytes(&mut scratchpad);
+        Ok(scratchpad)
+    }
+}
+
+impl<P: OpenDrainPin> Sensor for Ds18b20Sensor<P> {
     /// Tipo de salida: [`SensorOutput`] (valor textual formateado).
     type Output = SensorOutput;
 
     /// Lee la temperatura actual desde el DS18B20.
     ///
     /// # Flujo
-    /// 1. Llama a [`read_temp_raw`] para leer el archivo del kernel.  
-    /// 2. Busca el marcador `"t="` en el texto (donde se encuentra la lectura).  
-    /// 3. Convierte el valor crudo a grados Celsius dividiendo entre 1000.  
+    /// 1. Lee el archivo del kernel o el scratchpad del bus por software.  
+    /// 2. Lo interpreta con [`Ds18b20Sensor::parse_w1_slave`] o [`Ds18b20Sensor::parse_scratchpad`].  
+    /// 3. Si el valor es una firma de fallo, espera y reintenta hasta `max_retries` veces.  
     /// 4. Devuelve el resultado como texto formateado (`"xx.xx °C"`).  
     ///
     /// # Retorna
     /// - `Ok(SensorOutput::Text(...))` si la lectura fue válida.  
+    /// - `Err(SensorError::DeviceFault)` si todos los intentos devolvieron una firma de fallo.  
     /// - `Err(SensorError::InvalidData)` si el formato del archivo no es el esperado.  
     /// - `Err(SensorError::IoError)` si ocurre un problema al leer el archivo.
     fn read(&mut self) -> Result<Self::Output, SensorError> {
-        let data = self.read_temp_raw()?;
-        // Verificar CRC (checksum del sensor)
-        if !data.contains("YES") {
-            eprintln!("[DS18B20] CRC inválido - datos corruptos");
-            return Err(SensorError::InvalidData);
-        }
-
-        if let Some(eq_pos) = data.find("t=") {
-            let temp_str = data[eq_pos + 2..].trim();
-            
-            let temp_raw = temp_str
-                .parse::<i32>()
-                .map_err(|e| {
-                    eprintln!("[DS18B20] Error al parsear temperatura: {}", e);
-                    SensorError::InvalidData
-                })?;
-            
-            let temp_c = temp_raw as f32 / 1000.0;
-            
-            // Validar rango físico del DS18B20 (-55°C a 125°C)
-            if temp_c < -55.0 || temp_c > 125.0 {
-                eprintln!(
-                    "[DS18B20] Temperatura fuera de rango: {:.2}°C. \
-                    Sensor posiblemente desconectado o defectuoso.",
-                    temp_c
-                );
-                return Err(SensorError::InvalidData);
-            }
-            
-            // Detectar valores sospechosos (exactamente 0°C o 85°C suelen ser errores)
-            if temp_c == 0.0 || temp_c == 85.0 {
-                eprintln!(
-                    "[DS18B20] ADVERTENCIA: Temperatura sospechosa {:.2}°C. \
-                    Puede indicar sensor desconectado o en estado de inicialización.",
-                    temp_c
-                );
+        let mut attempt = 0;
+        loop {
+            match self.read_celsius() {
+                Ok(temp_c) => return Ok(SensorOutput::Text(format!("{:.2} °C", temp_c))),
+                Err(SensorError::DeviceFault) if attempt < self.max_retries => {
+                    attempt += 1;
+                    eprintln!(
+                        "[DS18B20] Reintentando lectura ({}/{})...",
+                        attempt, self.max_retries
+                    );
+                    thread::sleep(RETRY_DELAY);
+                }
+                Err(e) => return Err(e),
             }
-
-            Ok(SensorOutput::Text(format!("{:.2} °C", temp_c)))
-        } else {
-            eprintln!("[DS18B20] No se encontró marcador 't=' en los datos");
-            Err(SensorError::InvalidData)
         }
     }
 }
@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::devices::sensors::w1::W1Device;
+
+/// Código de familia 1-Wire del DS2408.
+pub const FAMILY_CODE: u8 = 0x29;
+
+/// Implementación del **DS2408**, E/S de 8 canales (familia 0x29), para el framework IoT.
+///
+/// Cada canal PIO0–PIO7 es un drenador abierto que puede leerse como entrada o activarse
+/// como salida. Usa el driver `w1_ds2408` del kernel. En las máscaras de bits el bit `n`
+/// corresponde a PIOn.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::ds2408::Ds2408Sensor;
+///
+/// let mut io = Ds2408Sensor::new("29-0000002b5f11").unwrap();
+/// io.set_output(3, true).unwrap();   // Activa PIO3
+/// println!("{:?}", io.read());       // Text("PIO7-0: 11110111")
+/// ```
+pub struct Ds2408Sensor {
+    /// Dispositivo en sysfs.
+    device: W1Device,
+}
+
+impl Ds2408Sensor {
+    /// Abre el DS2408 a partir de su identificador (ej. `"29-0000002b5f11"`).
+    pub fn new(device_id: &str) -> Result<Self, SensorError> {
+        Self::with_root("/", device_id)
+    }
+
+    /// Abre el DS2408 bajo otra raíz del sistema de archivos (útil en pruebas).
+    pub fn with_root<P: AsRef<Path>>(root: P, device_id: &str) -> Result<Self, SensorError> {
+        Ok(Self { device: W1Device::open(root, device_id, FAMILY_CODE)? })
+    }
+
+    /// Lee el nivel lógico de los 8 pines.
+    pub fn read_pins(&self) -> Result<u8, SensorError> {
+        self.read_byte("state")
+    }
+
+    /// Lee los latches de salida (`0` = salida activada, pin forzado a nivel bajo).
+    pub fn read_latches(&self) -> Result<u8, SensorError> {
+        self.read_byte("output")
+    }
+
+    /// Lee los flags de actividad (cambios de nivel desde el último borrado).
+    pub fn read_activity(&self) -> Result<u8, SensorError> {
+        self.read_byte("activity")
+    }
+
+    /// Escribe los 8 latches de salida.
+    pub fn write_latches(&mut self, latches: u8) -> Result<(), SensorError> {
+        self.device.write("output", &[latches])
+    }
+
+    /// Activa o desactiva la salida de un canal (0–7) conservando el resto.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el canal no existe.
+    pub fn set_output(&mut self, channel: u8, on: bool) -> Result<(), SensorError> {
+        if channel > 7 {
+            return Err(SensorError::InvalidData);
+        }
+        let latches = self.read_latches()?;
+        let latches = if on { latches & !(1 << channel) } else { latches | (1 << channel) };
+        self.write_latches(latches)
+    }
+
+    fn read_byte(&self, file: &str) -> Result<u8, SensorError> {
+        self.device.read(file)?.first().copied().ok_or(SensorError::InvalidData)
+    }
+}
+
+impl Sensor for Ds2408Sensor {
+    type Output = SensorOutput;
+
+    /// Lee los 8 pines como texto binario, PIO7 a la izquierda: `"PIO7-0: 11110111"`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Text(format!("PIO7-0: {:08b}", self.read_pins()?)))
+    }
+}
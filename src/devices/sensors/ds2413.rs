@@ -0,0 +1,155 @@
+use std::path::Path;
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::devices::sensors::w1::W1Device;
+
+/// Código de familia 1-Wire del DS2413.
+pub const FAMILY_CODE: u8 = 0x3A;
+
+/// Canal del DS2413.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ds2413Channel {
+    /// PIOA
+    A,
+    /// PIOB
+    B,
+}
+
+impl Ds2413Channel {
+    fn bit(self) -> u8 {
+        match self {
+            Ds2413Channel::A => 0,
+            Ds2413Channel::B => 1,
+        }
+    }
+}
+
+/// Estado de los dos canales del DS2413.
+///
+/// `pio_*` es el nivel real del pin; `latch_*` es el estado del transistor de salida
+/// (`true` = abierto, `false` = conduciendo y forzando el pin a nivel bajo).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ds2413State {
+    /// Nivel del pin PIOA.
+    pub pio_a: bool,
+    /// Latch de salida de PIOA.
+    pub latch_a: bool,
+    /// Nivel del pin PIOB.
+    pub pio_b: bool,
+    /// Latch de salida de PIOB.
+    pub latch_b: bool,
+}
+
+impl Ds2413State {
+    /// Decodifica el byte del archivo `state`.
+    ///
+    /// El nibble alto es el complemento del bajo y se usa para validar la lectura.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use lince::devices::sensors::ds2413::Ds2413State;
+    ///
+    /// let s = Ds2413State::from_byte(0x78).unwrap();  // 0111 1000 → nibble bajo 1000
+    /// assert!(!s.pio_a && !s.latch_a && !s.pio_b && s.latch_b);
+    /// assert!(Ds2413State::from_byte(0x00).is_none());
+    /// ```
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        if byte >> 4 != !byte & 0x0F {
+            return None;
+        }
+        Some(Self {
+            pio_a: byte & 0x01 != 0,
+            latch_a: byte & 0x02 != 0,
+            pio_b: byte & 0x04 != 0,
+            latch_b: byte & 0x08 != 0,
+        })
+    }
+
+    /// Indica si la salida del canal está activada (transistor conduciendo).
+    pub fn output_on(&self, channel: Ds2413Channel) -> bool {
+        match channel {
+            Ds2413Channel::A => !self.latch_a,
+            Ds2413Channel::B => !self.latch_b,
+        }
+    }
+}
+
+/// Implementación del **interruptor doble DS2413** (familia 0x3A) para el framework IoT.
+///
+/// Dos canales PIO en drenador abierto que sirven como entradas (contactos, sensores
+/// de puerta) o como salidas (relés, LEDs). Usa el driver `w1_ds2413` del kernel.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::ds2413::{Ds2413Channel, Ds2413Sensor};
+///
+/// let mut sw = Ds2413Sensor::new("3a-0000001c5d7e").unwrap();
+/// sw.set_output(Ds2413Channel::A, true).unwrap();  // Activa el relé de PIOA
+/// println!("{:?}", sw.read());                     // Text("A: BAJO, B: ALTO")
+/// ```
+pub struct Ds2413Sensor {
+    /// Dispositivo en sysfs.
+    device: W1Device,
+}
+
+impl Ds2413Sensor {
+    /// Abre el DS2413 a partir de su identificador (ej. `"3a-0000001c5d7e"`).
+    pub fn new(device_id: &str) -> Result<Self, SensorError> {
+        Self::with_root("/", device_id)
+    }
+
+    /// Abre el DS2413 bajo otra raíz del sistema de archivos (útil en pruebas).
+    pub fn with_root<P: AsRef<Path>>(root: P, device_id: &str) -> Result<Self, SensorError> {
+        Ok(Self { device: W1Device::open(root, device_id, FAMILY_CODE)? })
+    }
+
+    /// Lee el estado de los pines y latches.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el byte no supera la comprobación de complemento.
+    pub fn read_state(&self) -> Result<Ds2413State, SensorError> {
+        let data = self.device.read("state")?;
+        data.first()
+            .and_then(|b| Ds2413State::from_byte(*b))
+            .ok_or(SensorError::InvalidData)
+    }
+
+    /// Activa o desactiva ambas salidas a la vez.
+    ///
+    /// Una salida activada conduce y fuerza su pin a nivel bajo.
+    pub fn set_outputs(&mut self, a_on: bool, b_on: bool) -> Result<(), SensorError> {
+        let mut value = 0xFC;
+        if !a_on {
+            value |= 0x01;
+        }
+        if !b_on {
+            value |= 0x02;
+        }
+        self.device.write("output", &[value])
+    }
+
+    /// Activa o desactiva una salida conservando la otra.
+    pub fn set_output(&mut self, channel: Ds2413Channel, on: bool) -> Result<(), SensorError> {
+        let state = self.read_state()?;
+        let mut outputs = [state.output_on(Ds2413Channel::A), state.output_on(Ds2413Channel::B)];
+        outputs[channel.bit() as usize] = on;
+        self.set_outputs(outputs[0], outputs[1])
+    }
+}
+
+/// Texto del nivel de un pin.
+fn nivel(high: bool) -> &'static str {
+    if high { "ALTO" } else { "BAJO" }
+}
+
+impl Sensor for Ds2413Sensor {
+    type Output = SensorOutput;
+
+    /// Lee el nivel de ambos pines como texto, por ejemplo `"A: ALTO, B: BAJO"`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let state = self.read_state()?;
+        Ok(SensorOutput::Text(format!("A: {}, B: {}", nivel(state.pio_a), nivel(state.pio_b))))
+    }
+}
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::devices::sensors::w1::W1Device;
+
+/// Código de familia 1-Wire del DS2438.
+pub const FAMILY_CODE: u8 = 0x26;
+
+/// Lectura del monitor de batería DS2438.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ds2438Reading {
+    /// Temperatura interna (°C).
+    pub temperature: f32,
+    /// Tensión de alimentación VDD (V), normalmente la de la batería.
+    pub vdd: f32,
+    /// Tensión en la entrada VAD (V).
+    pub vad: f32,
+    /// Corriente por la resistencia de sensado (A), si está configurada.
+    pub current: Option<f32>,
+}
+
+/// Implementación del **monitor de batería DS2438** (familia 0x26) para el framework IoT.
+///
+/// Mide temperatura, la tensión de alimentación (VDD), una tensión auxiliar (VAD) y,
+/// con una resistencia de sensado entre VSENS+ y VSENS−, la corriente de carga o descarga.
+/// Usa el driver `w1_ds2438` del kernel. También se usa en muchos módulos de humedad
+/// 1-Wire, que conectan un HIH-4000 a VAD.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::ds2438::Ds2438Sensor;
+///
+/// let mut bat = Ds2438Sensor::new("26-000001a2b3c4").unwrap();
+/// bat.set_sense_resistor(Some(0.05));
+/// println!("{:?}", bat.read());  // Text("Temp: 21.50 °C, VDD: 3.98 V, VAD: 1.23 V, Corriente: -0.125 A")
+/// ```
+pub struct Ds2438Sensor {
+    /// Dispositivo en sysfs.
+    device: W1Device,
+    /// Resistencia de sensado (Ω) para calcular la corriente.
+    sense_resistor: Option<f32>,
+}
+
+impl Ds2438Sensor {
+    /// Abre el DS2438 a partir de su identificador (ej. `"26-000001a2b3c4"`).
+    pub fn new(device_id: &str) -> Result<Self, SensorError> {
+        Self::with_root("/", device_id)
+    }
+
+    /// Abre el DS2438 bajo otra raíz del sistema de archivos (útil en pruebas).
+    pub fn with_root<P: AsRef<Path>>(root: P, device_id: &str) -> Result<Self, SensorError> {
+        Ok(Self { device: W1Device::open(root, device_id, FAMILY_CODE)?, sense_resistor: None })
+    }
+
+    /// Configura la resistencia de sensado (Ω); `None` desactiva el cálculo de corriente.
+    pub fn set_sense_resistor(&mut self, ohms: Option<f32>) {
+        self.sense_resistor = ohms.filter(|r| *r > 0.0);
+    }
+
+    /// Activa o desactiva la medida de corriente del chip (archivo `iad`).
+    pub fn set_current_measurement(&mut self, enabled: bool) -> Result<(), SensorError> {
+        self.device.write("iad", if enabled { b"1" } else { b"0" })
+    }
+
+    /// Temperatura interna (°C). El kernel la entrega en 1/256 °C.
+    pub fn read_temperature(&self) -> Result<f32, SensorError> {
+        Ok(self.device.read_int("temperature")? as f32 / 256.0)
+    }
+
+    /// Tensión de alimentación (V). El kernel la entrega en unidades de 10 mV.
+    pub fn read_vdd(&self) -> Result<f32, SensorError> {
+        Ok(self.device.read_int("vdd")? as f32 / 100.0)
+    }
+
+    /// Tensión en VAD (V). El kernel la entrega en unidades de 10 mV.
+    pub fn read_vad(&self) -> Result<f32, SensorError> {
+        Ok(self.device.read_int("vad")? as f32 / 100.0)
+    }
+
+    /// Corriente (A) a partir del registro de corriente de la página 0.
+    ///
+    /// El registro es un entero con signo con LSB de `1 / (4096 · R)` A;
+    /// es negativo durante la descarga.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si no hay resistencia de sensado configurada.
+    pub fn read_current(&self) -> Result<f32, SensorError> {
+        let ohms = self.sense_resistor.ok_or(SensorError::InitializationError)?;
+        let page = self.device.read("page0")?;
+        if page.len() < 7 {
+            return Err(SensorError::InvalidData);
+        }
+        let raw = i16::from_le_bytes([page[5], page[6]]);
+        Ok(raw as f32 / (4096.0 * ohms))
+    }
+
+    /// Lee todas las magnitudes.
+    pub fn read_measurement(&self) -> Result<Ds2438Reading, SensorError> {
+        Ok(Ds2438Reading {
+            temperature: self.read_temperature()?,
+            vdd: self.read_vdd()?,
+            vad: self.read_vad()?,
+            current: match self.sense_resistor {
+                Some(_) => Some(self.read_current()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl Sensor for Ds2438Sensor {
+    type Output = SensorOutput;
+
+    /// Lee todas las magnitudes como texto.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let r = self.read_measurement()?;
+        let mut text = format!("Temp: {:.2} °C, VDD: {:.2} V, VAD: {:.2} V", r.temperature, r.vdd, r.vad);
+        if let Some(current) = r.current {
+            text.push_str(&format!(", Corriente: {:.3} A", current));
+        }
+        Ok(SensorOutput::Text(text))
+    }
+}
@@ -0,0 +1,601 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::serial::{SerialDriver, SerialPort};
+
+/// Velocidad por defecto de los receptores NMEA (u-blox NEO-6M, etc.).
+pub const BAUD_RATE: u32 = 9600;
+
+/// Tiempo máximo para completar un ciclo de sentencias (los receptores emiten a 1 Hz).
+const CYCLE_TIMEOUT: Duration = Duration::from_secs(2);
+/// Antigüedad máxima por defecto de la posición compartida (varios ciclos a 1 Hz).
+pub const LOCATION_MAX_AGE: Duration = Duration::from_secs(5);
+/// Longitud máxima de una sentencia NMEA (82 caracteres según el estándar, con margen).
+const MAX_SENTENCE: usize = 128;
+
+/// Calidad del fix según el campo 6 de GGA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixQuality {
+    Invalid,
+    Gps,
+    Dgps,
+    Pps,
+    RtkFixed,
+    RtkFloat,
+    Estimated,
+    Manual,
+    Simulation,
+}
+
+impl FixQuality {
+    fn from_code(code: u8) -> Self {
+        match code {
+            1 => FixQuality::Gps,
+            2 => FixQuality::Dgps,
+            3 => FixQuality::Pps,
+            4 => FixQuality::RtkFixed,
+            5 => FixQuality::RtkFloat,
+            6 => FixQuality::Estimated,
+            7 => FixQuality::Manual,
+            8 => FixQuality::Simulation,
+            _ => FixQuality::Invalid,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            FixQuality::Invalid => "sin fix",
+            FixQuality::Gps => "GPS",
+            FixQuality::Dgps => "DGPS",
+            FixQuality::Pps => "PPS",
+            FixQuality::RtkFixed => "RTK",
+            FixQuality::RtkFloat => "RTK flotante",
+            FixQuality::Estimated => "estimado",
+            FixQuality::Manual => "manual",
+            FixQuality::Simulation => "simulación",
+        }
+    }
+}
+
+/// Tipo de fix según el campo 2 de GSA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixType {
+    NoFix,
+    Fix2D,
+    Fix3D,
+}
+
+/// Hora UTC de una sentencia (`hhmmss.ss`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NmeaTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: f32,
+}
+
+/// Fecha UTC de una sentencia RMC (`ddmmyy`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NmeaDate {
+    pub day: u8,
+    pub month: u8,
+    pub year: u16,
+}
+
+/// Datos de una sentencia GGA (fix del sistema de posicionamiento).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GgaData {
+    pub time: Option<NmeaTime>,
+    /// Latitud en grados decimales (negativa al sur).
+    pub latitude: Option<f64>,
+    /// Longitud en grados decimales (negativa al oeste).
+    pub longitude: Option<f64>,
+    pub fix_quality: FixQuality,
+    /// Satélites usados en la solución.
+    pub satellites: u8,
+    pub hdop: Option<f32>,
+    /// Altitud sobre el nivel medio del mar (m).
+    pub altitude: Option<f32>,
+}
+
+/// Datos de una sentencia RMC (mínimo recomendado).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RmcData {
+    pub time: Option<NmeaTime>,
+    /// `true` si el estado es `A` (datos válidos).
+    pub valid: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Velocidad sobre el suelo (nudos).
+    pub speed_knots: Option<f32>,
+    /// Rumbo verdadero (grados).
+    pub course: Option<f32>,
+    pub date: Option<NmeaDate>,
+}
+
+/// Datos de una sentencia GSA (DOP y satélites activos).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GsaData {
+    pub fix_type: FixType,
+    /// PRN de los satélites usados.
+    pub satellites: Vec<u8>,
+    pub pdop: Option<f32>,
+    pub hdop: Option<f32>,
+    pub vdop: Option<f32>,
+}
+
+/// Sentencia NMEA-0183 decodificada.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NmeaSentence {
+    Gga(GgaData),
+    Rmc(RmcData),
+    Gsa(GsaData),
+    /// Sentencia válida de un tipo no soportado (se guarda el tipo, p. ej. `"GSV"`).
+    Other(String),
+}
+
+/// Calcula el checksum NMEA: XOR de los caracteres entre `$` y `*`.
+///
+/// # Ejemplo
+/// ```
+/// use lince::devices::sensors::gps::nmea_checksum;
+///
+/// assert_eq!(nmea_checksum("GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,"), 0x47);
+/// ```
+pub fn nmea_checksum(body: &str) -> u8 {
+    body.bytes().fold(0, |acc, b| acc ^ b)
+}
+
+/// Decodifica una sentencia NMEA-0183 completa (`$...*HH`), verificando el checksum.
+///
+/// Acepta cualquier emisor (`GP`, `GN`, `GL`, `GA`...).
+///
+/// # Retorno
+/// - `Err(SensorError::InvalidData)` si el formato o el checksum son incorrectos, o si la
+///   línea contiene caracteres no ASCII (bytes corruptos en el puerto serie).
+///
+/// # Ejemplo
+/// ```
+/// use lince::devices::sensors::gps::{parse_sentence, FixQuality, NmeaSentence};
+///
+/// let s = parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47").unwrap();
+/// let NmeaSentence::Gga(gga) = s else { panic!() };
+/// assert!((gga.latitude.unwrap() - 48.1173).abs() < 1e-6);
+/// assert_eq!(gga.fix_quality, FixQuality::Gps);
+/// assert_eq!(gga.altitude, Some(545.4));
+/// ```
+pub fn parse_sentence(line: &str) -> Result<NmeaSentence, SensorError> {
+    let line = line.trim();
+    // NMEA es ASCII; los campos se recortan por posición de byte
+    if !line.is_ascii() {
+        return Err(SensorError::InvalidData);
+    }
+    let body = line.strip_prefix('$').ok_or(SensorError::InvalidData)?;
+    let (body, checksum) = body.rsplit_once('*').ok_or(SensorError::InvalidData)?;
+    let checksum = u8::from_str_radix(checksum, 16).map_err(|_| SensorError::InvalidData)?;
+    if nmea_checksum(body) != checksum {
+        return Err(SensorError::InvalidData);
+    }
+
+    let fields: Vec<&str> = body.split(',').collect();
+    let kind = fields[0].get(2..).ok_or(SensorError::InvalidData)?;
+    let field = |i: usize| fields.get(i).copied().unwrap_or("");
+
+    let sentence = match kind {
+        "GGA" => NmeaSentence::Gga(GgaData {
+            time: parse_time(field(1)),
+            latitude: parse_coordinate(field(2), field(3)),
+            longitude: parse_coordinate(field(4), field(5)),
+            fix_quality: FixQuality::from_code(field(6).parse().unwrap_or(0)),
+            satellites: field(7).parse().unwrap_or(0),
+            hdop: field(8).parse().ok(),
+            altitude: field(9).parse().ok(),
+        }),
+        "RMC" => NmeaSentence::Rmc(RmcData {
+            time: parse_time(field(1)),
+            valid: field(2) == "A",
+            latitude: parse_coordinate(field(3), field(4)),
+            longitude: parse_coordinate(field(5), field(6)),
+            speed_knots: field(7).parse().ok(),
+            course: field(8).parse().ok(),
+            date: parse_date(field(9)),
+        }),
+        "GSA" => NmeaSentence::Gsa(GsaData {
+            fix_type: match field(2) {
+                "2" => FixType::Fix2D,
+                "3" => FixType::Fix3D,
+                _ => FixType::NoFix,
+            },
+            satellites: (3..15).filter_map(|i| field(i).parse().ok()).collect(),
+            pdop: field(15).parse().ok(),
+            hdop: field(16).parse().ok(),
+            vdop: field(17).parse().ok(),
+        }),
+        other => NmeaSentence::Other(other.to_string()),
+    };
+    Ok(sentence)
+}
+
+/// Convierte `ddmm.mmmm` / `dddmm.mmmm` y el hemisferio a grados decimales.
+fn parse_coordinate(value: &str, hemisphere: &str) -> Option<f64> {
+    let dot = value.find('.').unwrap_or(value.len());
+    if dot < 3 {
+        return None;
+    }
+    let degrees: f64 = value[..dot - 2].parse().ok()?;
+    let minutes: f64 = value[dot - 2..].parse().ok()?;
+    let decimal = degrees + minutes / 60.0;
+    match hemisphere {
+        "N" | "E" => Some(decimal),
+        "S" | "W" => Some(-decimal),
+        _ => None,
+    }
+}
+
+fn parse_time(value: &str) -> Option<NmeaTime> {
+    if value.len() < 6 {
+        return None;
+    }
+    Some(NmeaTime {
+        hour: value[0..2].parse().ok()?,
+        minute: value[2..4].parse().ok()?,
+        second: value[4..].parse().ok()?,
+    })
+}
+
+fn parse_date(value: &str) -> Option<NmeaDate> {
+    if value.len() != 6 {
+        return None;
+    }
+    Some(NmeaDate {
+        day: value[0..2].parse().ok()?,
+        month: value[2..4].parse().ok()?,
+        year: 2000 + value[4..6].parse::<u16>().ok()?,
+    })
+}
+
+/// Estado acumulado del receptor a partir de las últimas sentencias GGA, RMC y GSA.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpsFix {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Altitud sobre el nivel medio del mar (m).
+    pub altitude: Option<f32>,
+    pub fix_quality: FixQuality,
+    pub fix_type: FixType,
+    pub satellites: u8,
+    pub hdop: Option<f32>,
+    pub pdop: Option<f32>,
+    pub vdop: Option<f32>,
+    /// Velocidad sobre el suelo (km/h).
+    pub speed_kmh: Option<f32>,
+    pub course: Option<f32>,
+    pub time: Option<NmeaTime>,
+    pub date: Option<NmeaDate>,
+}
+
+impl Default for GpsFix {
+    fn default() -> Self {
+        Self {
+            latitude: None,
+            longitude: None,
+            altitude: None,
+            fix_quality: FixQuality::Invalid,
+            fix_type: FixType::NoFix,
+            satellites: 0,
+            hdop: None,
+            pdop: None,
+            vdop: None,
+            speed_kmh: None,
+            course: None,
+            time: None,
+            date: None,
+        }
+    }
+}
+
+impl GpsFix {
+    /// Incorpora una sentencia al estado.
+    pub fn update(&mut self, sentence: &NmeaSentence) {
+        match sentence {
+            NmeaSentence::Gga(gga) => {
+                self.fix_quality = gga.fix_quality;
+                self.satellites = gga.satellites;
+                self.hdop = gga.hdop.or(self.hdop);
+                self.time = gga.time.or(self.time);
+                if gga.fix_quality != FixQuality::Invalid {
+                    self.latitude = gga.latitude;
+                    self.longitude = gga.longitude;
+                    self.altitude = gga.altitude;
+                }
+            }
+            NmeaSentence::Rmc(rmc) => {
+                self.time = rmc.time.or(self.time);
+                self.date = rmc.date.or(self.date);
+                if rmc.valid {
+                    self.latitude = rmc.latitude;
+                    self.longitude = rmc.longitude;
+                    self.speed_kmh = rmc.speed_knots.map(|k| k * 1.852);
+                    self.course = rmc.course;
+                }
+            }
+            NmeaSentence::Gsa(gsa) => {
+                self.fix_type = gsa.fix_type;
+                self.pdop = gsa.pdop;
+                self.hdop = gsa.hdop.or(self.hdop);
+                self.vdop = gsa.vdop;
+            }
+            NmeaSentence::Other(_) => {}
+        }
+    }
+
+    /// `true` si hay posición válida.
+    pub fn has_fix(&self) -> bool {
+        self.fix_quality != FixQuality::Invalid && self.latitude.is_some() && self.longitude.is_some()
+    }
+
+    /// Marca de tiempo Unix (ms) de la hora GPS, si se conocen fecha y hora.
+    pub fn unix_time_ms(&self) -> Option<u64> {
+        let (date, time) = (self.date?, self.time?);
+        // Días desde 1970-01-01 (algoritmo de calendario civil de H. Hinnant)
+        let (y, m) = if date.month <= 2 {
+            (date.year as i64 - 1, date.month as i64 + 9)
+        } else {
+            (date.year as i64, date.month as i64 - 3)
+        };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * m + 2) / 5 + date.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe - 719_468;
+
+        let seconds = days * 86_400 + time.hour as i64 * 3600 + time.minute as i64 * 60;
+        Some((seconds as f64 * 1000.0 + time.second as f64 * 1000.0).round() as u64)
+    }
+}
+
+/// Referencia compartida a la última posición conocida.
+///
+/// Se obtiene con [`GpsSensor::location`] y se actualiza en cada lectura del GPS. Se vacía
+/// cuando un ciclo completo llega sin fix, y una posición más antigua que la antigüedad
+/// máxima ([`LOCATION_MAX_AGE`] por defecto) deja de devolverse, por ejemplo si el
+/// receptor deja de emitir.
+#[derive(Debug, Clone)]
+pub struct LocationHandle {
+    inner: Arc<Mutex<Option<(GpsFix, Instant)>>>,
+    max_age: Duration,
+}
+
+impl Default for LocationHandle {
+    fn default() -> Self {
+        Self { inner: Arc::default(), max_age: LOCATION_MAX_AGE }
+    }
+}
+
+impl LocationHandle {
+    /// Cambia la antigüedad máxima aceptada por este handle.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Último estado con posición válida, si existe y no ha caducado.
+    pub fn current(&self) -> Option<GpsFix> {
+        let guard = self.inner.lock().ok()?;
+        let (fix, at) = guard.as_ref()?;
+        (at.elapsed() <= self.max_age).then(|| fix.clone())
+    }
+
+    fn set(&self, fix: Option<&GpsFix>) {
+        if let Ok(mut guard) = self.inner.lock() {
+            *guard = fix.map(|fix| (fix.clone(), Instant::now()));
+        }
+    }
+}
+
+/// GpsSensor: receptor GPS **NMEA-0183** por UART.
+///
+/// Decodifica las sentencias GGA, RMC y GSA, verificando el checksum de cada una
+/// (las sentencias corruptas se descartan), e informa latitud, longitud, altitud,
+/// calidad del fix y hora GPS.
+///
+/// La última posición válida se comparte mediante un [`LocationHandle`], que
+/// [`LocatedSensor`] usa para etiquetar las lecturas de otros sensores.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::gps::GpsSensor;
+///
+/// let mut gps = GpsSensor::new("/dev/serial0").unwrap();
+/// println!("{:?}", gps.read()); // "Lat: 48.117300, Lon: 11.516667, Alt: 545.4 m, Fix: GPS (8 sat)"
+/// ```
+pub struct GpsSensor<S = SerialDriver> {
+    /// Puerto serie del receptor.
+    port: S,
+    /// Bytes recibidos pendientes de formar una línea.
+    buffer: Vec<u8>,
+    /// Estado acumulado.
+    fix: GpsFix,
+    /// Última posición válida compartida.
+    location: LocationHandle,
+}
+
+impl GpsSensor<SerialDriver> {
+    /// Abre el receptor en un puerto serie del sistema (9600 8N1).
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, SensorError> {
+        Ok(Self::with_port(SerialDriver::new(path, BAUD_RATE)?))
+    }
+}
+
+impl<S: SerialPort> GpsSensor<S> {
+    /// Crea el sensor sobre cualquier puerto serie.
+    pub fn with_port(port: S) -> Self {
+        Self {
+            port,
+            buffer: Vec::with_capacity(MAX_SENTENCE),
+            fix: GpsFix::default(),
+            location: LocationHandle::default(),
+        }
+    }
+
+    /// Referencia compartida a la última posición válida.
+    pub fn location(&self) -> LocationHandle {
+        self.location.clone()
+    }
+
+    /// Estado acumulado actual, sin leer el puerto.
+    pub fn last_fix(&self) -> &GpsFix {
+        &self.fix
+    }
+
+    /// Lee sentencias hasta recibir una GGA y una RMC nuevas (un ciclo completo).
+    ///
+    /// # Retorna
+    /// - `Ok(GpsFix)` con el estado actualizado (puede no tener fix, ver [`GpsFix::has_fix`]).
+    /// - `Err(SensorError::Timeout)` si no se completa el ciclo en 2 s.
+    pub fn read_fix(&mut self) -> Result<GpsFix, SensorError> {
+        let deadline = Instant::now() + CYCLE_TIMEOUT;
+        let (mut gga, mut rmc) = (false, false);
+
+        while !(gga && rmc) {
+            let line = self.read_line(deadline)?;
+            match parse_sentence(&line) {
+                Ok(sentence) => {
+                    gga |= matches!(sentence, NmeaSentence::Gga(_));
+                    rmc |= matches!(sentence, NmeaSentence::Rmc(_));
+                    self.fix.update(&sentence);
+                }
+                Err(_) => eprintln!("[GPS] Sentencia descartada: {}", line.trim()),
+            }
+        }
+
+        // Sin fix la posición compartida se descarta en lugar de quedar obsoleta
+        self.location.set(Some(&self.fix).filter(|fix| fix.has_fix()));
+        Ok(self.fix.clone())
+    }
+
+    /// Lanza un hilo que lee el receptor continuamente y devuelve la referencia
+    /// a la posición, para etiquetar lecturas sin tener que leer el GPS en el bucle principal.
+    pub fn spawn(mut self) -> LocationHandle
+    where
+        S: Send + 'static,
+    {
+        let handle = self.location();
+        thread::spawn(move || loop {
+            if self.read_fix().is_err() {
+                thread::sleep(Duration::from_secs(1));
+            }
+        });
+        handle
+    }
+
+    /// Devuelve el puerto serie, consumiendo el sensor.
+    pub fn release(self) -> S {
+        self.port
+    }
+
+    /// Lee una línea terminada en `\n` antes de `deadline`.
+    fn read_line(&mut self, deadline: Instant) -> Result<String, SensorError> {
+        loop {
+            if let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = self.buffer.drain(..=end).collect();
+                return Ok(String::from_utf8_lossy(&line).into_owned());
+            }
+            if self.buffer.len() > MAX_SENTENCE {
+                // Ruido sin fin de línea: se descarta
+                self.buffer.clear();
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(SensorError::Timeout);
+            }
+            let mut chunk = [0u8; 64];
+            let n = self.port.read_timeout(&mut chunk, remaining)?;
+            self.buffer.extend_from_slice(&chunk[..n]);
+        }
+    }
+}
+
+impl<S: SerialPort> Sensor for GpsSensor<S> {
+    /// Tipo de salida: [`SensorOutput`] (texto con la posición).
+    type Output = SensorOutput;
+
+    /// Devuelve, por ejemplo, `"Lat: -33.447487, Lon: -70.673676, Alt: 570.0 m, Fix: GPS (8 sat)"`,
+    /// o `"Sin fix (3 sat)"` si el receptor aún no tiene posición.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let fix = self.read_fix()?;
+        if !fix.has_fix() {
+            return Ok(SensorOutput::Text(format!("Sin fix ({} sat)", fix.satellites)));
+        }
+        Ok(SensorOutput::Text(format!(
+            "Lat: {:.6}, Lon: {:.6}, Alt: {:.1} m, Fix: {} ({} sat)",
+            fix.latitude.unwrap_or_default(),
+            fix.longitude.unwrap_or_default(),
+            fix.altitude.unwrap_or_default(),
+            fix.fix_quality.name(),
+            fix.satellites
+        )))
+    }
+}
+
+/// LocatedSensor: envuelve un sensor y etiqueta sus lecturas con la posición GPS actual.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::dht22::Dht22Sensor;
+/// use lince::devices::sensors::gps::{GpsSensor, LocatedSensor};
+///
+/// let ubicacion = GpsSensor::new("/dev/serial0").unwrap().spawn();
+/// let mut dht = LocatedSensor::new(Dht22Sensor::new(4).unwrap(), ubicacion);
+/// println!("{:?}", dht.read()); // "Temp: 24.1°C, Hum: 55.0% @ -33.447487,-70.673676"
+/// ```
+pub struct LocatedSensor<S: Sensor<Output = SensorOutput>> {
+    inner: S,
+    location: LocationHandle,
+}
+
+impl<S: Sensor<Output = SensorOutput>> LocatedSensor<S> {
+    /// Crea el envoltorio a partir de un sensor y la referencia a la posición.
+    pub fn new(inner: S, location: LocationHandle) -> Self {
+        Self { inner, location }
+    }
+
+    /// Lee el sensor y devuelve la lectura junto con la última posición conocida.
+    pub fn read_located(&mut self) -> Result<(SensorOutput, Option<GpsFix>), SensorError> {
+        Ok((self.inner.read()?, self.location.current()))
+    }
+
+    /// Devuelve el sensor original, consumiendo el envoltorio.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Sensor<Output = SensorOutput>> Sensor for LocatedSensor<S> {
+    type Output = SensorOutput;
+
+    /// Devuelve la lectura como texto con el sufijo `" @ lat,lon"` (o sin él si no hay una
+    /// posición vigente).
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let (output, fix) = self.read_located()?;
+        let text = match output {
+            SensorOutput::Bool(v) => v.to_string(),
+            SensorOutput::Int(v) => v.to_string(),
+            SensorOutput::Float(v) => v.to_string(),
+            SensorOutput::Text(s) => s,
+            SensorOutput::Bytes(b) => b.iter().map(|x| format!("{:02X}", x)).collect(),
+        };
+        Ok(SensorOutput::Text(match fix {
+            Some(GpsFix { latitude: Some(lat), longitude: Some(lon), .. }) => {
+                format!("{} @ {:.6},{:.6}", text, lat, lon)
+            }
+            _ => text,
+        }))
+    }
+}
@@ -0,0 +1,218 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use embedded_hal::digital::v2::{InputPin, OutputPin};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::devices::sensors::dht::DhtBase;
+use crate::drivers::gpio::GpioDriver;
+
+/// Tiempo máximo de espera hasta que el eco sube tras el disparo (µs).
+const ECHO_START_TIMEOUT_US: u64 = 30_000;
+/// Duración máxima del eco; el módulo lo mantiene ~38 ms cuando no hay obstáculo (µs).
+const ECHO_MAX_US: u64 = 38_000;
+/// Pausa mínima entre disparos para que los ecos anteriores se disipen.
+const PING_INTERVAL: Duration = Duration::from_millis(60);
+
+/// Velocidad del sonido en el aire (m/s) a la temperatura indicada (°C).
+pub fn speed_of_sound(temperature: f32) -> f32 {
+    331.3 + 0.606 * temperature
+}
+
+/// Implementación del **sensor ultrasónico de distancia HC-SR04**.
+///
+/// El módulo usa dos pines: TRIG (salida) y ECHO (entrada). Tras un pulso de 10 µs
+/// en TRIG, el módulo emite una ráfaga ultrasónica y mantiene ECHO en alto durante
+/// el tiempo de ida y vuelta del sonido. La medición de ese pulso reutiliza
+/// [`DhtBase::esperar_nivel`].
+///
+/// Para reducir el ruido, cada lectura toma varias muestras y devuelve la **mediana**
+/// de las que están dentro del rango válido. La velocidad del sonido se compensa
+/// con la temperatura del aire.
+///
+/// # Características
+/// - Rango: 2 cm a 400 cm
+/// - Resolución: ~0.3 cm
+/// - Alimentación: 5 V (ECHO necesita divisor de tensión a 3.3 V)
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::hcsr04::HcSr04Sensor;
+///
+/// let mut sensor = HcSr04Sensor::new(23, 24).unwrap(); // TRIG GPIO23, ECHO GPIO24
+/// sensor.set_temperature(12.0);
+/// println!("{:?}", sensor.read().unwrap()); // "87.4 cm"
+/// ```
+pub struct HcSr04Sensor<T = GpioDriver, E = GpioDriver> {
+    /// Pin de disparo (TRIG).
+    trigger: T,
+    /// Pin de eco (ECHO).
+    echo: E,
+    /// Temperatura del aire usada para compensar la velocidad del sonido (°C).
+    temperature: f32,
+    /// Número de muestras por lectura.
+    samples: usize,
+    /// Distancia mínima válida (cm).
+    min_distance: f32,
+    /// Distancia máxima válida (cm).
+    max_distance: f32,
+}
+
+impl HcSr04Sensor<GpioDriver, GpioDriver> {
+    /// Crea una nueva instancia del sensor en los pines BCM indicados.
+    ///
+    /// # Parámetros
+    /// - `trigger_pin`: pin conectado a TRIG.
+    /// - `echo_pin`: pin conectado a ECHO (a través de un divisor de tensión).
+    ///
+    /// # Retorna
+    /// - `Ok(Self)` si ambos pines se inicializan correctamente.
+    /// - `Err(SensorError::IoError)` si falla la inicialización de algún pin.
+    pub fn new(trigger_pin: u8, echo_pin: u8) -> Result<Self, SensorError> {
+        Ok(Self::with_pins(GpioDriver::new(trigger_pin)?, GpioDriver::new(echo_pin)?))
+    }
+}
+
+impl<T, E> HcSr04Sensor<T, E>
+where
+    T: OutputPin,
+    E: InputPin,
+{
+    /// Crea una nueva instancia a partir de pines ya inicializados.
+    ///
+    /// Por defecto usa 20 °C, 5 muestras por lectura y un rango de 2 a 400 cm.
+    pub fn with_pins(trigger: T, echo: E) -> Self {
+        Self {
+            trigger,
+            echo,
+            temperature: 20.0,
+            samples: 5,
+            min_distance: 2.0,
+            max_distance: 400.0,
+        }
+    }
+
+    /// Actualiza la temperatura del aire usada para la compensación (°C).
+    ///
+    /// Una diferencia de 10 °C supone ~1.8 % de error en la distancia.
+    pub fn set_temperature(&mut self, temperature: f32) {
+        self.temperature = temperature;
+    }
+
+    /// Número de muestras tomadas por lectura (mínimo 1).
+    pub fn set_samples(&mut self, samples: usize) {
+        self.samples = samples.max(1);
+    }
+
+    /// Rango de distancias válido en cm; las muestras fuera de él se descartan.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si `min >= max` o `min < 0`.
+    pub fn set_range(&mut self, min_cm: f32, max_cm: f32) -> Result<(), SensorError> {
+        if min_cm < 0.0 || min_cm >= max_cm {
+            return Err(SensorError::InitializationError);
+        }
+        self.min_distance = min_cm;
+        self.max_distance = max_cm;
+        Ok(())
+    }
+
+    /// Mide la duración de un único eco.
+    ///
+    /// # Retorna
+    /// - `Ok(Some(Duration))` con la duración del pulso de eco.
+    /// - `Ok(None)` si el eco superó la duración máxima (sin obstáculo en rango).
+    /// - `Err(SensorError::Timeout)` si el eco nunca subió (módulo desconectado).
+    pub fn ping(&mut self) -> Result<Option<Duration>, SensorError> {
+        // === Pulso de disparo de 10 µs ===
+        self.trigger.set_low().map_err(|_| SensorError::IoError)?;
+        spin_sleep::sleep(Duration::from_micros(2));
+        self.trigger.set_high().map_err(|_| SensorError::IoError)?;
+        spin_sleep::sleep(Duration::from_micros(10));
+        self.trigger.set_low().map_err(|_| SensorError::IoError)?;
+
+        // === Medir el pulso de eco ===
+        if !DhtBase::esperar_nivel(&self.echo, true, ECHO_START_TIMEOUT_US) {
+            return Err(SensorError::Timeout);
+        }
+        let start = Instant::now();
+        if !DhtBase::esperar_nivel(&self.echo, false, ECHO_MAX_US) {
+            return Ok(None);
+        }
+
+        Ok(Some(start.elapsed()))
+    }
+
+    /// Convierte la duración de un eco en distancia (cm) con la temperatura actual.
+    pub fn echo_to_distance(&self, echo: Duration) -> f32 {
+        // Ida y vuelta: se divide entre 2; m/s → cm/s multiplicando por 100
+        echo.as_secs_f32() * speed_of_sound(self.temperature) * 100.0 / 2.0
+    }
+
+    /// Toma `samples` mediciones y devuelve la mediana de las válidas, en cm.
+    ///
+    /// # Retorna
+    /// - `Ok(f32)` con la distancia mediana.
+    /// - `Err(SensorError::InvalidData)` si todas las muestras están fuera de rango.
+    /// - `Err(SensorError::Timeout)` si el módulo no respondió a ningún disparo.
+    pub fn read_distance(&mut self) -> Result<f32, SensorError> {
+        let mut distances = Vec::with_capacity(self.samples);
+        let mut last_error = SensorError::InvalidData;
+
+        for i in 0..self.samples {
+            if i > 0 {
+                thread::sleep(PING_INTERVAL);
+            }
+            match self.ping() {
+                Ok(Some(echo)) => {
+                    let d = self.echo_to_distance(echo);
+                    if (self.min_distance..=self.max_distance).contains(&d) {
+                        distances.push(d);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => last_error = e,
+            }
+        }
+
+        if distances.is_empty() {
+            if last_error == SensorError::InvalidData {
+                eprintln!(
+                    "[HC-SR04] Distancia fuera de rango ({:.0}–{:.0} cm)",
+                    self.min_distance, self.max_distance
+                );
+            }
+            return Err(last_error);
+        }
+
+        distances.sort_by(|a, b| a.total_cmp(b));
+        let mid = distances.len() / 2;
+        Ok(if distances.len() % 2 == 0 {
+            (distances[mid - 1] + distances[mid]) / 2.0
+        } else {
+            distances[mid]
+        })
+    }
+}
+
+impl<T, E> Sensor for HcSr04Sensor<T, E>
+where
+    T: OutputPin,
+    E: InputPin,
+{
+    /// Tipo de salida: [`SensorOutput`] (distancia formateada).
+    type Output = SensorOutput;
+
+    /// Lee la distancia mediana y la devuelve como texto (`"xx.x cm"`).
+    ///
+    /// # Retorna
+    /// - `Ok(SensorOutput::Text(...))` si al menos una muestra fue válida.
+    /// - `Err(SensorError::InvalidData)` si el objeto está fuera de rango.
+    /// - `Err(SensorError::Timeout)` si el módulo no responde.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let distance = self.read_distance()?;
+        Ok(SensorOutput::Text(format!("{:.1} cm", distance)))
+    }
+}
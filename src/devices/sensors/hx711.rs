@@ -0,0 +1,304 @@
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use embedded_hal::digital::v2::{InputPin, OutputPin};
+use serde::{Deserialize, Serialize};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::gpio::GpioDriver;
+
+/// Tiempo máximo de espera a que el HX711 tenga una conversión lista.
+/// A 10 SPS una conversión tarda 100 ms; tras un cambio de canal puede tardar 400 ms.
+const READY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Canal y ganancia del amplificador para la **siguiente** conversión.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hx711Gain {
+    /// Canal A, ganancia 128 (±20 mV a 5 V). Configuración habitual de celdas de carga.
+    A128,
+    /// Canal A, ganancia 64 (±40 mV).
+    A64,
+    /// Canal B, ganancia 32 (±80 mV).
+    B32,
+}
+
+impl Hx711Gain {
+    /// Pulsos adicionales tras los 24 bits de datos que seleccionan el canal/ganancia.
+    fn extra_pulses(self) -> u8 {
+        match self {
+            Hx711Gain::A128 => 1,
+            Hx711Gain::B32 => 2,
+            Hx711Gain::A64 => 3,
+        }
+    }
+}
+
+/// Calibración de la báscula: `masa = (crudo - offset) / scale`.
+///
+/// Puede guardarse y cargarse como archivo TOML para conservarla entre reinicios:
+///
+/// ```toml
+/// offset = 8388
+/// scale = 421.7
+/// unit = "g"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hx711Calibration {
+    /// Valor crudo con la báscula vacía (tara).
+    pub offset: i64,
+    /// Cuentas por unidad de masa.
+    pub scale: f32,
+    /// Unidad de la masa calibrada (por ejemplo `"g"` o `"kg"`).
+    pub unit: String,
+}
+
+impl Default for Hx711Calibration {
+    /// Sin calibrar: devuelve cuentas crudas.
+    fn default() -> Self {
+        Self { offset: 0, scale: 1.0, unit: "g".to_string() }
+    }
+}
+
+impl Hx711Calibration {
+    /// Carga una calibración desde un archivo TOML.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el archivo no puede leerse.
+    /// - `Err(SensorError::InvalidData)` si el contenido no es válido o `scale` es cero.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SensorError> {
+        let text = fs::read_to_string(path).map_err(|_| SensorError::IoError)?;
+        let calibration: Self = toml::from_str(&text).map_err(|e| {
+            eprintln!("[HX711] Calibración inválida: {}", e);
+            SensorError::InvalidData
+        })?;
+        if calibration.scale == 0.0 {
+            return Err(SensorError::InvalidData);
+        }
+        Ok(calibration)
+    }
+
+    /// Guarda la calibración en un archivo TOML.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el archivo no puede escribirse.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SensorError> {
+        let text = toml::to_string(self).map_err(|_| SensorError::InvalidData)?;
+        fs::write(path, text).map_err(|_| SensorError::IoError)
+    }
+
+    /// Convierte un valor crudo en masa.
+    pub fn to_mass(&self, raw: f64) -> f32 {
+        ((raw - self.offset as f64) / self.scale as f64) as f32
+    }
+}
+
+/// Implementación del **amplificador de celdas de carga HX711**.
+///
+/// El HX711 es un ADC de 24 bits con protocolo propio de dos hilos:
+/// DOUT (datos, entrada) y PD_SCK (reloj, salida), controlados por bit-banging.
+/// Se usa típicamente para básculas (por ejemplo, pesar colmenas).
+///
+/// # Flujo de una conversión
+/// 1. Esperar a que DOUT baje (dato listo).
+/// 2. Generar 24 pulsos en PD_SCK y leer un bit en cada uno (MSB primero).
+/// 3. Generar 1–3 pulsos adicionales para elegir canal y ganancia de la siguiente conversión.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::hx711::{Hx711Calibration, Hx711Gain, Hx711Sensor};
+///
+/// let mut sensor = Hx711Sensor::new(5, 6, Hx711Gain::A128).unwrap(); // DOUT GPIO5, SCK GPIO6
+/// sensor.set_calibration(Hx711Calibration::load("bascula.toml").unwrap());
+/// println!("{:?}", sensor.read().unwrap()); // "42.15 kg"
+/// ```
+pub struct Hx711Sensor<D = GpioDriver, S = GpioDriver> {
+    /// Pin de datos (DOUT).
+    dout: D,
+    /// Pin de reloj (PD_SCK).
+    sck: S,
+    /// Canal y ganancia seleccionados.
+    gain: Hx711Gain,
+    /// Calibración de la báscula.
+    calibration: Hx711Calibration,
+    /// Número de conversiones promediadas por lectura.
+    samples: usize,
+}
+
+impl Hx711Sensor<GpioDriver, GpioDriver> {
+    /// Crea una nueva instancia del HX711 en los pines BCM indicados.
+    ///
+    /// # Parámetros
+    /// - `dout_pin`: pin conectado a DOUT.
+    /// - `sck_pin`: pin conectado a PD_SCK.
+    /// - `gain`: canal y ganancia.
+    ///
+    /// # Retorna
+    /// - `Ok(Self)` si los pines se inicializan y el HX711 responde.
+    /// - `Err(SensorError)` si falla algún pin o el HX711 no entrega datos.
+    pub fn new(dout_pin: u8, sck_pin: u8, gain: Hx711Gain) -> Result<Self, SensorError> {
+        Self::with_pins(GpioDriver::new(dout_pin)?, GpioDriver::new(sck_pin)?, gain)
+    }
+}
+
+impl<D, S> Hx711Sensor<D, S>
+where
+    D: InputPin,
+    S: OutputPin,
+{
+    /// Crea una nueva instancia a partir de pines ya inicializados.
+    ///
+    /// Realiza una conversión inicial para aplicar la ganancia (la selección
+    /// afecta a la conversión siguiente). Promedia 10 muestras por defecto.
+    pub fn with_pins(dout: D, sck: S, gain: Hx711Gain) -> Result<Self, SensorError> {
+        let mut sensor = Self {
+            dout,
+            sck,
+            gain,
+            calibration: Hx711Calibration::default(),
+            samples: 10,
+        };
+        sensor.power_up()?;
+        sensor.read_raw()?;
+        Ok(sensor)
+    }
+
+    /// Cambia el canal y la ganancia.
+    ///
+    /// La primera conversión tras el cambio se descarta.
+    pub fn set_gain(&mut self, gain: Hx711Gain) -> Result<(), SensorError> {
+        self.gain = gain;
+        self.read_raw().map(|_| ())
+    }
+
+    /// Número de conversiones promediadas por lectura (mínimo 1).
+    pub fn set_samples(&mut self, samples: usize) {
+        self.samples = samples.max(1);
+    }
+
+    /// Calibración actual.
+    pub fn calibration(&self) -> &Hx711Calibration {
+        &self.calibration
+    }
+
+    /// Reemplaza la calibración (por ejemplo, una cargada de archivo).
+    pub fn set_calibration(&mut self, calibration: Hx711Calibration) {
+        self.calibration = calibration;
+    }
+
+    /// Registra el valor actual como cero (tara), con la báscula vacía.
+    pub fn tare(&mut self) -> Result<(), SensorError> {
+        self.calibration.offset = self.read_average()?.round() as i64;
+        Ok(())
+    }
+
+    /// Calcula la escala colocando una masa conocida sobre la báscula (tras la tara).
+    ///
+    /// # Parámetros
+    /// - `known_mass`: masa colocada, en la unidad de la calibración.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si la masa es cero o la lectura no cambia.
+    pub fn calibrate(&mut self, known_mass: f32) -> Result<(), SensorError> {
+        if known_mass == 0.0 {
+            return Err(SensorError::InvalidData);
+        }
+        let delta = self.read_average()? - self.calibration.offset as f64;
+        if delta == 0.0 {
+            return Err(SensorError::InvalidData);
+        }
+        self.calibration.scale = (delta / known_mass as f64) as f32;
+        Ok(())
+    }
+
+    /// Pone el HX711 en modo de bajo consumo (PD_SCK en alto > 60 µs).
+    pub fn power_down(&mut self) -> Result<(), SensorError> {
+        self.sck.set_low().map_err(|_| SensorError::IoError)?;
+        self.sck.set_high().map_err(|_| SensorError::IoError)?;
+        spin_sleep::sleep(Duration::from_micros(100));
+        Ok(())
+    }
+
+    /// Despierta el HX711.
+    ///
+    /// Al despertar el chip vuelve al canal A con ganancia 128; si la ganancia
+    /// configurada es otra, se descarta una conversión para volver a aplicarla.
+    pub fn power_up(&mut self) -> Result<(), SensorError> {
+        self.sck.set_low().map_err(|_| SensorError::IoError)?;
+        if self.gain != Hx711Gain::A128 {
+            self.read_raw()?;
+        }
+        Ok(())
+    }
+
+    /// Lee una conversión cruda de 24 bits con signo.
+    ///
+    /// # Retorna
+    /// - `Ok(i32)` con el valor crudo.
+    /// - `Err(SensorError::Timeout)` si el HX711 no tiene datos listos a tiempo.
+    pub fn read_raw(&mut self) -> Result<i32, SensorError> {
+        let start = Instant::now();
+        while self.dout.is_high().map_err(|_| SensorError::IoError)? {
+            if start.elapsed() > READY_TIMEOUT {
+                return Err(SensorError::Timeout);
+            }
+            spin_sleep::sleep(Duration::from_micros(100));
+        }
+
+        let mut value: u32 = 0;
+        for _ in 0..24 {
+            self.clock_pulse()?;
+            value <<= 1;
+            if self.dout.is_high().map_err(|_| SensorError::IoError)? {
+                value |= 1;
+            }
+        }
+        for _ in 0..self.gain.extra_pulses() {
+            self.clock_pulse()?;
+        }
+
+        // Extensión de signo de 24 a 32 bits
+        Ok(((value << 8) as i32) >> 8)
+    }
+
+    /// Promedio de `samples` conversiones crudas.
+    pub fn read_average(&mut self) -> Result<f64, SensorError> {
+        let mut sum: i64 = 0;
+        for _ in 0..self.samples {
+            sum += self.read_raw()? as i64;
+        }
+        Ok(sum as f64 / self.samples as f64)
+    }
+
+    /// Lee la masa promediada aplicando la calibración.
+    pub fn read_mass(&mut self) -> Result<f32, SensorError> {
+        let raw = self.read_average()?;
+        Ok(self.calibration.to_mass(raw))
+    }
+
+    /// Pulso de reloj: el HX711 exige PD_SCK en alto menos de 50 µs.
+    fn clock_pulse(&mut self) -> Result<(), SensorError> {
+        self.sck.set_high().map_err(|_| SensorError::IoError)?;
+        spin_sleep::sleep(Duration::from_micros(1));
+        self.sck.set_low().map_err(|_| SensorError::IoError)?;
+        spin_sleep::sleep(Duration::from_micros(1));
+        Ok(())
+    }
+}
+
+impl<D, S> Sensor for Hx711Sensor<D, S>
+where
+    D: InputPin,
+    S: OutputPin,
+{
+    /// Tipo de salida: [`SensorOutput`] (masa formateada con su unidad).
+    type Output = SensorOutput;
+
+    /// Lee la masa promediada y la devuelve como texto (`"42.15 kg"`).
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let mass = self.read_mass()?;
+        Ok(SensorOutput::Text(format!("{:.2} {}", mass, self.calibration.unit)))
+    }
+}
@@ -0,0 +1,376 @@
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto (A0 y A1 a GND).
+pub const DEFAULT_ADDRESS: u8 = 0x40;
+
+// Registros comunes
+const REG_CONFIG: u8 = 0x00;
+const REG_SHUNT_VOLTAGE: u8 = 0x01;
+const REG_BUS_VOLTAGE: u8 = 0x02;
+const REG_POWER: u8 = 0x03;
+const REG_CURRENT: u8 = 0x04;
+const REG_CALIBRATION: u8 = 0x05;
+
+// Registros de identificación del INA226
+const REG_MANUFACTURER_ID: u8 = 0xFE;
+const REG_DIE_ID: u8 = 0xFF;
+const INA226_MANUFACTURER_ID: u16 = 0x5449; // "TI"
+const INA226_DIE_ID: u16 = 0x2260;
+
+const CONFIG_RESET: u16 = 0x8000;
+/// Modo continuo de tensión de shunt y de bus.
+const MODE_CONTINUOUS: u16 = 0b111;
+
+/// Rangos de tensión de shunt del PGA del INA219 (V).
+const INA219_SHUNT_RANGES: [f32; 4] = [0.04, 0.08, 0.16, 0.32];
+/// Rango de tensión de shunt del INA226 (V).
+const INA226_SHUNT_RANGE: f32 = 0.08192;
+
+/// Tolerancia relativa al comparar la tensión de shunt máxima con los rangos.
+///
+/// El producto en `f32` puede pasarse por unos ULP (`0.1 · 3.2 = 0.32000002`), y una
+/// calibración justo en el límite de un rango debe aceptarse.
+const RANGE_TOLERANCE: f32 = 1e-5;
+
+/// Promedios admitidos por el ADC del INA219 (con 12 bits).
+const INA219_AVERAGING: [u16; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+/// Promedios admitidos por el INA226.
+const INA226_AVERAGING: [u16; 8] = [1, 4, 16, 64, 128, 256, 512, 1024];
+
+/// Modelo del monitor de potencia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InaModel {
+    /// INA219: bus hasta 26 V, ADC de 12 bits, PGA de shunt de ±40 a ±320 mV.
+    Ina219,
+    /// INA226: bus hasta 36 V, ADC de 16 bits, shunt de ±81.92 mV.
+    Ina226,
+}
+
+impl InaModel {
+    /// Constante del registro de calibración (`cal = K / (LSB de corriente · R shunt)`).
+    fn calibration_constant(self) -> f32 {
+        match self {
+            InaModel::Ina219 => 0.04096,
+            InaModel::Ina226 => 0.00512,
+        }
+    }
+
+    /// Límite exclusivo del registro de calibración (el bit 15 del INA226 está reservado).
+    fn calibration_limit(self) -> f32 {
+        match self {
+            InaModel::Ina219 => 65536.0,
+            InaModel::Ina226 => 32768.0,
+        }
+    }
+
+    /// Relación entre el LSB de potencia y el de corriente.
+    fn power_lsb_ratio(self) -> f32 {
+        match self {
+            InaModel::Ina219 => 20.0,
+            InaModel::Ina226 => 25.0,
+        }
+    }
+
+    /// LSB de la tensión de shunt (V).
+    fn shunt_lsb(self) -> f32 {
+        match self {
+            InaModel::Ina219 => 10e-6,
+            InaModel::Ina226 => 2.5e-6,
+        }
+    }
+
+    fn averaging_options(self) -> &'static [u16; 8] {
+        match self {
+            InaModel::Ina219 => &INA219_AVERAGING,
+            InaModel::Ina226 => &INA226_AVERAGING,
+        }
+    }
+}
+
+/// Calibración del shunt.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InaCalibration {
+    /// Resistencia del shunt (Ω). Los módulos comerciales suelen usar 0.1 Ω.
+    pub shunt_ohms: f32,
+    /// Corriente máxima esperada (A); fija la resolución de corriente.
+    pub max_current: f32,
+}
+
+impl InaCalibration {
+    /// Crea una calibración a partir del shunt y la corriente máxima esperada.
+    pub fn new(shunt_ohms: f32, max_current: f32) -> Self {
+        Self { shunt_ohms, max_current }
+    }
+
+    /// LSB del registro de corriente (A): `max_current / 2^15`.
+    pub fn current_lsb(&self) -> f32 {
+        self.max_current / 32768.0
+    }
+}
+
+/// Lectura completa de un monitor de potencia.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerReading {
+    /// Tensión del bus respecto a GND (V).
+    pub bus_voltage: f32,
+    /// Tensión en el shunt (V, con signo).
+    pub shunt_voltage: f32,
+    /// Corriente (A, con signo).
+    pub current: f32,
+    /// Potencia (W).
+    pub power: f32,
+}
+
+/// Implementación de los monitores de corriente y potencia **TI INA219/INA226** sobre I2C.
+///
+/// Miden la tensión del bus y la caída en una resistencia shunt. A partir de la
+/// [`InaCalibration`] se programa el registro de calibración, de modo que el propio
+/// chip calcula la corriente y la potencia. En el INA219 se elige además el rango del
+/// PGA que cubre la tensión de shunt máxima.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::ina::{InaCalibration, InaModel, InaSensor, DEFAULT_ADDRESS};
+///
+/// // Batería con shunt de 0.1 Ω y hasta 2 A
+/// let mut bateria = InaSensor::new(1, DEFAULT_ADDRESS, InaModel::Ina219, InaCalibration::new(0.1, 2.0)).unwrap();
+/// bateria.set_averaging(16).unwrap();
+/// println!("{:?}", bateria.read().unwrap()); // "Bus: 12.48 V, Corriente: 0.512 A, Potencia: 6.39 W"
+/// ```
+pub struct InaSensor<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el sensor.
+    i2c: I2C,
+    /// Dirección I2C del sensor.
+    address: u8,
+    /// Modelo del sensor.
+    model: InaModel,
+    /// Calibración del shunt.
+    calibration: InaCalibration,
+    /// Índice del promedio configurado en la tabla del modelo.
+    averaging: u16,
+    /// Rango del PGA del INA219 (índice en `INA219_SHUNT_RANGES`).
+    pga: u16,
+}
+
+impl InaSensor<I2cDriver> {
+    /// Crea una nueva instancia del sensor en un bus I2C del sistema.
+    ///
+    /// # Parámetros
+    /// - `bus`: número del bus I2C (`1` en la Raspberry Pi).
+    /// - `address`: dirección del sensor (0x40–0x4F según A0/A1).
+    /// - `model`: modelo del sensor.
+    /// - `calibration`: shunt y corriente máxima.
+    pub fn new(bus: u8, address: u8, model: InaModel, calibration: InaCalibration) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address, model, calibration)
+    }
+}
+
+impl<I2C> InaSensor<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Crea una nueva instancia del sensor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Reinicia el chip, comprueba su identificador (INA226), y programa la configuración
+    /// (modo continuo, sin promediado) y la calibración.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el sensor no responde.
+    /// - `Err(SensorError::InitializationError)` si el ID no coincide o la calibración
+    ///   no es representable (shunt o corriente fuera de rango).
+    pub fn with_i2c(i2c: I2C, address: u8, model: InaModel, calibration: InaCalibration) -> Result<Self, SensorError> {
+        let mut sensor = Self {
+            i2c,
+            address,
+            model,
+            calibration,
+            averaging: 0,
+            pga: 0,
+        };
+
+        sensor.write_register(REG_CONFIG, CONFIG_RESET)?;
+        if model == InaModel::Ina226 {
+            let manufacturer = sensor.read_register(REG_MANUFACTURER_ID)?;
+            let die = sensor.read_register(REG_DIE_ID)?;
+            if manufacturer != INA226_MANUFACTURER_ID || die != INA226_DIE_ID {
+                eprintln!("[INA] ID inesperado: 0x{:04X}/0x{:04X}", manufacturer, die);
+                return Err(SensorError::InitializationError);
+            }
+        }
+        sensor.set_calibration(calibration)?;
+        Ok(sensor)
+    }
+
+    /// Modelo del sensor.
+    pub fn model(&self) -> InaModel {
+        self.model
+    }
+
+    /// Calibración actual.
+    pub fn calibration(&self) -> InaCalibration {
+        self.calibration
+    }
+
+    /// Programa una nueva calibración del shunt.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si la tensión de shunt máxima supera el
+    ///   rango del chip o el valor de calibración no cabe en el registro (16 bits en el
+    ///   INA219, 15 en el INA226).
+    pub fn set_calibration(&mut self, calibration: InaCalibration) -> Result<(), SensorError> {
+        if calibration.shunt_ohms <= 0.0 || calibration.max_current <= 0.0 {
+            return Err(SensorError::InitializationError);
+        }
+
+        let max_shunt = calibration.shunt_ohms * calibration.max_current;
+        let fits = |range: f32| max_shunt <= range * (1.0 + RANGE_TOLERANCE);
+        self.pga = match self.model {
+            InaModel::Ina219 => INA219_SHUNT_RANGES
+                .iter()
+                .position(|&range| fits(range))
+                .ok_or(SensorError::InitializationError)? as u16,
+            InaModel::Ina226 if fits(INA226_SHUNT_RANGE) => 0,
+            InaModel::Ina226 => return Err(SensorError::InitializationError),
+        };
+
+        let cal = self.model.calibration_constant() / (calibration.current_lsb() * calibration.shunt_ohms);
+        if !(1.0..self.model.calibration_limit()).contains(&cal) {
+            eprintln!("[INA] Calibración fuera de rango: {}", cal);
+            return Err(SensorError::InitializationError);
+        }
+        let mut cal = cal as u16;
+        if self.model == InaModel::Ina219 {
+            // El bit 0 del registro de calibración del INA219 no se usa
+            cal &= 0xFFFE;
+        }
+
+        self.calibration = calibration;
+        self.write_config()?;
+        self.write_register(REG_CALIBRATION, cal)
+    }
+
+    /// Número de muestras promediadas por conversión.
+    pub fn averaging(&self) -> u16 {
+        self.model.averaging_options()[self.averaging as usize]
+    }
+
+    /// Configura el promediado por hardware.
+    ///
+    /// - INA219: 1, 2, 4, 8, 16, 32, 64 o 128 muestras (de 0.5 a 68 ms por conversión).
+    /// - INA226: 1, 4, 16, 64, 128, 256, 512 o 1024 muestras.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si el valor no es admitido por el modelo.
+    pub fn set_averaging(&mut self, samples: u16) -> Result<(), SensorError> {
+        self.averaging = self
+            .model
+            .averaging_options()
+            .iter()
+            .position(|&s| s == samples)
+            .ok_or(SensorError::InitializationError)? as u16;
+        self.write_config()
+    }
+
+    /// Devuelve el bus I2C, consumiendo el sensor.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Tensión del bus (V).
+    pub fn read_bus_voltage(&mut self) -> Result<f32, SensorError> {
+        let raw = self.read_register(REG_BUS_VOLTAGE)?;
+        Ok(match self.model {
+            InaModel::Ina219 => (raw >> 3) as f32 * 0.004,
+            InaModel::Ina226 => raw as f32 * 0.00125,
+        })
+    }
+
+    /// Tensión de shunt (V, con signo).
+    pub fn read_shunt_voltage(&mut self) -> Result<f32, SensorError> {
+        let raw = self.read_register(REG_SHUNT_VOLTAGE)? as i16;
+        Ok(raw as f32 * self.model.shunt_lsb())
+    }
+
+    /// Corriente (A, con signo).
+    pub fn read_current(&mut self) -> Result<f32, SensorError> {
+        let raw = self.read_register(REG_CURRENT)? as i16;
+        Ok(raw as f32 * self.calibration.current_lsb())
+    }
+
+    /// Potencia (W).
+    pub fn read_power(&mut self) -> Result<f32, SensorError> {
+        let raw = self.read_register(REG_POWER)?;
+        Ok(raw as f32 * self.calibration.current_lsb() * self.model.power_lsb_ratio())
+    }
+
+    /// Lee tensión de bus, tensión de shunt, corriente y potencia.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el INA219 indica desbordamiento aritmético
+    ///   (la corriente supera la calibración).
+    pub fn read_measurement(&mut self) -> Result<PowerReading, SensorError> {
+        if self.model == InaModel::Ina219 && self.read_register(REG_BUS_VOLTAGE)? & 0x0001 != 0 {
+            eprintln!("[INA] Desbordamiento: la corriente supera la calibración");
+            return Err(SensorError::InvalidData);
+        }
+
+        Ok(PowerReading {
+            bus_voltage: self.read_bus_voltage()?,
+            shunt_voltage: self.read_shunt_voltage()?,
+            current: self.read_current()?,
+            power: self.read_power()?,
+        })
+    }
+
+    fn write_config(&mut self) -> Result<(), SensorError> {
+        let config = match self.model {
+            InaModel::Ina219 => {
+                // Bus de 32 V, PGA, ADC de 12 bits con promediado (1xxx) para bus y shunt
+                let adc = if self.averaging == 0 { 0b0011 } else { 0b1000 | self.averaging };
+                (1 << 13) | (self.pga << 11) | (adc << 7) | (adc << 3) | MODE_CONTINUOUS
+            }
+            InaModel::Ina226 => {
+                // Conversión de 1.1 ms para bus y shunt
+                0x4000 | (self.averaging << 9) | (0b100 << 6) | (0b100 << 3) | MODE_CONTINUOUS
+            }
+        };
+        self.write_register(REG_CONFIG, config)
+    }
+
+    fn write_register(&mut self, reg: u8, value: u16) -> Result<(), SensorError> {
+        let [high, low] = value.to_be_bytes();
+        self.i2c
+            .write(self.address, &[reg, high, low])
+            .map_err(|_| SensorError::IoError)
+    }
+
+    fn read_register(&mut self, reg: u8) -> Result<u16, SensorError> {
+        let mut buf = [0u8; 2];
+        self.i2c
+            .write_read(self.address, &[reg], &mut buf)
+            .map_err(|_| SensorError::IoError)?;
+        Ok(u16::from_be_bytes(buf))
+    }
+}
+
+impl<I2C> Sensor for InaSensor<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Tipo de salida: [`SensorOutput`] (texto con tensión, corriente y potencia).
+    type Output = SensorOutput;
+
+    /// Devuelve, por ejemplo, `"Bus: 12.48 V, Corriente: 0.512 A, Potencia: 6.39 W"`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let r = self.read_measurement()?;
+        Ok(SensorOutput::Text(format!(
+            "Bus: {:.2} V, Corriente: {:.3} A, Potencia: {:.2} W",
+            r.bus_voltage, r.current, r.power
+        )))
+    }
+}
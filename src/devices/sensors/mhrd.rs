@@ -1,24 +1,112 @@
+use embedded_hal::digital::v2::InputPin;
 use crate::core::traits::sensor::Sensor;
+use crate::drivers::adc::AnalogInput;
 use crate::drivers::gpio::GpioDriver;
 use crate::core::SensorError;
 use crate::core::SensorOutput;
 
-/// MhRdSensor: representa un **sensor de lluvia digital (Rain Sensor)**.
+/// MhRdSensor: representa un **sensor de lluvia (Rain Sensor)**.
 ///
-/// Este sensor interpreta la salida digital (DO) de un módulo de lluvia.
-/// Muchos módulos digitales tienen **salida activa baja** (`active_low = true`), 
-/// lo que significa que el pin DO está en LOW cuando se detecta agua.
+/// Este sensor puede funcionar en dos modos:
+/// - **Digital**: interpreta la salida digital (DO) del comparador del módulo.
+///   Muchos módulos digitales tienen **salida activa baja** (`active_low = true`),
+///   lo que significa que el pin DO está en LOW cuando se detecta agua.
+/// - **Analógico**: lee la salida analógica (AO) a través de un ADC y la convierte
+///   en un porcentaje de humedad calibrado (0–100 %), con un estado HÚMEDO/SECO
+///   derivado mediante histéresis.
 pub struct MhRdSensor {
-    /// Controlador GPIO asociado al pin del sensor.
-    gpio: GpioDriver,
-    
-    /// Indica si la salida digital es activa en LOW (`true`) o HIGH (`false`).
-    /// Por defecto, muchos módulos digitales son `active_low = true`.
-    active_low: bool,
+    /// Fuente de la lectura (pin digital o canal ADC).
+    input: MhRdInput,
+}
+
+/// Fuente de lectura del sensor según el modo configurado.
+enum MhRdInput {
+    /// Salida digital DO leída por GPIO.
+    Digital {
+        /// Pin conectado a DO (GPIO de la placa o de un expansor).
+        gpio: Box<dyn DigitalInput>,
+        /// Indica si la salida digital es activa en LOW (`true`) o HIGH (`false`).
+        active_low: bool,
+    },
+    /// Salida analógica AO leída por un ADC.
+    Analog {
+        /// Canal del ADC conectado al pin AO.
+        adc: Box<dyn AnalogInput>,
+        /// Puntos de referencia y umbrales de histéresis.
+        calibration: MhRdCalibration,
+        /// Último estado calculado (necesario para la histéresis).
+        wet: bool,
+    },
+}
+
+/// Pin de entrada digital sin tipo concreto, para admitir cualquier `InputPin`.
+trait DigitalInput {
+    /// Devuelve `true` si el pin está en HIGH.
+    fn read_high(&self) -> Result<bool, SensorError>;
+}
+
+impl<P: InputPin> DigitalInput for P {
+    fn read_high(&self) -> Result<bool, SensorError> {
+        self.is_high().map_err(|_| SensorError::IoError)
+    }
+}
+
+/// Calibración del modo analógico del MH-RD.
+///
+/// Los puntos de referencia se obtienen leyendo el valor crudo del ADC con la placa
+/// completamente seca y completamente mojada. En la mayoría de módulos el valor
+/// **disminuye** con el agua, pero se admite cualquier orden.
+///
+/// El estado pasa a HÚMEDO cuando la humedad alcanza `wet_threshold` y solo vuelve
+/// a SECO cuando baja de `dry_threshold`, evitando oscilaciones con gotas aisladas.
+#[derive(Debug, Clone, Copy)]
+pub struct MhRdCalibration {
+    /// Valor crudo del ADC con la placa seca (0 %).
+    pub dry_raw: u16,
+    /// Valor crudo del ADC con la placa mojada (100 %).
+    pub wet_raw: u16,
+    /// Porcentaje a partir del cual el estado pasa a HÚMEDO.
+    pub wet_threshold: f32,
+    /// Porcentaje por debajo del cual el estado vuelve a SECO.
+    pub dry_threshold: f32,
+}
+
+impl MhRdCalibration {
+    /// Crea una calibración con umbrales de histéresis por defecto (60 % / 40 %).
+    ///
+    /// # Parámetros
+    /// - `dry_raw`: valor crudo del ADC con la placa seca.
+    /// - `wet_raw`: valor crudo del ADC con la placa mojada.
+    pub fn new(dry_raw: u16, wet_raw: u16) -> Self {
+        Self {
+            dry_raw,
+            wet_raw,
+            wet_threshold: 60.0,
+            dry_threshold: 40.0,
+        }
+    }
+
+    /// Convierte un valor crudo del ADC en porcentaje de humedad (0–100 %).
+    ///
+    /// Los valores fuera de los puntos de referencia se recortan al rango válido.
+    pub fn wetness_percent(&self, raw: u16) -> f32 {
+        let span = self.wet_raw as f32 - self.dry_raw as f32;
+        let pct = (raw as f32 - self.dry_raw as f32) / span * 100.0;
+        // `+ 0.0` normaliza -0.0 (placa seca con referencias decrecientes) a 0.0
+        (pct + 0.0).clamp(0.0, 100.0)
+    }
+
+    /// Comprueba que la calibración sea coherente.
+    fn is_valid(&self) -> bool {
+        self.dry_raw != self.wet_raw
+            && (0.0..=100.0).contains(&self.dry_threshold)
+            && (0.0..=100.0).contains(&self.wet_threshold)
+            && self.dry_threshold < self.wet_threshold
+    }
 }
 
 impl MhRdSensor {
-    /// Crea un nuevo sensor de lluvia en el pin BCM indicado.
+    /// Crea un nuevo sensor de lluvia digital en el pin BCM indicado.
     ///
     /// # Parámetros
     /// - `pin`: número del pin BCM donde está conectado el sensor.
@@ -30,33 +118,121 @@ impl MhRdSensor {
     pub fn new(pin: u8, active_low: bool) -> Result<Self, SensorError> {
         // Inicializa el driver GPIO; mapea errores a SensorError::IoError
         let gpio = GpioDriver::new(pin).map_err(|_| SensorError::IoError)?;
-        Ok(Self { gpio, active_low })
+        Ok(Self::with_pin(gpio, active_low))
+    }
+
+    /// Crea un sensor de lluvia digital sobre cualquier pin de entrada de `embedded-hal`,
+    /// por ejemplo un [`ExpanderPin`](crate::drivers::expander::ExpanderPin) de un MCP23017.
+    ///
+    /// # Parámetros
+    /// - `pin`: pin conectado a la salida DO del módulo.
+    /// - `active_low`: `true` si el módulo es activo en LOW (pin LOW = mojado).
+    pub fn with_pin<P: InputPin + 'static>(pin: P, active_low: bool) -> Self {
+        Self { input: MhRdInput::Digital { gpio: Box::new(pin), active_low } }
+    }
+
+    /// Crea un nuevo sensor de lluvia analógico leído a través de un canal ADC.
+    ///
+    /// # Parámetros
+    /// - `adc`: canal del ADC conectado al pin AO del módulo
+    ///   (por ejemplo un [`Mcp3008Driver`](crate::drivers::mcp3008::Mcp3008Driver)).
+    /// - `calibration`: puntos de referencia seco/mojado y umbrales de histéresis.
+    ///
+    /// # Retorno
+    /// - `Ok(Self)`: sensor inicializado correctamente.
+    /// - `Err(SensorError::InitializationError)`: calibración incoherente
+    ///   (referencias iguales o umbrales invertidos).
+    pub fn new_analog(
+        adc: Box<dyn AnalogInput>,
+        calibration: MhRdCalibration,
+    ) -> Result<Self, SensorError> {
+        if !calibration.is_valid() || calibration.dry_raw.max(calibration.wet_raw) > adc.max_raw() {
+            return Err(SensorError::InitializationError);
+        }
+        Ok(Self { input: MhRdInput::Analog { adc, calibration, wet: false } })
+    }
+
+    /// Lee el porcentaje de humedad de la placa (solo en modo analógico).
+    ///
+    /// Útil durante la calibración junto con [`MhRdSensor::read_raw`].
+    ///
+    /// # Retorno
+    /// - `Ok(f32)`: humedad calibrada entre 0 y 100 %.
+    /// - `Err(SensorError::InitializationError)`: el sensor está en modo digital.
+    /// - `Err(SensorError)`: error al leer el ADC.
+    pub fn read_wetness(&mut self) -> Result<f32, SensorError> {
+        match &mut self.input {
+            MhRdInput::Analog { adc, calibration, .. } => {
+                Ok(calibration.wetness_percent(adc.read_raw()?))
+            }
+            MhRdInput::Digital { .. } => Err(SensorError::InitializationError),
+        }
+    }
+
+    /// Lee el valor crudo del ADC (solo en modo analógico).
+    ///
+    /// Permite obtener los puntos de referencia `dry_raw` y `wet_raw`.
+    ///
+    /// # Retorno
+    /// - `Ok(u16)`: cuentas del ADC.
+    /// - `Err(SensorError::InitializationError)`: el sensor está en modo digital.
+    pub fn read_raw(&mut self) -> Result<u16, SensorError> {
+        match &mut self.input {
+            MhRdInput::Analog { adc, .. } => adc.read_raw(),
+            MhRdInput::Digital { .. } => Err(SensorError::InitializationError),
+        }
     }
 }
 
+/// Texto del estado según presencia de agua.
+fn estado(wet: bool) -> &'static str {
+    if wet { "HÚMEDO" } else { "SECO" }
+}
+
 impl Sensor for MhRdSensor {
     type Output = SensorOutput;
 
     /// Lee el estado actual del sensor de lluvia.
     ///
-    /// # Lógica
-    /// 1. `read_bool()` devuelve `true` si el pin está en HIGH.
+    /// # Lógica (modo digital)
+    /// 1. Lee el pin DO: `true` si está en HIGH.
     /// 2. Si el sensor es `active_low`, entonces LOW indica **mojado**.
     /// 3. Devuelve `SensorOutput::Text` con "HÚMEDO" o "SECO".
     ///
+    /// # Lógica (modo analógico)
+    /// 1. Lee el ADC y calcula el porcentaje de humedad calibrado.
+    /// 2. Actualiza el estado con histéresis (`wet_threshold` / `dry_threshold`).
+    /// 3. Devuelve `SensorOutput::Text` con el estado y el porcentaje,
+    ///    por ejemplo `"HÚMEDO (72.5%)"`.
+    ///
     /// # Retorno
     /// - `Ok(SensorOutput)`: estado del sensor.
     /// - `Err(SensorError)`: error durante la lectura.
     fn read(&mut self) -> Result<Self::Output, SensorError> {
-        // Lee el estado lógico del pin (HIGH = true, LOW = false)
-        let raw_high = self.gpio.read_bool();
+        match &mut self.input {
+            MhRdInput::Digital { gpio, active_low } => {
+                // Lee el estado lógico del pin (HIGH = true, LOW = false)
+                let raw_high = gpio.read_high()?;
+
+                // Ajusta según si el sensor es active_low
+                let wet = if *active_low { !raw_high } else { raw_high };
+
+                Ok(SensorOutput::Text(estado(wet).to_string()))
+            }
+            MhRdInput::Analog { adc, calibration, wet } => {
+                let pct = calibration.wetness_percent(adc.read_raw()?);
 
-        // Ajusta según si el sensor es active_low
-        let wet = if self.active_low { !raw_high } else { raw_high };
+                // Histéresis: solo cambia de estado al cruzar el umbral opuesto
+                if *wet {
+                    if pct <= calibration.dry_threshold {
+                        *wet = false;
+                    }
+                } else if pct >= calibration.wet_threshold {
+                    *wet = true;
+                }
 
-        // Devuelve el estado como texto
-        Ok(SensorOutput::Text(
-            if wet { "HÚMEDO".to_string() } else { "SECO".to_string() }
-        ))
+                Ok(SensorOutput::Text(format!("{} ({:.1}%)", estado(*wet), pct)))
+            }
+        }
     }
 }
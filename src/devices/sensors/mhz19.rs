@@ -0,0 +1,195 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::serial::{SerialDriver, SerialPort};
+
+/// Velocidad del puerto serie del MH-Z19.
+pub const BAUD_RATE: u32 = 9600;
+/// Tiempo de precalentamiento indicado por el fabricante.
+pub const WARMUP: Duration = Duration::from_secs(180);
+
+const READ_CO2: u8 = 0x86;
+const ZERO_CALIBRATION: u8 = 0x87;
+const SPAN_CALIBRATION: u8 = 0x88;
+const SET_ABC: u8 = 0x79;
+const SET_RANGE: u8 = 0x99;
+
+/// Tiempo máximo de espera de la respuesta.
+const RESPONSE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Calcula el checksum de una trama MH-Z19: complemento a dos de la suma de los bytes 1–7.
+///
+/// # Ejemplo
+/// ```
+/// use lince::devices::sensors::mhz19::checksum;
+///
+/// // Comando de lectura del datasheet: FF 01 86 00 00 00 00 00 79
+/// assert_eq!(checksum(&[0xFF, 0x01, 0x86, 0, 0, 0, 0, 0, 0]), 0x79);
+/// ```
+pub fn checksum(frame: &[u8; 9]) -> u8 {
+    let sum = frame[1..8].iter().fold(0u8, |acc, b| acc.wrapping_add(*b));
+    (!sum).wrapping_add(1)
+}
+
+/// Construye una trama de comando de 9 bytes con su checksum.
+fn command_frame(command: u8, data: [u8; 5]) -> [u8; 9] {
+    let mut frame = [0xFF, 0x01, command, data[0], data[1], data[2], data[3], data[4], 0];
+    frame[8] = checksum(&frame);
+    frame
+}
+
+/// Lectura del MH-Z19.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mhz19Reading {
+    /// Concentración de CO2 en ppm.
+    pub co2_ppm: u16,
+    /// Temperatura interna aproximada del sensor (°C, ±2 °C).
+    pub temperature: i16,
+}
+
+/// Decodifica la respuesta al comando de lectura (`FF 86 HH LL TT SS UU UU CS`).
+///
+/// # Retorno
+/// - `Err(SensorError::InvalidData)` si la cabecera o el checksum no coinciden.
+pub fn decode_response(frame: &[u8; 9]) -> Result<Mhz19Reading, SensorError> {
+    if frame[0] != 0xFF || frame[1] != READ_CO2 || checksum(frame) != frame[8] {
+        return Err(SensorError::InvalidData);
+    }
+    Ok(Mhz19Reading {
+        co2_ppm: u16::from_be_bytes([frame[2], frame[3]]),
+        temperature: frame[4] as i16 - 40,
+    })
+}
+
+/// Implementación del sensor de CO2 NDIR **Winsen MH-Z19B/C** sobre UART.
+///
+/// El protocolo usa tramas de 9 bytes (`FF 01 <cmd> ... <checksum>`). Incluye
+/// control de la calibración automática de línea base (ABC), calibración de cero
+/// y de span, y rango de detección.
+///
+/// Durante los 3 minutos de precalentamiento el sensor devuelve valores fijos
+/// (410 o 500 ppm), por lo que las lecturas se rechazan con
+/// `SensorError::InitializationError` hasta que termina (ver [`Mhz19Sensor::set_warmup`]).
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::mhz19::Mhz19Sensor;
+///
+/// let mut sensor = Mhz19Sensor::new("/dev/serial0").unwrap();
+/// sensor.set_abc(false).unwrap();            // Interiores sin ventilación diaria
+/// println!("{:?}", sensor.read());           // Int(612)
+/// ```
+pub struct Mhz19Sensor<S = SerialDriver> {
+    /// Puerto serie donde está conectado el sensor.
+    port: S,
+    /// Momento de la creación (inicio del precalentamiento).
+    started: Instant,
+    /// Duración del precalentamiento.
+    warmup: Duration,
+}
+
+impl Mhz19Sensor<SerialDriver> {
+    /// Abre el sensor en un puerto serie del sistema (9600 8N1).
+    ///
+    /// # Parámetros
+    /// - `path`: ruta del puerto (`"/dev/serial0"` en la Raspberry Pi).
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, SensorError> {
+        Ok(Self::with_port(SerialDriver::new(path, BAUD_RATE)?))
+    }
+}
+
+impl<S: SerialPort> Mhz19Sensor<S> {
+    /// Crea el sensor sobre cualquier puerto serie.
+    ///
+    /// El precalentamiento comienza en este momento; si el sensor ya llevaba
+    /// alimentado un tiempo, usar `set_warmup(Duration::ZERO)`.
+    pub fn with_port(port: S) -> Self {
+        Self {
+            port,
+            started: Instant::now(),
+            warmup: WARMUP,
+        }
+    }
+
+    /// Cambia la duración del precalentamiento ([`WARMUP`] por defecto).
+    pub fn set_warmup(&mut self, warmup: Duration) {
+        self.warmup = warmup;
+    }
+
+    /// Tiempo restante de precalentamiento (`Duration::ZERO` si ya terminó).
+    pub fn warmup_remaining(&self) -> Duration {
+        self.warmup.saturating_sub(self.started.elapsed())
+    }
+
+    /// Activa o desactiva la calibración automática de línea base (ABC).
+    ///
+    /// Con ABC el sensor asume que cada 24 h alcanza aire exterior (~400 ppm). Debe
+    /// desactivarse en recintos que nunca se ventilan por completo.
+    pub fn set_abc(&mut self, enabled: bool) -> Result<(), SensorError> {
+        self.send(SET_ABC, [if enabled { 0xA0 } else { 0x00 }, 0, 0, 0, 0])
+    }
+
+    /// Calibra el punto cero (400 ppm).
+    ///
+    /// El sensor debe haber estado al menos 20 minutos en aire exterior limpio.
+    pub fn calibrate_zero(&mut self) -> Result<(), SensorError> {
+        self.send(ZERO_CALIBRATION, [0; 5])
+    }
+
+    /// Calibra el span con un gas de referencia de `ppm` (tras calibrar el cero).
+    pub fn calibrate_span(&mut self, ppm: u16) -> Result<(), SensorError> {
+        let [high, low] = ppm.to_be_bytes();
+        self.send(SPAN_CALIBRATION, [high, low, 0, 0, 0])
+    }
+
+    /// Configura el rango de detección (2000, 5000 o 10000 ppm).
+    pub fn set_detection_range(&mut self, ppm: u16) -> Result<(), SensorError> {
+        if ![2000, 5000, 10000].contains(&ppm) {
+            return Err(SensorError::InitializationError);
+        }
+        let [high, low] = ppm.to_be_bytes();
+        self.send(SET_RANGE, [0, 0, 0, high, low])
+    }
+
+    /// Devuelve el puerto serie, consumiendo el sensor.
+    pub fn release(self) -> S {
+        self.port
+    }
+
+    /// Lee la concentración de CO2 y la temperatura interna.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` durante el precalentamiento.
+    /// - `Err(SensorError::Timeout)` si el sensor no responde.
+    /// - `Err(SensorError::InvalidData)` si la respuesta es inválida.
+    pub fn read_measurement(&mut self) -> Result<Mhz19Reading, SensorError> {
+        let remaining = self.warmup_remaining();
+        if !remaining.is_zero() {
+            eprintln!("[MHZ19] Precalentando, faltan {} s", remaining.as_secs());
+            return Err(SensorError::InitializationError);
+        }
+
+        self.send(READ_CO2, [0; 5])?;
+        let mut frame = [0u8; 9];
+        self.port.read_exact_timeout(&mut frame, RESPONSE_TIMEOUT)?;
+        decode_response(&frame)
+    }
+
+    fn send(&mut self, command: u8, data: [u8; 5]) -> Result<(), SensorError> {
+        self.port.clear_input()?;
+        self.port.write_all(&command_frame(command, data))
+    }
+}
+
+impl<S: SerialPort> Sensor for Mhz19Sensor<S> {
+    /// Tipo de salida: [`SensorOutput`] (CO2 en ppm).
+    type Output = SensorOutput;
+
+    /// Lee la concentración de CO2 como `SensorOutput::Int` (ppm).
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Int(self.read_measurement()?.co2_ppm as i64))
+    }
+}
@@ -2,4 +2,29 @@ pub mod mhrd;
 pub mod ds18b20;
 pub mod dht22;
 pub mod dht11;
-pub mod dht;
\ No newline at end of file
+pub mod dht;
+pub mod psychrometric;
+pub mod simulated;
+pub mod replay;
+pub mod bme280;
+pub mod sht;
+pub mod hcsr04;
+pub mod hx711;
+pub mod pulse_counter;
+pub mod system;
+pub mod sysfs;
+pub mod bh1750;
+pub mod tsl2561;
+pub mod mhz19;
+pub mod senseair_s8;
+pub mod particulate;
+pub mod gps;
+pub mod ina;
+pub mod analog;
+pub mod modbus;
+pub mod process;
+pub mod w1;
+pub mod ds2413;
+pub mod ds2438;
+pub mod ds2408;
+pub mod discovery;
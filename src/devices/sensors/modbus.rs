@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::modbus::ModbusClient;
+
+/// Tabla de registros a leer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegisterKind {
+    /// Registros de retención (función 0x03).
+    Holding,
+    /// Registros de entrada (función 0x04).
+    Input,
+}
+
+/// Tipo de dato almacenado en los registros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegisterFormat {
+    /// Entero sin signo de 16 bits (1 registro).
+    U16,
+    /// Entero con signo de 16 bits (1 registro).
+    I16,
+    /// Entero sin signo de 32 bits (2 registros).
+    U32,
+    /// Entero con signo de 32 bits (2 registros).
+    I32,
+    /// Coma flotante IEEE-754 de 32 bits (2 registros).
+    F32,
+}
+
+impl RegisterFormat {
+    /// Número de registros de 16 bits que ocupa el valor.
+    pub fn registers(self) -> u16 {
+        match self {
+            RegisterFormat::U16 | RegisterFormat::I16 => 1,
+            RegisterFormat::U32 | RegisterFormat::I32 | RegisterFormat::F32 => 2,
+        }
+    }
+}
+
+/// Orden de las palabras en los valores de 32 bits.
+///
+/// Dentro de cada registro los bytes siempre son big-endian; lo que varía entre
+/// fabricantes es qué registro contiene la palabra alta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WordOrder {
+    /// Palabra alta en el primer registro (ABCD).
+    #[default]
+    HighFirst,
+    /// Palabra baja en el primer registro (CDAB).
+    LowFirst,
+}
+
+/// Descripción de un valor leído de un dispositivo Modbus.
+///
+/// Se puede declarar en la configuración TOML:
+///
+/// ```toml
+/// [[registers]]
+/// name = "Tensión"
+/// kind = "Input"
+/// address = 0
+/// format = "F32"
+/// word_order = "HighFirst"
+/// scale = 1.0
+/// unit = "V"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModbusRegister {
+    /// Nombre del valor en la salida.
+    pub name: String,
+    /// Tabla de registros.
+    pub kind: RegisterKind,
+    /// Dirección del primer registro (base 0).
+    pub address: u16,
+    /// Tipo de dato.
+    pub format: RegisterFormat,
+    /// Orden de palabras para valores de 32 bits.
+    #[serde(default)]
+    pub word_order: WordOrder,
+    /// Factor aplicado al valor crudo (`valor = crudo * scale`).
+    #[serde(default = "default_scale")]
+    pub scale: f64,
+    /// Unidad del valor (solo informativa).
+    #[serde(default)]
+    pub unit: String,
+}
+
+fn default_scale() -> f64 {
+    1.0
+}
+
+impl ModbusRegister {
+    /// Crea la descripción de un valor con orden de palabras ABCD y escala 1.
+    pub fn new(name: &str, kind: RegisterKind, address: u16, format: RegisterFormat) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            address,
+            format,
+            word_order: WordOrder::HighFirst,
+            scale: 1.0,
+            unit: String::new(),
+        }
+    }
+
+    /// Cambia el orden de palabras.
+    pub fn with_word_order(mut self, word_order: WordOrder) -> Self {
+        self.word_order = word_order;
+        self
+    }
+
+    /// Cambia el factor de escala.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Cambia la unidad.
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = unit.to_string();
+        self
+    }
+
+    /// Decodifica los registros leídos y aplica la escala.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use lince::devices::sensors::modbus::{ModbusRegister, RegisterFormat, RegisterKind, WordOrder};
+    ///
+    /// let reg = ModbusRegister::new("Energía", RegisterKind::Holding, 0, RegisterFormat::U32)
+    ///     .with_word_order(WordOrder::LowFirst)
+    ///     .with_scale(0.01);
+    /// assert_eq!(reg.decode(&[0x86A0, 0x0001]), Some(1000.0));
+    /// ```
+    ///
+    /// # Retorna
+    /// - `None` si el número de registros no corresponde al formato o el valor no es finito.
+    pub fn decode(&self, words: &[u16]) -> Option<f64> {
+        if words.len() != self.format.registers() as usize {
+            return None;
+        }
+        let bits = match (words, self.word_order) {
+            ([w], _) => *w as u32,
+            ([hi, lo], WordOrder::HighFirst) | ([lo, hi], WordOrder::LowFirst) => {
+                ((*hi as u32) << 16) | *lo as u32
+            }
+            _ => return None,
+        };
+        let raw = match self.format {
+            RegisterFormat::U16 => bits as f64,
+            RegisterFormat::I16 => bits as u16 as i16 as f64,
+            RegisterFormat::U32 => bits as f64,
+            RegisterFormat::I32 => bits as i32 as f64,
+            RegisterFormat::F32 => f32::from_bits(bits) as f64,
+        };
+        let value = raw * self.scale;
+        value.is_finite().then_some(value)
+    }
+}
+
+/// ModbusSensor: sensor configurable sobre un dispositivo **Modbus** (RTU o TCP).
+///
+/// Lee una lista de registros de un contador de energía, PLC, variador, etc. y los
+/// convierte en valores físicos. Con un solo registro devuelve `SensorOutput::Float`;
+/// con varios, un texto `"nombre: valor unidad, ..."`.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::modbus::{ModbusRegister, ModbusSensor, RegisterFormat, RegisterKind};
+/// use lince::drivers::modbus::ModbusRtuClient;
+///
+/// let bus = ModbusRtuClient::new("/dev/ttyUSB0", 9600).unwrap();
+/// let registers = vec![
+///     ModbusRegister::new("Tensión", RegisterKind::Input, 0x0000, RegisterFormat::F32).with_unit("V"),
+///     ModbusRegister::new("Potencia", RegisterKind::Input, 0x000C, RegisterFormat::F32).with_unit("W"),
+/// ];
+/// let mut meter = ModbusSensor::new(bus, 1, registers).unwrap();
+/// println!("{:?}", meter.read());  // Text("Tensión: 230.1 V, Potencia: 412 W")
+/// ```
+pub struct ModbusSensor<C> {
+    /// Cliente Modbus (RTU o TCP).
+    client: C,
+    /// Dirección de la unidad esclava.
+    unit: u8,
+    /// Valores a leer.
+    registers: Vec<ModbusRegister>,
+}
+
+impl<C: ModbusClient> ModbusSensor<C> {
+    /// Crea el sensor.
+    ///
+    /// # Parámetros
+    /// - `client`: cliente Modbus ya conectado.
+    /// - `unit`: dirección de la unidad (1–247; en TCP suele ser 1 o 255).
+    /// - `registers`: valores a leer, en el orden de la salida.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si la lista está vacía.
+    pub fn new(client: C, unit: u8, registers: Vec<ModbusRegister>) -> Result<Self, SensorError> {
+        if registers.is_empty() {
+            return Err(SensorError::InitializationError);
+        }
+        Ok(Self { client, unit, registers })
+    }
+
+    /// Valores configurados.
+    pub fn registers(&self) -> &[ModbusRegister] {
+        &self.registers
+    }
+
+    /// Acceso al cliente, por ejemplo para escribir registros de configuración.
+    pub fn client_mut(&mut self) -> &mut C {
+        &mut self.client
+    }
+
+    /// Devuelve el cliente, consumiendo el sensor.
+    pub fn release(self) -> C {
+        self.client
+    }
+
+    /// Lee un valor.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::DeviceFault)` si el dispositivo responde con una excepción.
+    /// - `Err(SensorError::InvalidData)` si el valor no se puede decodificar.
+    pub fn read_register(&mut self, register: &ModbusRegister) -> Result<f64, SensorError> {
+        Self::fetch(&mut self.client, self.unit, register)
+    }
+
+    /// Lee todos los valores configurados como pares `(nombre, valor)`.
+    pub fn read_values(&mut self) -> Result<Vec<(String, f64)>, SensorError> {
+        let Self { client, unit, registers } = self;
+        registers
+            .iter()
+            .map(|reg| Ok((reg.name.clone(), Self::fetch(client, *unit, reg)?)))
+            .collect()
+    }
+
+    /// Pide al esclavo los registros de un valor y los decodifica.
+    fn fetch(client: &mut C, unit: u8, register: &ModbusRegister) -> Result<f64, SensorError> {
+        let count = register.format.registers();
+        let words = match register.kind {
+            RegisterKind::Holding => client.read_holding_registers(unit, register.address, count)?,
+            RegisterKind::Input => client.read_input_registers(unit, register.address, count)?,
+        };
+        register.decode(&words).ok_or(SensorError::InvalidData)
+    }
+}
+
+impl<C: ModbusClient> Sensor for ModbusSensor<C> {
+    type Output = SensorOutput;
+
+    /// Lee los registros configurados.
+    ///
+    /// Los valores del texto se escriben con la precisión de `f32`, la misma de
+    /// `SensorOutput::Float`: un `230.1` en `F32` sale como `230.1` y no como
+    /// `230.10000610351562`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let values = self.read_values()?;
+        if let [(_, value)] = values.as_slice() {
+            return Ok(SensorOutput::Float(*value as f32));
+        }
+        let text = values
+            .iter()
+            .zip(&self.registers)
+            .map(|((name, value), reg)| {
+                if reg.unit.is_empty() {
+                    format!("{}: {}", name, *value as f32)
+                } else {
+                    format!("{}: {} {}", name, *value as f32, reg.unit)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        Ok(SensorOutput::Text(text))
+    }
+}
@@ -0,0 +1,421 @@
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::serial::{SerialDriver, SerialPort};
+
+/// Velocidad del puerto serie de PMS5003 y SDS011.
+pub const BAUD_RATE: u32 = 9600;
+/// Tiempo recomendado de ventilador tras despertar antes de leer.
+pub const DEFAULT_SPIN_UP: Duration = Duration::from_secs(30);
+
+/// Tiempo máximo de espera de una trama (el PMS5003 envía cada 0.2–2.3 s).
+const FRAME_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Número de partículas por 0.1 L de aire con diámetro mayor que cada umbral (µm).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParticleCounts {
+    pub over_0_3: u16,
+    pub over_0_5: u16,
+    pub over_1_0: u16,
+    pub over_2_5: u16,
+    pub over_5_0: u16,
+    pub over_10: u16,
+}
+
+/// Lectura de un sensor de partículas (concentraciones en µg/m³).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticulateReading {
+    /// PM1.0 (solo PMS5003).
+    pub pm1_0: Option<f32>,
+    /// PM2.5.
+    pub pm2_5: f32,
+    /// PM10.
+    pub pm10: f32,
+    /// Recuento de partículas (solo PMS5003).
+    pub counts: Option<ParticleCounts>,
+}
+
+impl ParticulateReading {
+    /// Texto con el formato de salida de los sensores de partículas.
+    fn to_text(self) -> String {
+        match self.pm1_0 {
+            Some(pm1_0) => format!(
+                "PM1.0: {:.1} µg/m³, PM2.5: {:.1} µg/m³, PM10: {:.1} µg/m³",
+                pm1_0, self.pm2_5, self.pm10
+            ),
+            None => format!("PM2.5: {:.1} µg/m³, PM10: {:.1} µg/m³", self.pm2_5, self.pm10),
+        }
+    }
+}
+
+/// Descarta bytes hasta encontrar la cabecera de dos bytes `header`.
+///
+/// # Retorno
+/// - `Err(SensorError::Timeout)` si no aparece antes de `deadline`.
+fn sync_header<S: SerialPort>(port: &mut S, header: [u8; 2], deadline: Instant) -> Result<(), SensorError> {
+    let mut previous = None;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let mut byte = [0u8; 1];
+        port.read_exact_timeout(&mut byte, remaining)?;
+        if previous == Some(header[0]) && byte[0] == header[1] {
+            return Ok(());
+        }
+        previous = Some(byte[0]);
+    }
+}
+
+// --------------------------------------------------------------------
+// Plantower PMS5003
+// --------------------------------------------------------------------
+
+const PMS_HEADER: [u8; 2] = [0x42, 0x4D];
+/// Longitud declarada de una trama de datos (13 palabras + checksum).
+const PMS_DATA_LENGTH: u16 = 28;
+
+const PMS_CMD_READ: u8 = 0xE2;
+const PMS_CMD_MODE: u8 = 0xE1;
+const PMS_CMD_SLEEP: u8 = 0xE4;
+
+/// Decodifica una trama de datos completa de 32 bytes del PMS5003.
+///
+/// Usa las concentraciones "atmosféricas" (palabras 4–6), apropiadas para aire ambiente.
+///
+/// # Retorno
+/// - `Err(SensorError::InvalidData)` si la cabecera, la longitud o el checksum no coinciden.
+pub fn decode_pms5003(frame: &[u8; 32]) -> Result<ParticulateReading, SensorError> {
+    let word = |i: usize| u16::from_be_bytes([frame[2 + 2 * i], frame[3 + 2 * i]]);
+    let sum = frame[..30].iter().map(|b| *b as u16).fold(0u16, u16::wrapping_add);
+
+    if frame[..2] != PMS_HEADER || word(0) != PMS_DATA_LENGTH || sum != word(14) {
+        return Err(SensorError::InvalidData);
+    }
+
+    Ok(ParticulateReading {
+        pm1_0: Some(word(4) as f32),
+        pm2_5: word(5) as f32,
+        pm10: word(6) as f32,
+        counts: Some(ParticleCounts {
+            over_0_3: word(7),
+            over_0_5: word(8),
+            over_1_0: word(9),
+            over_2_5: word(10),
+            over_5_0: word(11),
+            over_10: word(12),
+        }),
+    })
+}
+
+/// Implementación del sensor láser de partículas **Plantower PMS5003** sobre UART.
+///
+/// Decodifica las tramas binarias de 32 bytes (PM1.0, PM2.5, PM10 y recuentos por
+/// tamaño) y verifica su checksum. Admite los modos activo (el sensor emite tramas
+/// continuamente) y pasivo (bajo petición), además de dormir el láser y el ventilador
+/// entre lecturas para alargar su vida útil (ver [`Pms5003Sensor::set_duty_cycle`]).
+///
+/// # Ejemplo
+/// ```no_run
+/// use std::time::Duration;
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::particulate::Pms5003Sensor;
+///
+/// let mut sensor = Pms5003Sensor::new("/dev/serial0").unwrap();
+/// sensor.set_duty_cycle(Some(Duration::from_secs(30))).unwrap();
+/// println!("{:?}", sensor.read()); // "PM1.0: 3.0 µg/m³, PM2.5: 5.0 µg/m³, PM10: 7.0 µg/m³"
+/// ```
+pub struct Pms5003Sensor<S = SerialDriver> {
+    /// Puerto serie donde está conectado el sensor.
+    port: S,
+    /// `true` si el sensor está en modo pasivo.
+    passive: bool,
+    /// Tiempo de ventilador tras despertar; `None` mantiene el sensor siempre despierto.
+    duty_cycle: Option<Duration>,
+}
+
+impl Pms5003Sensor<SerialDriver> {
+    /// Abre el sensor en un puerto serie del sistema (9600 8N1), en modo activo.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, SensorError> {
+        Ok(Self::with_port(SerialDriver::new(path, BAUD_RATE)?))
+    }
+}
+
+impl<S: SerialPort> Pms5003Sensor<S> {
+    /// Crea el sensor sobre cualquier puerto serie. Se asume el modo activo de fábrica.
+    pub fn with_port(port: S) -> Self {
+        Self {
+            port,
+            passive: false,
+            duty_cycle: None,
+        }
+    }
+
+    /// Cambia entre modo pasivo (lecturas bajo petición) y activo.
+    pub fn set_passive_mode(&mut self, passive: bool) -> Result<(), SensorError> {
+        self.command(PMS_CMD_MODE, if passive { 0 } else { 1 })?;
+        self.passive = passive;
+        Ok(())
+    }
+
+    /// Duerme el sensor (apaga láser y ventilador).
+    pub fn sleep(&mut self) -> Result<(), SensorError> {
+        self.command(PMS_CMD_SLEEP, 0)
+    }
+
+    /// Despierta el sensor. Las lecturas son estables tras ~30 s de ventilador.
+    pub fn wake(&mut self) -> Result<(), SensorError> {
+        self.command(PMS_CMD_SLEEP, 1)
+    }
+
+    /// Activa el ciclo de trabajo: cada lectura despierta el sensor, espera `spin_up`,
+    /// lee y lo vuelve a dormir. Con `None` el sensor permanece despierto.
+    ///
+    /// Al activarlo el sensor se duerme inmediatamente.
+    pub fn set_duty_cycle(&mut self, spin_up: Option<Duration>) -> Result<(), SensorError> {
+        self.duty_cycle = spin_up;
+        if spin_up.is_some() {
+            self.sleep()
+        } else {
+            self.wake()
+        }
+    }
+
+    /// Devuelve el puerto serie, consumiendo el sensor.
+    pub fn release(self) -> S {
+        self.port
+    }
+
+    /// Obtiene una lectura, aplicando el ciclo de trabajo si está activo.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si no llega ninguna trama.
+    /// - `Err(SensorError::InvalidData)` si el checksum es incorrecto.
+    pub fn read_measurement(&mut self) -> Result<ParticulateReading, SensorError> {
+        let Some(spin_up) = self.duty_cycle else {
+            return self.read_frame();
+        };
+
+        self.wake()?;
+        thread::sleep(spin_up);
+        let result = self.read_frame();
+        self.sleep()?;
+        result
+    }
+
+    /// Espera la siguiente trama de datos (pidiéndola en modo pasivo).
+    fn read_frame(&mut self) -> Result<ParticulateReading, SensorError> {
+        if self.passive {
+            self.command(PMS_CMD_READ, 0)?;
+        } else {
+            self.port.clear_input()?;
+        }
+
+        let deadline = Instant::now() + FRAME_TIMEOUT;
+        loop {
+            sync_header(&mut self.port, PMS_HEADER, deadline)?;
+            let mut length = [0u8; 2];
+            self.port.read_exact_timeout(&mut length, FRAME_TIMEOUT)?;
+            let length = u16::from_be_bytes(length);
+
+            if length != PMS_DATA_LENGTH {
+                // Respuesta a un comando (p. ej. 42 4D 00 04 E4 ...): se descarta
+                let mut skip = vec![0u8; length as usize];
+                self.port.read_exact_timeout(&mut skip, FRAME_TIMEOUT)?;
+                continue;
+            }
+
+            let mut frame = [0u8; 32];
+            frame[..2].copy_from_slice(&PMS_HEADER);
+            frame[2..4].copy_from_slice(&length.to_be_bytes());
+            self.port.read_exact_timeout(&mut frame[4..], FRAME_TIMEOUT)?;
+            return decode_pms5003(&frame).inspect_err(|_| {
+                eprintln!("[PMS5003] Checksum inválido");
+            });
+        }
+    }
+
+    /// Envía un comando de 7 bytes: `42 4D CMD 00 DATO LRC_H LRC_L`.
+    fn command(&mut self, command: u8, data: u8) -> Result<(), SensorError> {
+        let mut frame = [0x42, 0x4D, command, 0x00, data, 0, 0];
+        let sum = frame[..5].iter().map(|b| *b as u16).sum::<u16>();
+        frame[5..].copy_from_slice(&sum.to_be_bytes());
+        self.port.clear_input()?;
+        self.port.write_all(&frame)
+    }
+}
+
+impl<S: SerialPort> Sensor for Pms5003Sensor<S> {
+    /// Tipo de salida: [`SensorOutput`] (texto con PM1.0, PM2.5 y PM10).
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Text(self.read_measurement()?.to_text()))
+    }
+}
+
+// --------------------------------------------------------------------
+// Nova Fitness SDS011
+// --------------------------------------------------------------------
+
+const SDS_HEADER: [u8; 2] = [0xAA, 0xC0];
+const SDS_TAIL: u8 = 0xAB;
+
+const SDS_CMD_REPORTING_MODE: u8 = 0x02;
+const SDS_CMD_QUERY: u8 = 0x04;
+const SDS_CMD_SLEEP: u8 = 0x06;
+
+/// Decodifica una trama de datos de 10 bytes del SDS011 (`AA C0 ... CS AB`).
+///
+/// # Retorno
+/// - `Err(SensorError::InvalidData)` si la cabecera, la cola o el checksum no coinciden.
+pub fn decode_sds011(frame: &[u8; 10]) -> Result<ParticulateReading, SensorError> {
+    let sum = frame[2..8].iter().fold(0u8, |acc, b| acc.wrapping_add(*b));
+    if frame[..2] != SDS_HEADER || frame[9] != SDS_TAIL || sum != frame[8] {
+        return Err(SensorError::InvalidData);
+    }
+
+    Ok(ParticulateReading {
+        pm1_0: None,
+        pm2_5: u16::from_le_bytes([frame[2], frame[3]]) as f32 / 10.0,
+        pm10: u16::from_le_bytes([frame[4], frame[5]]) as f32 / 10.0,
+        counts: None,
+    })
+}
+
+/// Implementación del sensor láser de partículas **Nova Fitness SDS011** sobre UART.
+///
+/// Decodifica las tramas de 10 bytes (PM2.5 y PM10 con resolución de 0.1 µg/m³) y
+/// verifica su checksum. Admite el modo de consulta (bajo petición) y dormir el
+/// sensor entre lecturas; el láser tiene una vida útil de ~8000 h en funcionamiento
+/// continuo.
+///
+/// # Ejemplo
+/// ```no_run
+/// use std::time::Duration;
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::particulate::Sds011Sensor;
+///
+/// let mut sensor = Sds011Sensor::new("/dev/ttyUSB0").unwrap();
+/// sensor.set_query_mode(true).unwrap();
+/// sensor.set_duty_cycle(Some(Duration::from_secs(30))).unwrap();
+/// println!("{:?}", sensor.read()); // "PM2.5: 4.2 µg/m³, PM10: 7.9 µg/m³"
+/// ```
+pub struct Sds011Sensor<S = SerialDriver> {
+    /// Puerto serie donde está conectado el sensor.
+    port: S,
+    /// `true` si el sensor está en modo de consulta.
+    query_mode: bool,
+    /// Tiempo de ventilador tras despertar; `None` mantiene el sensor siempre despierto.
+    duty_cycle: Option<Duration>,
+}
+
+impl Sds011Sensor<SerialDriver> {
+    /// Abre el sensor en un puerto serie del sistema (9600 8N1), en modo activo.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, SensorError> {
+        Ok(Self::with_port(SerialDriver::new(path, BAUD_RATE)?))
+    }
+}
+
+impl<S: SerialPort> Sds011Sensor<S> {
+    /// Crea el sensor sobre cualquier puerto serie. Se asume el modo activo de fábrica.
+    pub fn with_port(port: S) -> Self {
+        Self {
+            port,
+            query_mode: false,
+            duty_cycle: None,
+        }
+    }
+
+    /// Cambia entre modo de consulta (lecturas bajo petición) y activo (una trama por segundo).
+    pub fn set_query_mode(&mut self, query: bool) -> Result<(), SensorError> {
+        self.command(SDS_CMD_REPORTING_MODE, [0x01, query as u8])?;
+        self.query_mode = query;
+        Ok(())
+    }
+
+    /// Duerme el sensor (apaga láser y ventilador).
+    pub fn sleep(&mut self) -> Result<(), SensorError> {
+        self.command(SDS_CMD_SLEEP, [0x01, 0x00])
+    }
+
+    /// Despierta el sensor. Las lecturas son estables tras ~30 s de ventilador.
+    pub fn wake(&mut self) -> Result<(), SensorError> {
+        self.command(SDS_CMD_SLEEP, [0x01, 0x01])
+    }
+
+    /// Activa el ciclo de trabajo: cada lectura despierta el sensor, espera `spin_up`,
+    /// lee y lo vuelve a dormir. Con `None` el sensor permanece despierto.
+    ///
+    /// Al activarlo el sensor se duerme inmediatamente.
+    pub fn set_duty_cycle(&mut self, spin_up: Option<Duration>) -> Result<(), SensorError> {
+        self.duty_cycle = spin_up;
+        if spin_up.is_some() {
+            self.sleep()
+        } else {
+            self.wake()
+        }
+    }
+
+    /// Devuelve el puerto serie, consumiendo el sensor.
+    pub fn release(self) -> S {
+        self.port
+    }
+
+    /// Obtiene una lectura, aplicando el ciclo de trabajo si está activo.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si no llega ninguna trama.
+    /// - `Err(SensorError::InvalidData)` si el checksum es incorrecto.
+    pub fn read_measurement(&mut self) -> Result<ParticulateReading, SensorError> {
+        let Some(spin_up) = self.duty_cycle else {
+            return self.read_frame();
+        };
+
+        self.wake()?;
+        thread::sleep(spin_up);
+        let result = self.read_frame();
+        self.sleep()?;
+        result
+    }
+
+    /// Espera la siguiente trama de datos (pidiéndola en modo de consulta).
+    fn read_frame(&mut self) -> Result<ParticulateReading, SensorError> {
+        if self.query_mode {
+            self.command(SDS_CMD_QUERY, [0x00, 0x00])?;
+        } else {
+            self.port.clear_input()?;
+        }
+
+        // Las respuestas a comandos (AA C5 ...) no coinciden con la cabecera y se descartan
+        sync_header(&mut self.port, SDS_HEADER, Instant::now() + FRAME_TIMEOUT)?;
+        let mut frame = [0u8; 10];
+        frame[..2].copy_from_slice(&SDS_HEADER);
+        self.port.read_exact_timeout(&mut frame[2..], FRAME_TIMEOUT)?;
+        decode_sds011(&frame).inspect_err(|_| {
+            eprintln!("[SDS011] Checksum inválido");
+        })
+    }
+
+    /// Envía un comando de 19 bytes a todos los sensores (ID `FFFF`).
+    fn command(&mut self, command: u8, data: [u8; 2]) -> Result<(), SensorError> {
+        let mut frame = [0u8; 19];
+        frame[..5].copy_from_slice(&[0xAA, 0xB4, command, data[0], data[1]]);
+        frame[15] = 0xFF;
+        frame[16] = 0xFF;
+        frame[17] = frame[2..17].iter().fold(0u8, |acc, b| acc.wrapping_add(*b));
+        frame[18] = SDS_TAIL;
+        self.port.clear_input()?;
+        self.port.write_all(&frame)
+    }
+}
+
+impl<S: SerialPort> Sensor for Sds011Sensor<S> {
+    /// Tipo de salida: [`SensorOutput`] (texto con PM2.5 y PM10).
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Text(self.read_measurement()?.to_text()))
+    }
+}
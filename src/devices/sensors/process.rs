@@ -0,0 +1,377 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+
+/// Tiempo de espera por defecto de una lectura.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reinicios consecutivos permitidos por defecto en modo persistente.
+pub const DEFAULT_MAX_RESTARTS: u32 = 3;
+
+/// Líneas de stderr conservadas por defecto.
+const STDERR_LINES: usize = 50;
+
+/// Forma de ejecutar el programa externo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessMode {
+    /// Se lanza el programa en cada lectura; su salida completa es una lectura.
+    OneShot,
+    /// El programa queda en ejecución y escribe una lectura JSON por línea.
+    ///
+    /// Si `request` está definido, se escribe (con salto de línea) en su stdin antes de
+    /// cada lectura; si no, se usa la línea más reciente que haya emitido.
+    LongLived {
+        /// Petición enviada por stdin en cada lectura.
+        request: Option<String>,
+    },
+}
+
+/// Convierte una lectura JSON del programa externo en [`SensorOutput`].
+///
+/// Protocolo:
+/// - Escalares: `true` → `Bool`, `42` → `Int`, `21.5` → `Float`, `"SECO"` → `Text`.
+/// - `{"value": <escalar>}` → el escalar.
+/// - `{"error": "timeout" | "invalid_data" | "device_fault" | ...}` → el `SensorError`
+///   correspondiente (cualquier otro texto es `IoError`).
+/// - Cualquier otro objeto → `Text` con sus campos `"clave: valor"` separados por comas,
+///   en orden alfabético.
+///
+/// # Ejemplo
+/// ```
+/// use lince::core::{SensorError, SensorOutput};
+/// use lince::devices::sensors::process::parse_reading;
+///
+/// assert_eq!(parse_reading("21.5").unwrap(), SensorOutput::Float(21.5));
+/// assert_eq!(parse_reading(r#"{"value": 412}"#).unwrap(), SensorOutput::Int(412));
+/// assert_eq!(
+///     parse_reading(r#"{"temp": 21.5, "hum": 40}"#).unwrap(),
+///     SensorOutput::Text("hum: 40, temp: 21.5".to_string())
+/// );
+/// assert!(matches!(parse_reading(r#"{"error": "timeout"}"#), Err(SensorError::Timeout)));
+/// ```
+pub fn parse_reading(line: &str) -> Result<SensorOutput, SensorError> {
+    let value: Value = serde_json::from_str(line.trim()).map_err(|_| SensorError::InvalidData)?;
+    match value {
+        Value::Object(mut fields) => {
+            if let Some(error) = fields.get("error") {
+                return Err(match error.as_str() {
+                    Some("timeout") => SensorError::Timeout,
+                    Some("invalid_data") => SensorError::InvalidData,
+                    Some("device_fault") => SensorError::DeviceFault,
+                    Some("initialization") => SensorError::InitializationError,
+                    _ => SensorError::IoError,
+                });
+            }
+            if fields.len() == 1 {
+                if let Some(value) = fields.remove("value") {
+                    return scalar(value);
+                }
+            }
+            let text = fields
+                .iter()
+                .map(|(k, v)| match v {
+                    Value::String(s) => format!("{}: {}", k, s),
+                    other => format!("{}: {}", k, other),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            Ok(SensorOutput::Text(text))
+        }
+        other => scalar(other),
+    }
+}
+
+fn scalar(value: Value) -> Result<SensorOutput, SensorError> {
+    match value {
+        Value::Bool(b) => Ok(SensorOutput::Bool(b)),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => Ok(SensorOutput::Int(i)),
+            None => n.as_f64().map(|f| SensorOutput::Float(f as f32)).ok_or(SensorError::InvalidData),
+        },
+        Value::String(s) => Ok(SensorOutput::Text(s)),
+        _ => Err(SensorError::InvalidData),
+    }
+}
+
+/// Programa persistente en ejecución.
+struct Running {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    /// Líneas de stdout recibidas por el hilo lector.
+    lines: Receiver<String>,
+}
+
+/// ProcessSensor: sensor implementado por un **programa externo**.
+///
+/// Permite integrar sensores que solo tienen drivers en Python o herramientas del
+/// fabricante sin escribir Rust: el programa escribe sus lecturas en stdout como JSON
+/// (ver [`parse_reading`]) y lince las entrega a través del trait [`Sensor`].
+///
+/// - En modo [`ProcessMode::OneShot`] se lanza el programa en cada lectura y se mata si
+///   supera el tiempo de espera.
+/// - En modo [`ProcessMode::LongLived`] el programa se mantiene abierto y se relanza
+///   si termina, hasta `max_restarts` veces seguidas sin una lectura correcta. Después
+///   las lecturas fallan con `IoError` sin relanzarlo hasta llamar a [`ProcessSensor::stop`].
+///
+/// La salida de error del programa se reenvía al log con el prefijo `[PROCESO]` y se
+/// conservan las últimas líneas para diagnóstico.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::process::{ProcessMode, ProcessSensor};
+///
+/// let mut sensor = ProcessSensor::new(
+///     "python3",
+///     &["/opt/sensores/leer_sgp30.py"],
+///     ProcessMode::LongLived { request: Some("read".into()) },
+/// );
+/// println!("{:?}", sensor.read());
+/// ```
+pub struct ProcessSensor {
+    /// Programa a ejecutar.
+    program: String,
+    /// Argumentos del programa.
+    args: Vec<String>,
+    /// Modo de ejecución.
+    mode: ProcessMode,
+    /// Tiempo máximo de espera de una lectura.
+    timeout: Duration,
+    /// Reinicios consecutivos permitidos (modo persistente).
+    max_restarts: u32,
+    /// Reinicios desde la última lectura correcta.
+    restarts: u32,
+    /// Se agotaron los reinicios: no se relanza hasta [`ProcessSensor::stop`].
+    given_up: bool,
+    /// Programa en ejecución (modo persistente).
+    running: Option<Running>,
+    /// Últimas líneas de stderr.
+    stderr: Arc<Mutex<VecDeque<String>>>,
+}
+
+impl ProcessSensor {
+    /// Crea el sensor; el programa no se lanza hasta la primera lectura.
+    ///
+    /// # Parámetros
+    /// - `program`: ejecutable (se busca en `PATH`).
+    /// - `args`: argumentos.
+    /// - `mode`: una ejecución por lectura o programa persistente.
+    pub fn new(program: &str, args: &[&str], mode: ProcessMode) -> Self {
+        Self {
+            program: program.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            mode,
+            timeout: DEFAULT_TIMEOUT,
+            max_restarts: DEFAULT_MAX_RESTARTS,
+            restarts: 0,
+            given_up: false,
+            running: None,
+            stderr: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Cambia el tiempo máximo de espera de una lectura.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Cambia el número de reinicios consecutivos permitidos en modo persistente.
+    pub fn set_max_restarts(&mut self, max_restarts: u32) {
+        self.max_restarts = max_restarts;
+    }
+
+    /// Reinicios realizados desde la última lectura correcta.
+    pub fn restarts(&self) -> u32 {
+        self.restarts
+    }
+
+    /// Últimas líneas escritas por el programa en stderr (la más antigua primero).
+    pub fn stderr_lines(&self) -> Vec<String> {
+        self.stderr.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Detiene el programa persistente, si está en ejecución.
+    ///
+    /// También pone a cero los reinicios: tras agotar `max_restarts`, la siguiente
+    /// lectura vuelve a lanzar el programa.
+    pub fn stop(&mut self) {
+        if let Some(mut running) = self.running.take() {
+            let _ = running.child.kill();
+            let _ = running.child.wait();
+        }
+        self.restarts = 0;
+        self.given_up = false;
+    }
+
+    /// Lanza el programa con stdout y stderr redirigidos.
+    fn spawn(&self) -> Result<Child, SensorError> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                eprintln!("[PROCESO] No se pudo lanzar '{}': {}", self.program, e);
+                SensorError::InitializationError
+            })?;
+
+        // Captura stderr en segundo plano para que el programa nunca se bloquee escribiendo
+        let stderr = child.stderr.take().unwrap();
+        let buffer = self.stderr.clone();
+        let program = self.program.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                eprintln!("[PROCESO] {}: {}", program, line);
+                let mut buffer = buffer.lock().unwrap();
+                if buffer.len() == STDERR_LINES {
+                    buffer.pop_front();
+                }
+                buffer.push_back(line);
+            }
+        });
+        Ok(child)
+    }
+
+    /// Ejecuta el programa una vez y devuelve su salida completa.
+    fn run_once(&mut self) -> Result<String, SensorError> {
+        let mut child = self.spawn()?;
+        drop(child.stdin.take());
+        let mut stdout = child.stdout.take().unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut output = String::new();
+            let _ = tx.send(stdout.read_to_string(&mut output).map(|_| output));
+        });
+
+        let output = match rx.recv_timeout(self.timeout) {
+            Ok(output) => output.map_err(|_| SensorError::InvalidData),
+            Err(_) => {
+                eprintln!("[PROCESO] '{}' no terminó en {:?}", self.program, self.timeout);
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(SensorError::Timeout);
+            }
+        }?;
+
+        let status = child.wait().map_err(|_| SensorError::IoError)?;
+        if !status.success() && output.trim().is_empty() {
+            eprintln!("[PROCESO] '{}' terminó con {}", self.program, status);
+            return Err(SensorError::IoError);
+        }
+        Ok(output)
+    }
+
+    /// Devuelve el programa persistente, relanzándolo si ha terminado.
+    fn ensure_running(&mut self) -> Result<&mut Running, SensorError> {
+        if self.given_up {
+            return Err(SensorError::IoError);
+        }
+        let exited = match self.running.as_mut() {
+            Some(running) => running.child.try_wait().map_err(|_| SensorError::IoError)?,
+            None => None,
+        };
+        if let Some(status) = exited {
+            eprintln!("[PROCESO] '{}' terminó inesperadamente ({})", self.program, status);
+            self.running = None;
+            if self.restarts >= self.max_restarts {
+                eprintln!("[PROCESO] '{}' agotó {} reinicios", self.program, self.max_restarts);
+                self.given_up = true;
+                return Err(SensorError::IoError);
+            }
+            self.restarts += 1;
+        }
+
+        if self.running.is_none() {
+            let mut child = self.spawn()?;
+            let stdin = child.stdin.take();
+            let stdout = child.stdout.take().unwrap();
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                    if !line.trim().is_empty() && tx.send(line).is_err() {
+                        break;
+                    }
+                }
+            });
+            self.running = Some(Running { child, stdin, lines: rx });
+        }
+        Ok(self.running.as_mut().unwrap())
+    }
+
+    /// Obtiene una línea del programa persistente.
+    fn read_line(&mut self) -> Result<String, SensorError> {
+        let timeout = self.timeout;
+        let request = match &self.mode {
+            ProcessMode::LongLived { request } => request.clone(),
+            ProcessMode::OneShot => None,
+        };
+        let running = self.ensure_running()?;
+
+        // Descarta (o aprovecha) las líneas acumuladas desde la última lectura
+        let mut latest = None;
+        while let Ok(line) = running.lines.try_recv() {
+            latest = Some(line);
+        }
+
+        if let Some(request) = request {
+            let stdin = running.stdin.as_mut().ok_or(SensorError::IoError)?;
+            writeln!(stdin, "{}", request)
+                .and_then(|_| stdin.flush())
+                .map_err(|_| SensorError::IoError)?;
+        } else if let Some(line) = latest {
+            return Ok(line);
+        }
+
+        match running.lines.recv_timeout(timeout) {
+            Ok(line) => Ok(line),
+            Err(RecvTimeoutError::Timeout) => Err(SensorError::Timeout),
+            // stdout cerrado: el programa ha terminado y se relanzará en la próxima lectura
+            Err(RecvTimeoutError::Disconnected) => {
+                let _ = running.child.wait();
+                Err(SensorError::IoError)
+            }
+        }
+    }
+}
+
+impl Sensor for ProcessSensor {
+    type Output = SensorOutput;
+
+    /// Obtiene una lectura del programa externo y la interpreta con [`parse_reading`].
+    ///
+    /// En modo de una ejecución se usa la última línea no vacía de la salida.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let line = match self.mode {
+            ProcessMode::OneShot => {
+                let output = self.run_once()?;
+                output
+                    .lines()
+                    .rev()
+                    .find(|l| !l.trim().is_empty())
+                    .map(str::to_string)
+                    .ok_or(SensorError::InvalidData)?
+            }
+            ProcessMode::LongLived { .. } => self.read_line()?,
+        };
+        let reading = parse_reading(&line)?;
+        self.restarts = 0;
+        Ok(reading)
+    }
+}
+
+impl Drop for ProcessSensor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
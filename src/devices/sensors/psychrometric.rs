@@ -0,0 +1,179 @@
+use crate::core::traits::climate::{ClimateReading, ClimateSensor};
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+
+/// Coeficientes de la fórmula de Magnus (Sonntag, 1990) sobre agua líquida.
+const MAGNUS_A: f32 = 17.62;
+const MAGNUS_B: f32 = 243.12;
+
+/// Presión de vapor de saturación a 0 °C, en hPa.
+const MAGNUS_E0_HPA: f32 = 6.112;
+
+/// Métricas psicrométricas derivadas de una lectura de temperatura y humedad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PsychrometricReading {
+    /// Temperatura del aire en °C (valor original del sensor).
+    pub temperature: f32,
+    /// Humedad relativa en % (valor original del sensor).
+    pub humidity: f32,
+    /// Punto de rocío en °C.
+    pub dew_point: f32,
+    /// Índice de calor (sensación térmica) en °C.
+    pub heat_index: f32,
+    /// Humedad absoluta en g/m³.
+    pub absolute_humidity: f32,
+    /// Déficit de presión de vapor en kPa.
+    pub vapour_pressure_deficit: f32,
+}
+
+impl PsychrometricReading {
+    /// Calcula todas las métricas derivadas a partir de una lectura de clima.
+    pub fn from_climate(reading: ClimateReading) -> Self {
+        let t = reading.temperature;
+        let rh = reading.humidity;
+        Self {
+            temperature: t,
+            humidity: rh,
+            dew_point: dew_point(t, rh),
+            heat_index: heat_index(t, rh),
+            absolute_humidity: absolute_humidity(t, rh),
+            vapour_pressure_deficit: vapour_pressure_deficit(t, rh),
+        }
+    }
+}
+
+/// Presión de vapor de saturación en hPa (fórmula de Magnus).
+pub fn saturation_vapour_pressure(temperature: f32) -> f32 {
+    MAGNUS_E0_HPA * ((MAGNUS_A * temperature) / (MAGNUS_B + temperature)).exp()
+}
+
+/// Punto de rocío en °C (fórmula de Magnus).
+///
+/// Una humedad de 0 % no tiene punto de rocío definido; se limita a 0.1 %.
+pub fn dew_point(temperature: f32, humidity: f32) -> f32 {
+    let rh = humidity.clamp(0.1, 100.0) / 100.0;
+    let gamma = rh.ln() + (MAGNUS_A * temperature) / (MAGNUS_B + temperature);
+    MAGNUS_B * gamma / (MAGNUS_A - gamma)
+}
+
+/// Índice de calor en °C según el algoritmo de la NOAA.
+///
+/// Usa la aproximación de Steadman para valores bajos y la regresión de
+/// Rothfusz con sus ajustes cuando el índice supera los 80 °F (26.7 °C).
+pub fn heat_index(temperature: f32, humidity: f32) -> f32 {
+    let t = temperature * 9.0 / 5.0 + 32.0;
+    let rh = humidity.clamp(0.0, 100.0);
+
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let hi = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let mut hi = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+            - 0.224_755_4 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            hi -= ((13.0 - rh) / 4.0) * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            hi += ((rh - 85.0) / 10.0) * ((87.0 - t) / 5.0);
+        }
+        hi
+    };
+
+    (hi - 32.0) * 5.0 / 9.0
+}
+
+/// Humedad absoluta en g/m³ (masa de vapor de agua por volumen de aire).
+pub fn absolute_humidity(temperature: f32, humidity: f32) -> f32 {
+    let vapour_hpa = saturation_vapour_pressure(temperature) * humidity.clamp(0.0, 100.0) / 100.0;
+    216.7 * vapour_hpa / (273.15 + temperature)
+}
+
+/// Déficit de presión de vapor (VPD) en kPa.
+pub fn vapour_pressure_deficit(temperature: f32, humidity: f32) -> f32 {
+    let es_kpa = saturation_vapour_pressure(temperature) / 10.0;
+    es_kpa * (1.0 - humidity.clamp(0.0, 100.0) / 100.0)
+}
+
+/// PsychrometricSensor: **sensor virtual** que deriva métricas psicrométricas.
+///
+/// Envuelve cualquier fuente de temperatura y humedad que implemente
+/// [`ClimateSensor`] (por ejemplo [`Dht11Sensor`](super::dht11::Dht11Sensor) o
+/// [`Dht22Sensor`](super::dht22::Dht22Sensor)) y, en cada lectura, calcula:
+/// - Punto de rocío (°C)
+/// - Índice de calor (°C)
+/// - Humedad absoluta (g/m³)
+/// - Déficit de presión de vapor (kPa)
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::dht22::Dht22Sensor;
+/// use lince::devices::sensors::psychrometric::PsychrometricSensor;
+///
+/// let dht22 = Dht22Sensor::new(23).unwrap();
+/// let mut sensor = PsychrometricSensor::new(dht22);
+/// let lectura = sensor.read().unwrap();
+/// println!("{:?}", lectura);
+/// ```
+pub struct PsychrometricSensor<S: ClimateSensor> {
+    /// Sensor de temperatura y humedad subyacente.
+    source: S,
+}
+
+impl<S: ClimateSensor> PsychrometricSensor<S> {
+    /// Crea un sensor derivado a partir de una fuente de temperatura y humedad.
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Lee la fuente y devuelve las métricas derivadas como valores numéricos.
+    ///
+    /// # Retorna
+    /// - `Ok(PsychrometricReading)` con los valores originales y derivados.
+    /// - `Err(SensorError)` si falla la lectura de la fuente.
+    pub fn read_metrics(&mut self) -> Result<PsychrometricReading, SensorError> {
+        let reading = self.source.read_climate()?;
+        Ok(PsychrometricReading::from_climate(reading))
+    }
+
+    /// Devuelve el sensor subyacente, consumiendo el envoltorio.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: ClimateSensor> ClimateSensor for PsychrometricSensor<S> {
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        self.source.read_climate()
+    }
+}
+
+impl<S: ClimateSensor> Sensor for PsychrometricSensor<S> {
+    /// Tipo de salida: [`SensorOutput`] (texto con valores originales y derivados).
+    type Output = SensorOutput;
+
+    /// Lee la fuente y devuelve los valores originales junto con las métricas derivadas.
+    ///
+    /// # Retorna
+    /// - `Ok(SensorOutput::Text(...))`, por ejemplo:
+    ///   `"Temp: 24.3°C, Hum: 58.2%, Rocío: 15.6°C, IC: 24.4°C, HA: 12.8 g/m³, VPD: 1.27 kPa"`.
+    /// - `Err(SensorError)` si falla la lectura de la fuente.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let m = self.read_metrics()?;
+
+        Ok(SensorOutput::Text(format!(
+            "Temp: {:.1}°C, Hum: {:.1}%, Rocío: {:.1}°C, IC: {:.1}°C, HA: {:.1} g/m³, VPD: {:.2} kPa",
+            m.temperature,
+            m.humidity,
+            m.dew_point,
+            m.heat_index,
+            m.absolute_humidity,
+            m.vapour_pressure_deficit
+        )))
+    }
+}
@@ -0,0 +1,242 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::edge::{Edge, EdgeCounter, PulseSource};
+
+/// Conversión de pulsos a una magnitud física.
+///
+/// Cada pulso equivale a `per_pulse` unidades, por lo que:
+/// - `total = pulsos · per_pulse` (en `unit`)
+/// - `tasa = pulsos/s · per_pulse · rate_scale` (en `rate_unit`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PulseCalibration {
+    /// Unidades de la magnitud por cada pulso.
+    pub per_pulse: f64,
+    /// Unidad del total acumulado.
+    pub unit: String,
+    /// Unidad de la tasa.
+    pub rate_unit: String,
+    /// Factor de `unit/s` a `rate_unit` (por ejemplo 3600 para `mm/h`).
+    pub rate_scale: f64,
+}
+
+impl PulseCalibration {
+    /// Pulsos sin convertir (total en pulsos, tasa en Hz).
+    pub fn raw() -> Self {
+        Self {
+            per_pulse: 1.0,
+            unit: "pulsos".to_string(),
+            rate_unit: "Hz".to_string(),
+            rate_scale: 1.0,
+        }
+    }
+
+    /// Pluviómetro de balancín: milímetros de lluvia por vuelco (típico 0.2794 mm).
+    pub fn rain_gauge(mm_per_tip: f64) -> Self {
+        Self {
+            per_pulse: mm_per_tip,
+            unit: "mm".to_string(),
+            rate_unit: "mm/h".to_string(),
+            rate_scale: 3600.0,
+        }
+    }
+
+    /// Anemómetro de cazoletas: m/s por Hz (típico 2.4 km/h por Hz ≈ 0.667 m/s).
+    ///
+    /// El total acumulado es el recorrido del viento en metros.
+    pub fn anemometer(ms_per_hz: f64) -> Self {
+        Self {
+            per_pulse: ms_per_hz,
+            unit: "m".to_string(),
+            rate_unit: "m/s".to_string(),
+            rate_scale: 1.0,
+        }
+    }
+
+    /// Caudalímetro de turbina: litros por pulso (YF-S201 ≈ 1/450 L).
+    pub fn flow_meter(liters_per_pulse: f64) -> Self {
+        Self {
+            per_pulse: liters_per_pulse,
+            unit: "L".to_string(),
+            rate_unit: "L/min".to_string(),
+            rate_scale: 60.0,
+        }
+    }
+}
+
+/// Lectura de un contador de pulsos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PulseReading {
+    /// Pulsos totales (incluidos los de ejecuciones anteriores si hay persistencia).
+    pub total_pulses: u64,
+    /// Pulsos por segundo desde la lectura anterior.
+    pub pulses_per_second: f64,
+    /// Total acumulado en la unidad calibrada.
+    pub total: f64,
+    /// Tasa en la unidad de tasa calibrada.
+    pub rate: f64,
+}
+
+/// Estado persistido en disco.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedTotal {
+    total_pulses: u64,
+}
+
+/// PulseCounterSensor: sensor de **conteo de pulsos** para pluviómetros,
+/// anemómetros y caudalímetros.
+///
+/// Cuenta flancos de un pin GPIO mediante interrupciones ([`EdgeCounter`]) e informa:
+/// - el número de pulsos,
+/// - la tasa (pulsos/s) desde la lectura anterior,
+/// - las magnitudes calibradas (por ejemplo mm de lluvia y mm/h).
+///
+/// Con [`PulseCounterSensor::with_persistence`], el total se guarda en un archivo en
+/// cada lectura y se recupera al reiniciar, de modo que la lluvia acumulada no se pierde.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::pulse_counter::{PulseCalibration, PulseCounterSensor};
+///
+/// let mut pluviometro = PulseCounterSensor::new(27, PulseCalibration::rain_gauge(0.2794))
+///     .unwrap()
+///     .with_persistence("/var/lib/lince/lluvia.json")
+///     .unwrap();
+/// println!("{:?}", pluviometro.read().unwrap()); // "Pulsos: 45, Total: 12.57 mm, Tasa: 2.01 mm/h"
+/// ```
+pub struct PulseCounterSensor<P: PulseSource = EdgeCounter> {
+    /// Fuente de pulsos.
+    source: P,
+    /// Conversión a magnitudes físicas.
+    calibration: PulseCalibration,
+    /// Pulsos acumulados antes de crear la fuente (recuperados del archivo).
+    base_pulses: u64,
+    /// Archivo de persistencia del total.
+    persist_path: Option<PathBuf>,
+    /// Conteo de la fuente y momento de la lectura anterior.
+    last: (u64, Instant),
+}
+
+impl PulseCounterSensor<EdgeCounter> {
+    /// Crea un contador sobre un pin BCM, contando flancos de bajada con pull-up
+    /// y un anti-rebote de 5 ms (adecuado para contactos reed).
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si falla la configuración del GPIO.
+    pub fn new(pin: u8, calibration: PulseCalibration) -> Result<Self, SensorError> {
+        let source = EdgeCounter::new(pin, Edge::Falling, true, Duration::from_millis(5))?;
+        Ok(Self::with_source(source, calibration))
+    }
+}
+
+impl<P: PulseSource> PulseCounterSensor<P> {
+    /// Crea un contador a partir de cualquier fuente de pulsos.
+    pub fn with_source(source: P, calibration: PulseCalibration) -> Self {
+        let start = source.count();
+        Self {
+            source,
+            calibration,
+            base_pulses: 0,
+            persist_path: None,
+            last: (start, Instant::now()),
+        }
+    }
+
+    /// Activa la persistencia del total en `path`, recuperando el valor guardado si existe.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el archivo existe pero no es válido.
+    /// - `Err(SensorError::IoError)` si el archivo existe pero no puede leerse.
+    pub fn with_persistence<Q: AsRef<Path>>(mut self, path: Q) -> Result<Self, SensorError> {
+        let path = path.as_ref().to_path_buf();
+        if path.exists() {
+            let text = fs::read_to_string(&path).map_err(|_| SensorError::IoError)?;
+            let saved: PersistedTotal = serde_json::from_str(&text).map_err(|e| {
+                eprintln!("[PULSOS] Archivo de totales inválido: {}", e);
+                SensorError::InvalidData
+            })?;
+            // Los pulsos ya contados por la fuente se restan para no duplicarlos
+            self.base_pulses = saved.total_pulses.saturating_sub(self.source.count());
+        }
+        self.persist_path = Some(path);
+        Ok(self)
+    }
+
+    /// Calibración actual.
+    pub fn calibration(&self) -> &PulseCalibration {
+        &self.calibration
+    }
+
+    /// Pulsos totales, incluidos los recuperados de ejecuciones anteriores.
+    pub fn total_pulses(&self) -> u64 {
+        self.base_pulses.wrapping_add(self.source.count())
+    }
+
+    /// Pone el total acumulado a cero (por ejemplo, al inicio de cada día de lluvia).
+    pub fn reset_total(&mut self) -> Result<(), SensorError> {
+        // Desplazamiento modular: base + conteo actual = 0
+        self.base_pulses = 0u64.wrapping_sub(self.source.count());
+        self.persist()
+    }
+
+    /// Lee el contador y calcula total y tasa desde la lectura anterior.
+    pub fn read_pulses(&mut self) -> Result<PulseReading, SensorError> {
+        let now = Instant::now();
+        let count = self.source.count();
+        let (last_count, last_time) = self.last;
+        let elapsed = now.duration_since(last_time).as_secs_f64();
+        self.last = (count, now);
+
+        let pulses_per_second = if elapsed > 0.0 {
+            count.saturating_sub(last_count) as f64 / elapsed
+        } else {
+            0.0
+        };
+
+        let total_pulses = self.total_pulses();
+        self.persist()?;
+
+        Ok(PulseReading {
+            total_pulses,
+            pulses_per_second,
+            total: total_pulses as f64 * self.calibration.per_pulse,
+            rate: pulses_per_second * self.calibration.per_pulse * self.calibration.rate_scale,
+        })
+    }
+
+    /// Guarda el total en el archivo de persistencia (escritura atómica).
+    fn persist(&self) -> Result<(), SensorError> {
+        let Some(path) = &self.persist_path else {
+            return Ok(());
+        };
+        let text = serde_json::to_string(&PersistedTotal { total_pulses: self.total_pulses() })
+            .map_err(|_| SensorError::InvalidData)?;
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, text)
+            .and_then(|_| fs::rename(&tmp, path))
+            .map_err(|e| {
+                eprintln!("[PULSOS] Error al guardar totales: {}", e);
+                SensorError::IoError
+            })
+    }
+}
+
+impl<P: PulseSource> Sensor for PulseCounterSensor<P> {
+    /// Tipo de salida: [`SensorOutput`] (texto con pulsos, total y tasa).
+    type Output = SensorOutput;
+
+    /// Lee el contador y devuelve, por ejemplo, `"Pulsos: 45, Total: 12.57 mm, Tasa: 2.01 mm/h"`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let r = self.read_pulses()?;
+        Ok(SensorOutput::Text(format!(
+            "Pulsos: {}, Total: {:.2} {}, Tasa: {:.2} {}",
+            r.total_pulses, r.total, self.calibration.unit, r.rate, self.calibration.rate_unit
+        )))
+    }
+}
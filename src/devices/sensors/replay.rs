@@ -0,0 +1,315 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::traits::storage::StorageError;
+use crate::core::{SensorError, SensorOutput};
+
+/// Registro individual de una traza de sensor.
+///
+/// Cada línea del archivo de traza es un `TraceRecord` serializado en JSON.
+/// Se guardan tanto las lecturas correctas como los errores, de modo que la
+/// reproducción incluya los mismos fallos que ocurrieron en campo.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceRecord {
+    /// Milisegundos transcurridos desde el inicio de la grabación.
+    pub elapsed_ms: u64,
+    /// Marca de tiempo Unix (ms) en la que se tomó la lectura.
+    pub unix_ms: u64,
+    /// Resultado de la lectura.
+    pub result: Result<SensorOutput, SensorError>,
+}
+
+/// TraceRecorder: escribe lecturas de sensores en un archivo de traza (JSON Lines).
+///
+/// Cada registro se vuelca a disco inmediatamente para no perder datos si el
+/// gateway se apaga de forma inesperada.
+pub struct TraceRecorder {
+    /// Escritor del archivo de traza.
+    writer: BufWriter<File>,
+    /// Instante de inicio de la grabación.
+    start: Instant,
+    /// Tiempo relativo (ms) en el que empieza esta sesión de grabación.
+    offset_ms: u64,
+}
+
+impl TraceRecorder {
+    /// Crea (o trunca) un archivo de traza.
+    ///
+    /// # Retorno
+    /// - `Ok(Self)` si el archivo se creó correctamente.
+    /// - `Err(StorageError::SaveError)` si el archivo no puede crearse.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, StorageError> {
+        let file = File::create(path).map_err(|_| StorageError::SaveError)?;
+        Ok(Self::from_file(file))
+    }
+
+    /// Abre un archivo de traza existente y añade registros al final.
+    ///
+    /// Los tiempos relativos de los nuevos registros continúan a partir del último
+    /// registro del archivo, de modo que la reproducción no encadena ambas sesiones
+    /// sin pausas.
+    pub fn append<P: AsRef<Path>>(path: P) -> Result<Self, StorageError> {
+        let last_elapsed_ms = File::open(&path)
+            .ok()
+            .and_then(|file| {
+                BufReader::new(file)
+                    .lines()
+                    .map_while(Result::ok)
+                    .filter_map(|line| serde_json::from_str::<TraceRecord>(&line).ok())
+                    .last()
+            })
+            .map(|record| record.elapsed_ms)
+            .unwrap_or(0);
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|_| StorageError::SaveError)?;
+        let mut recorder = Self::from_file(file);
+        recorder.offset_ms = last_elapsed_ms;
+        Ok(recorder)
+    }
+
+    fn from_file(file: File) -> Self {
+        Self {
+            writer: BufWriter::new(file),
+            start: Instant::now(),
+            offset_ms: 0,
+        }
+    }
+
+    /// Registra el resultado de una lectura.
+    ///
+    /// # Ejemplo: tramas crudas de un DHT22
+    /// ```no_run
+    /// use lince::core::SensorOutput;
+    /// use lince::devices::sensors::dht22::Dht22Sensor;
+    /// use lince::devices::sensors::replay::TraceRecorder;
+    ///
+    /// let mut dht22 = Dht22Sensor::new(23).unwrap();
+    /// let mut recorder = TraceRecorder::create("dht22_frames.jsonl").unwrap();
+    ///
+    /// let frame = dht22.read_frame().map(|f| SensorOutput::Bytes(f.to_vec()));
+    /// recorder.record(&frame).unwrap();
+    /// ```
+    ///
+    /// # Retorno
+    /// - `Ok(())` si el registro se escribió en disco.
+    /// - `Err(StorageError::SaveError)` si falla la serialización o la escritura.
+    pub fn record(&mut self, result: &Result<SensorOutput, SensorError>) -> Result<(), StorageError> {
+        let record = TraceRecord {
+            elapsed_ms: self.offset_ms + self.start.elapsed().as_millis() as u64,
+            unix_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+            result: result.clone(),
+        };
+
+        let line = serde_json::to_string(&record).map_err(|_| StorageError::SaveError)?;
+        writeln!(self.writer, "{}", line).map_err(|_| StorageError::SaveError)?;
+        self.writer.flush().map_err(|_| StorageError::SaveError)
+    }
+}
+
+/// RecordingSensor: envuelve un sensor y graba cada lectura en una traza.
+///
+/// Es transparente para el resto del pipeline: devuelve exactamente el mismo
+/// resultado que el sensor original. Si la escritura de la traza falla, se
+/// informa por `stderr` pero la lectura no se pierde.
+pub struct RecordingSensor<S: Sensor<Output = SensorOutput>> {
+    /// Sensor real.
+    inner: S,
+    /// Grabador de la traza.
+    recorder: TraceRecorder,
+}
+
+impl<S: Sensor<Output = SensorOutput>> RecordingSensor<S> {
+    /// Crea un sensor que graba las lecturas de `inner` con `recorder`.
+    pub fn new(inner: S, recorder: TraceRecorder) -> Self {
+        Self { inner, recorder }
+    }
+
+    /// Devuelve el sensor original, consumiendo el envoltorio.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Sensor<Output = SensorOutput>> Sensor for RecordingSensor<S> {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let result = self.inner.read();
+        if let Err(e) = self.recorder.record(&result) {
+            eprintln!("[REPLAY] Error al grabar la traza: {:?}", e);
+        }
+        result
+    }
+}
+
+/// Modo de temporización de la reproducción.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayTiming {
+    /// Respeta los intervalos originales entre lecturas.
+    Original,
+    /// Reproduce `n` veces más rápido que el original (por ejemplo `10.0`).
+    ///
+    /// Un factor no finito o ≤ 0 equivale a [`ReplayTiming::Immediate`].
+    Accelerated(f32),
+    /// Devuelve cada lectura inmediatamente, sin esperas.
+    Immediate,
+}
+
+/// ReplaySensor: reproduce una traza grabada como si fuera un sensor real.
+///
+/// Permite reproducir en escritorio el comportamiento observado en campo
+/// (incluidos los errores) para depurar el almacenamiento o la comunicación.
+///
+/// Al terminar la traza, `read()` devuelve `Err(SensorError::IoError)` salvo que
+/// se haya activado la repetición con [`ReplaySensor::with_loop`].
+///
+/// # Ejemplo
+/// ```
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::core::{SensorError, SensorOutput};
+/// use lince::devices::sensors::replay::{ReplaySensor, ReplayTiming, TraceRecord};
+///
+/// let records = vec![
+///     TraceRecord { elapsed_ms: 0, unix_ms: 0, result: Ok(SensorOutput::Text("21.50 °C".into())) },
+///     TraceRecord { elapsed_ms: 2000, unix_ms: 0, result: Err(SensorError::Timeout) },
+/// ];
+///
+/// let mut sensor = ReplaySensor::from_records(records, ReplayTiming::Immediate);
+/// assert_eq!(sensor.read(), Ok(SensorOutput::Text("21.50 °C".into())));
+/// assert_eq!(sensor.read(), Err(SensorError::Timeout));
+/// assert!(sensor.is_finished());
+/// ```
+pub struct ReplaySensor {
+    /// Registros de la traza, en orden.
+    records: Vec<TraceRecord>,
+    /// Índice del siguiente registro a devolver.
+    position: usize,
+    /// Modo de temporización.
+    timing: ReplayTiming,
+    /// Reinicia la traza al llegar al final.
+    looping: bool,
+    /// Instante en que se devolvió el primer registro de la pasada actual.
+    start: Option<Instant>,
+}
+
+impl ReplaySensor {
+    /// Carga una traza desde un archivo JSON Lines generado por [`TraceRecorder`].
+    ///
+    /// Las líneas vacías se ignoran.
+    ///
+    /// # Retorno
+    /// - `Ok(Self)` si la traza se cargó correctamente.
+    /// - `Err(SensorError::IoError)` si el archivo no puede leerse.
+    /// - `Err(SensorError::InvalidData)` si alguna línea no es un registro válido.
+    pub fn from_file<P: AsRef<Path>>(path: P, timing: ReplayTiming) -> Result<Self, SensorError> {
+        let file = File::open(path).map_err(|_| SensorError::IoError)?;
+        let mut records = Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|_| SensorError::IoError)?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: TraceRecord = serde_json::from_str(&line).map_err(|e| {
+                eprintln!("[REPLAY] Registro inválido: {}", e);
+                SensorError::InvalidData
+            })?;
+            records.push(record);
+        }
+
+        Ok(Self::from_records(records, timing))
+    }
+
+    /// Crea un sensor a partir de registros ya cargados en memoria.
+    pub fn from_records(records: Vec<TraceRecord>, timing: ReplayTiming) -> Self {
+        Self {
+            records,
+            position: 0,
+            timing,
+            looping: false,
+            start: None,
+        }
+    }
+
+    /// Activa o desactiva la repetición de la traza al llegar al final.
+    pub fn with_loop(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Indica si ya se devolvieron todos los registros (sin repetición).
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.position >= self.records.len()
+    }
+
+    /// Número total de registros de la traza.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Indica si la traza está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Espera hasta el instante en que el registro actual debe devolverse.
+    fn wait_for(&mut self, elapsed_ms: u64) {
+        let scale = match self.timing {
+            ReplayTiming::Original => 1.0,
+            ReplayTiming::Accelerated(speed) if speed.is_finite() && speed > 0.0 => 1.0 / speed as f64,
+            ReplayTiming::Accelerated(_) | ReplayTiming::Immediate => return,
+        };
+
+        let first_ms = self.records.first().map(|r| r.elapsed_ms).unwrap_or(0);
+        let start = *self.start.get_or_insert_with(Instant::now);
+        let offset_secs = elapsed_ms.saturating_sub(first_ms) as f64 / 1000.0 * scale;
+
+        // Con factores diminutos la espera no es representable: se reproduce sin pausa
+        let Some(deadline) = Duration::try_from_secs_f64(offset_secs).ok().and_then(|d| start.checked_add(d)) else {
+            eprintln!("[REPLAY] Espera de {:.0} s fuera de rango; se omite", offset_secs);
+            return;
+        };
+        if let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            thread::sleep(remaining);
+        }
+    }
+}
+
+impl Sensor for ReplaySensor {
+    type Output = SensorOutput;
+
+    /// Devuelve el siguiente registro de la traza, respetando la temporización.
+    ///
+    /// # Retorno
+    /// - El resultado grabado (`Ok` o `Err`) del registro actual.
+    /// - `Err(SensorError::IoError)` si la traza terminó o está vacía.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        if self.position >= self.records.len() {
+            if !self.looping || self.records.is_empty() {
+                return Err(SensorError::IoError);
+            }
+            self.position = 0;
+            self.start = None;
+        }
+
+        let elapsed_ms = self.records[self.position].elapsed_ms;
+        self.wait_for(elapsed_ms);
+
+        let result = self.records[self.position].result.clone();
+        self.position += 1;
+        result
+    }
+}
@@ -0,0 +1,174 @@
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::modbus::{ModbusClient, ModbusRtuClient};
+use crate::drivers::serial::{SerialDriver, SerialPort};
+
+/// Velocidad del puerto serie del S8.
+pub const BAUD_RATE: u32 = 9600;
+/// Dirección Modbus "cualquier sensor".
+pub const ANY_ADDRESS: u8 = 0xFE;
+/// Tiempo de precalentamiento hasta especificaciones completas.
+pub const WARMUP: Duration = Duration::from_secs(30);
+
+// Registros (dirección Modbus = número de registro − 1)
+const IR_METER_STATUS: u16 = 0x0000;
+const IR_CO2: u16 = 0x0003;
+const HR_ACK: u16 = 0x0000;
+const HR_COMMAND: u16 = 0x0001;
+const HR_ABC_PERIOD: u16 = 0x001F;
+
+/// Comando de calibración de fondo (background / zero, 400 ppm).
+const BACKGROUND_CALIBRATION: u16 = 0x7C06;
+/// Bit de confirmación de la calibración de fondo en el registro ACK.
+const ACK_BACKGROUND: u16 = 0x0020;
+
+/// Periodo ABC de fábrica (horas).
+pub const DEFAULT_ABC_PERIOD_HOURS: u16 = 180;
+
+const RESPONSE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// CRC-16/MODBUS de las tramas del sensor.
+pub use crate::drivers::modbus::crc16;
+
+/// Implementación del sensor de CO2 NDIR **SenseAir S8** sobre UART (Modbus RTU).
+///
+/// Lee el CO2 del registro de entrada IR4, el estado del IR1, y permite ajustar el
+/// periodo de calibración automática (ABC) y lanzar la calibración de fondo (cero).
+/// Las tramas se construyen y verifican con [`ModbusRtuClient`].
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::senseair_s8::SenseairS8Sensor;
+///
+/// let mut sensor = SenseairS8Sensor::new("/dev/serial0").unwrap();
+/// println!("{:?}", sensor.read());  // Int(645)
+/// ```
+pub struct SenseairS8Sensor<S = SerialDriver> {
+    /// Cliente Modbus RTU sobre el puerto serie del sensor.
+    client: ModbusRtuClient<S>,
+    /// Dirección Modbus del sensor.
+    address: u8,
+    /// Momento de la creación (inicio del precalentamiento).
+    started: Instant,
+    /// Duración del precalentamiento.
+    warmup: Duration,
+}
+
+impl SenseairS8Sensor<SerialDriver> {
+    /// Abre el sensor en un puerto serie del sistema (9600 8N1).
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, SensorError> {
+        Ok(Self::with_port(SerialDriver::new(path, BAUD_RATE)?, ANY_ADDRESS))
+    }
+}
+
+impl<S: SerialPort> SenseairS8Sensor<S> {
+    /// Crea el sensor sobre cualquier puerto serie y dirección Modbus.
+    pub fn with_port(port: S, address: u8) -> Self {
+        let mut client = ModbusRtuClient::with_port(port);
+        client.set_timeout(RESPONSE_TIMEOUT);
+        Self {
+            client,
+            address,
+            started: Instant::now(),
+            warmup: WARMUP,
+        }
+    }
+
+    /// Cambia la duración del precalentamiento ([`WARMUP`] por defecto).
+    pub fn set_warmup(&mut self, warmup: Duration) {
+        self.warmup = warmup;
+    }
+
+    /// Tiempo restante de precalentamiento (`Duration::ZERO` si ya terminó).
+    pub fn warmup_remaining(&self) -> Duration {
+        self.warmup.saturating_sub(self.started.elapsed())
+    }
+
+    /// Registro de estado (IR1). Cualquier bit activo indica un fallo del sensor.
+    pub fn read_status(&mut self) -> Result<u16, SensorError> {
+        self.read_input(IR_METER_STATUS)
+    }
+
+    /// Activa ABC con el periodo indicado (horas) o lo desactiva con `0`.
+    pub fn set_abc_period(&mut self, hours: u16) -> Result<(), SensorError> {
+        self.write_register(HR_ABC_PERIOD, hours)
+    }
+
+    /// Periodo ABC actual en horas (`0` = desactivado).
+    pub fn abc_period(&mut self) -> Result<u16, SensorError> {
+        self.read_holding(HR_ABC_PERIOD)
+    }
+
+    /// Activa o desactiva ABC con el periodo de fábrica ([`DEFAULT_ABC_PERIOD_HOURS`]).
+    pub fn set_abc(&mut self, enabled: bool) -> Result<(), SensorError> {
+        self.set_abc_period(if enabled { DEFAULT_ABC_PERIOD_HOURS } else { 0 })
+    }
+
+    /// Calibración de fondo: fija la lectura actual como 400 ppm.
+    ///
+    /// El sensor debe estar en aire exterior limpio. Se confirma leyendo el registro ACK.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::DeviceFault)` si el sensor no confirma la calibración.
+    pub fn calibrate_zero(&mut self) -> Result<(), SensorError> {
+        self.write_register(HR_ACK, 0)?;
+        self.write_register(HR_COMMAND, BACKGROUND_CALIBRATION)?;
+        thread::sleep(Duration::from_secs(2));
+        if self.read_holding(HR_ACK)? & ACK_BACKGROUND == 0 {
+            eprintln!("[S8] El sensor no confirmó la calibración");
+            return Err(SensorError::DeviceFault);
+        }
+        Ok(())
+    }
+
+    /// Devuelve el puerto serie, consumiendo el sensor.
+    pub fn release(self) -> S {
+        self.client.release()
+    }
+
+    /// Lee la concentración de CO2 en ppm.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` durante el precalentamiento.
+    /// - `Err(SensorError::Timeout)` si el sensor no responde.
+    /// - `Err(SensorError::InvalidData)` si la respuesta es inválida.
+    /// - `Err(SensorError::DeviceFault)` si el sensor responde con una excepción Modbus.
+    pub fn read_co2(&mut self) -> Result<u16, SensorError> {
+        let remaining = self.warmup_remaining();
+        if !remaining.is_zero() {
+            eprintln!("[S8] Precalentando, faltan {} s", remaining.as_secs());
+            return Err(SensorError::InitializationError);
+        }
+        self.read_input(IR_CO2)
+    }
+
+    /// Lee un registro de entrada (función 0x04).
+    fn read_input(&mut self, register: u16) -> Result<u16, SensorError> {
+        Ok(self.client.read_input_registers(self.address, register, 1)?[0])
+    }
+
+    /// Lee un registro de retención (función 0x03).
+    fn read_holding(&mut self, register: u16) -> Result<u16, SensorError> {
+        Ok(self.client.read_holding_registers(self.address, register, 1)?[0])
+    }
+
+    /// Escribe un registro de retención (función 0x06).
+    fn write_register(&mut self, register: u16, value: u16) -> Result<(), SensorError> {
+        self.client.write_single_register(self.address, register, value)
+    }
+}
+
+impl<S: SerialPort> Sensor for SenseairS8Sensor<S> {
+    /// Tipo de salida: [`SensorOutput`] (CO2 en ppm).
+    type Output = SensorOutput;
+
+    /// Lee la concentración de CO2 como `SensorOutput::Int` (ppm).
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Int(self.read_co2()? as i64))
+    }
+}
@@ -0,0 +1,474 @@
+use std::thread;
+use std::time::Duration;
+
+use embedded_hal::blocking::i2c::{Read, Write};
+
+use crate::core::traits::climate::{ClimateReading, ClimateSensor};
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto de SHT3x (ADDR a GND) y SHT4x.
+pub const DEFAULT_ADDRESS: u8 = 0x44;
+/// Dirección I2C alternativa del SHT3x (ADDR a VDD).
+pub const SECONDARY_ADDRESS: u8 = 0x45;
+
+// Comandos SHT3x (16 bits)
+const SHT3X_SOFT_RESET: u16 = 0x30A2;
+// Número de serie sin clock stretching (0x3682 lo usa y bloquea buses sin soporte)
+const SHT3X_READ_SERIAL: u16 = 0x3780;
+const SHT3X_FETCH_DATA: u16 = 0xE000;
+const SHT3X_BREAK: u16 = 0x3093;
+const SHT3X_HEATER_ON: u16 = 0x306D;
+const SHT3X_HEATER_OFF: u16 = 0x3066;
+
+// Comandos SHT4x (8 bits)
+const SHT4X_SOFT_RESET: u8 = 0x94;
+const SHT4X_READ_SERIAL: u8 = 0x89;
+
+/// Modelo de sensor Sensirion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShtModel {
+    /// SHT31: ±2 % RH, ±0.2 °C.
+    Sht31,
+    /// SHT35: ±1.5 % RH, ±0.1 °C.
+    Sht35,
+    /// SHT40: ±1.8 % RH, ±0.2 °C (familia SHT4x, sin modo periódico).
+    Sht40,
+}
+
+impl ShtModel {
+    fn is_sht4x(self) -> bool {
+        self == ShtModel::Sht40
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ShtModel::Sht31 => "sht31",
+            ShtModel::Sht35 => "sht35",
+            ShtModel::Sht40 => "sht40",
+        }
+    }
+}
+
+/// Repetibilidad (precisión) de la medición: a mayor repetibilidad, mayor tiempo y consumo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeatability {
+    High,
+    Medium,
+    Low,
+}
+
+/// Frecuencia de adquisición del modo periódico del SHT3x (mediciones por segundo).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodicRate {
+    Mps0_5,
+    Mps1,
+    Mps2,
+    Mps4,
+    Mps10,
+}
+
+/// Modo de medición.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShtMode {
+    /// Una medición por lectura (disponible en SHT3x y SHT4x).
+    SingleShot,
+    /// Mediciones continuas; cada lectura obtiene el último resultado (solo SHT3x).
+    Periodic(PeriodicRate),
+}
+
+/// Potencia del calentador del SHT4x.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaterPower {
+    /// 200 mW.
+    High,
+    /// 110 mW.
+    Medium,
+    /// 20 mW.
+    Low,
+}
+
+/// Calcula el CRC-8 de Sensirion (polinomio 0x31, valor inicial 0xFF).
+///
+/// # Ejemplo
+/// ```
+/// use lince::devices::sensors::sht::crc8;
+///
+/// // Ejemplo del datasheet: CRC(0xBEEF) = 0x92
+/// assert_eq!(crc8(&[0xBE, 0xEF]), 0x92);
+/// ```
+pub fn crc8(data: &[u8]) -> u8 {
+    let mut crc: u8 = 0xFF;
+    for byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x31 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+/// Valida el CRC de una palabra de 16 bits recibida del sensor.
+///
+/// # Parámetros
+/// - `word`: los dos bytes de datos.
+/// - `crc`: byte de CRC recibido a continuación.
+///
+/// # Retorno
+/// - `Ok(())` si el CRC es correcto.
+/// - `Err(SensorError::InvalidData)` si hay inconsistencia.
+pub fn validar_crc(word: &[u8; 2], crc: u8) -> Result<(), SensorError> {
+    if crc8(word) != crc {
+        Err(SensorError::InvalidData)
+    } else {
+        Ok(())
+    }
+}
+
+/// Valida y extrae las dos palabras de una respuesta de 6 bytes (`[w0, w0, crc, w1, w1, crc]`).
+fn decode_words(buf: &[u8; 6]) -> Result<(u16, u16), SensorError> {
+    validar_crc(&[buf[0], buf[1]], buf[2])?;
+    validar_crc(&[buf[3], buf[4]], buf[5])?;
+    Ok((
+        u16::from_be_bytes([buf[0], buf[1]]),
+        u16::from_be_bytes([buf[3], buf[4]]),
+    ))
+}
+
+/// Implementación de los sensores de humedad **Sensirion SHT31/SHT35/SHT40** sobre I2C.
+///
+/// Cada palabra de 16 bits recibida va acompañada de un CRC-8 que se verifica
+/// con [`validar_crc`], de forma análoga a `DhtBase::validar_checksum`.
+/// El número de serie se lee durante la inicialización y sirve como identidad
+/// del sensor (ver [`ShtSensor::identity`]).
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::sht::{ShtModel, ShtSensor, DEFAULT_ADDRESS};
+///
+/// let mut sensor = ShtSensor::new(1, DEFAULT_ADDRESS, ShtModel::Sht31).unwrap();
+/// println!("{}", sensor.identity());          // "sht31-1a2b3c4d"
+/// println!("{:?}", sensor.read().unwrap());   // "Temp: 4.2°C, Hum: 91.3%"
+/// ```
+pub struct ShtSensor<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el sensor.
+    i2c: I2C,
+    /// Dirección I2C del sensor.
+    address: u8,
+    /// Modelo del sensor.
+    model: ShtModel,
+    /// Modo de medición actual.
+    mode: ShtMode,
+    /// Repetibilidad usada en las mediciones.
+    repeatability: Repeatability,
+    /// Número de serie leído en la inicialización.
+    serial_number: u32,
+}
+
+impl ShtSensor<I2cDriver> {
+    /// Crea una nueva instancia del sensor en un bus I2C del sistema.
+    ///
+    /// # Parámetros
+    /// - `bus`: número del bus I2C (`1` en la Raspberry Pi).
+    /// - `address`: dirección del sensor ([`DEFAULT_ADDRESS`] o [`SECONDARY_ADDRESS`]).
+    /// - `model`: modelo del sensor.
+    ///
+    /// # Retorna
+    /// - `Ok(Self)` si el sensor responde y su número de serie es válido.
+    /// - `Err(SensorError)` si el bus no está disponible o el sensor no responde.
+    pub fn new(bus: u8, address: u8, model: ShtModel) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address, model)
+    }
+}
+
+impl<I2C> ShtSensor<I2C>
+where
+    I2C: Read + Write,
+{
+    /// Crea una nueva instancia del sensor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Envía un soft-reset y lee el número de serie. El sensor queda en modo
+    /// [`ShtMode::SingleShot`] con repetibilidad alta.
+    ///
+    /// # Retorna
+    /// - `Ok(Self)` si el sensor responde correctamente.
+    /// - `Err(SensorError::IoError)` si el sensor no responde.
+    /// - `Err(SensorError::InvalidData)` si el CRC del número de serie es incorrecto.
+    pub fn with_i2c(i2c: I2C, address: u8, model: ShtModel) -> Result<Self, SensorError> {
+        let mut sensor = Self {
+            i2c,
+            address,
+            model,
+            mode: ShtMode::SingleShot,
+            repeatability: Repeatability::High,
+            serial_number: 0,
+        };
+
+        sensor.soft_reset()?;
+        sensor.serial_number = sensor.read_serial_number()?;
+
+        Ok(sensor)
+    }
+
+    /// Modelo del sensor.
+    pub fn model(&self) -> ShtModel {
+        self.model
+    }
+
+    /// Número de serie único del sensor.
+    pub fn serial_number(&self) -> u32 {
+        self.serial_number
+    }
+
+    /// Identidad del sensor basada en el modelo y el número de serie (ej. `"sht31-1a2b3c4d"`).
+    ///
+    /// Es estable entre reinicios, por lo que puede usarse como identificador
+    /// en almacenamiento o tópicos MQTT.
+    pub fn identity(&self) -> String {
+        format!("{}-{:08x}", self.model.name(), self.serial_number)
+    }
+
+    /// Modo de medición actual.
+    pub fn mode(&self) -> ShtMode {
+        self.mode
+    }
+
+    /// Cambia la repetibilidad de las mediciones.
+    ///
+    /// En modo periódico reinicia la adquisición con la nueva repetibilidad.
+    pub fn set_repeatability(&mut self, repeatability: Repeatability) -> Result<(), SensorError> {
+        self.repeatability = repeatability;
+        self.set_mode(self.mode)
+    }
+
+    /// Cambia el modo de medición.
+    ///
+    /// # Retorna
+    /// - `Ok(())` si el modo se aplicó.
+    /// - `Err(SensorError::InitializationError)` si se pide modo periódico a un SHT4x.
+    /// - `Err(SensorError::IoError)` si falla la comunicación.
+    pub fn set_mode(&mut self, mode: ShtMode) -> Result<(), SensorError> {
+        if self.model.is_sht4x() && mode != ShtMode::SingleShot {
+            return Err(SensorError::InitializationError);
+        }
+
+        if let ShtMode::Periodic(_) = self.mode {
+            self.command16(SHT3X_BREAK)?;
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        if let ShtMode::Periodic(rate) = mode {
+            self.command16(periodic_command(rate, self.repeatability))?;
+        }
+
+        self.mode = mode;
+        Ok(())
+    }
+
+    /// Activa o desactiva el calentador interno del SHT3x.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` en un SHT4x (usar [`ShtSensor::heater_pulse`]).
+    pub fn set_heater(&mut self, enabled: bool) -> Result<(), SensorError> {
+        if self.model.is_sht4x() {
+            return Err(SensorError::InitializationError);
+        }
+        self.command16(if enabled { SHT3X_HEATER_ON } else { SHT3X_HEATER_OFF })
+    }
+
+    /// Ejecuta un pulso del calentador del SHT4x y devuelve la medición tomada al final.
+    ///
+    /// # Parámetros
+    /// - `power`: potencia del calentador.
+    /// - `long`: `true` para un pulso de 1 s, `false` para 0.1 s.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` en un SHT3x (usar [`ShtSensor::set_heater`]).
+    pub fn heater_pulse(&mut self, power: HeaterPower, long: bool) -> Result<ClimateReading, SensorError> {
+        if !self.model.is_sht4x() {
+            return Err(SensorError::InitializationError);
+        }
+
+        let command = match (power, long) {
+            (HeaterPower::High, true) => 0x39,
+            (HeaterPower::High, false) => 0x32,
+            (HeaterPower::Medium, true) => 0x2F,
+            (HeaterPower::Medium, false) => 0x24,
+            (HeaterPower::Low, true) => 0x1E,
+            (HeaterPower::Low, false) => 0x15,
+        };
+        self.command8(command)?;
+        thread::sleep(if long { Duration::from_millis(1100) } else { Duration::from_millis(110) });
+
+        self.read_result()
+    }
+
+    /// Calienta el sensor durante `duration` para evaporar la condensación.
+    ///
+    /// - SHT3x: enciende el calentador, espera y lo apaga.
+    /// - SHT4x: encadena pulsos de 1 s a máxima potencia.
+    ///
+    /// Tras calentar, las lecturas de humedad son bajas y la temperatura alta
+    /// hasta que el sensor se enfría (unos segundos).
+    pub fn recover_condensation(&mut self, duration: Duration) -> Result<(), SensorError> {
+        if self.model.is_sht4x() {
+            let pulses = duration.as_secs_f32().ceil().max(1.0) as u32;
+            for _ in 0..pulses {
+                self.heater_pulse(HeaterPower::High, true)?;
+            }
+            Ok(())
+        } else {
+            self.set_heater(true)?;
+            thread::sleep(duration);
+            self.set_heater(false)
+        }
+    }
+
+    /// Devuelve el bus I2C, consumiendo el sensor.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Realiza una medición (o recoge la última en modo periódico).
+    fn measure(&mut self) -> Result<ClimateReading, SensorError> {
+        match self.mode {
+            ShtMode::Periodic(_) => self.command16(SHT3X_FETCH_DATA)?,
+            ShtMode::SingleShot if self.model.is_sht4x() => {
+                let (command, wait_us) = match self.repeatability {
+                    Repeatability::High => (0xFD, 8_300),
+                    Repeatability::Medium => (0xF6, 4_500),
+                    Repeatability::Low => (0xE0, 1_700),
+                };
+                self.command8(command)?;
+                thread::sleep(Duration::from_micros(wait_us));
+            }
+            ShtMode::SingleShot => {
+                // Comandos sin clock stretching
+                let (command, wait_us) = match self.repeatability {
+                    Repeatability::High => (0x2400, 15_500),
+                    Repeatability::Medium => (0x240B, 6_500),
+                    Repeatability::Low => (0x2416, 4_500),
+                };
+                self.command16(command)?;
+                thread::sleep(Duration::from_micros(wait_us));
+            }
+        }
+
+        self.read_result()
+    }
+
+    /// Lee y convierte una respuesta de temperatura y humedad.
+    fn read_result(&mut self) -> Result<ClimateReading, SensorError> {
+        let mut buf = [0u8; 6];
+        self.read_bytes(&mut buf)?;
+        let (raw_t, raw_rh) = decode_words(&buf)?;
+
+        let temperature = -45.0 + 175.0 * raw_t as f32 / 65535.0;
+        let humidity = if self.model.is_sht4x() {
+            (-6.0 + 125.0 * raw_rh as f32 / 65535.0).clamp(0.0, 100.0)
+        } else {
+            100.0 * raw_rh as f32 / 65535.0
+        };
+
+        Ok(ClimateReading { temperature, humidity })
+    }
+
+    fn soft_reset(&mut self) -> Result<(), SensorError> {
+        if self.model.is_sht4x() {
+            self.command8(SHT4X_SOFT_RESET)?;
+        } else {
+            self.command16(SHT3X_SOFT_RESET)?;
+        }
+        thread::sleep(Duration::from_millis(2));
+        Ok(())
+    }
+
+    fn read_serial_number(&mut self) -> Result<u32, SensorError> {
+        if self.model.is_sht4x() {
+            self.command8(SHT4X_READ_SERIAL)?;
+        } else {
+            self.command16(SHT3X_READ_SERIAL)?;
+        }
+        thread::sleep(Duration::from_millis(1));
+
+        let mut buf = [0u8; 6];
+        self.read_bytes(&mut buf)?;
+        let (high, low) = decode_words(&buf)?;
+        Ok((high as u32) << 16 | low as u32)
+    }
+
+    fn command8(&mut self, command: u8) -> Result<(), SensorError> {
+        self.i2c
+            .write(self.address, &[command])
+            .map_err(|_| SensorError::IoError)
+    }
+
+    fn command16(&mut self, command: u16) -> Result<(), SensorError> {
+        self.i2c
+            .write(self.address, &command.to_be_bytes())
+            .map_err(|_| SensorError::IoError)
+    }
+
+    fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), SensorError> {
+        self.i2c
+            .read(self.address, buf)
+            .map_err(|_| SensorError::IoError)
+    }
+}
+
+/// Comando de inicio del modo periódico del SHT3x (datasheet, tabla 10).
+fn periodic_command(rate: PeriodicRate, repeatability: Repeatability) -> u16 {
+    use PeriodicRate::*;
+    use Repeatability::*;
+    match (rate, repeatability) {
+        (Mps0_5, High) => 0x2032,
+        (Mps0_5, Medium) => 0x2024,
+        (Mps0_5, Low) => 0x202F,
+        (Mps1, High) => 0x2130,
+        (Mps1, Medium) => 0x2126,
+        (Mps1, Low) => 0x212D,
+        (Mps2, High) => 0x2236,
+        (Mps2, Medium) => 0x2220,
+        (Mps2, Low) => 0x222B,
+        (Mps4, High) => 0x2334,
+        (Mps4, Medium) => 0x2322,
+        (Mps4, Low) => 0x2329,
+        (Mps10, High) => 0x2737,
+        (Mps10, Medium) => 0x2721,
+        (Mps10, Low) => 0x272A,
+    }
+}
+
+impl<I2C> ClimateSensor for ShtSensor<I2C>
+where
+    I2C: Read + Write,
+{
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        self.measure()
+    }
+}
+
+impl<I2C> Sensor for ShtSensor<I2C>
+where
+    I2C: Read + Write,
+{
+    /// Tipo de salida: [`SensorOutput`] (texto con temperatura y humedad).
+    type Output = SensorOutput;
+
+    /// Lee temperatura y humedad con el mismo formato que el DHT22.
+    ///
+    /// # Retorna
+    /// - `Ok(SensorOutput::Text(...))` con los valores formateados.
+    /// - `Err(SensorError::InvalidData)` si algún CRC es incorrecto.
+    /// - `Err(SensorError::IoError)` si falla la comunicación I2C.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let reading = self.measure()?;
+
+        Ok(SensorOutput::Text(format!(
+            "Temp: {:.1}°C, Hum: {:.1}%",
+            reading.temperature, reading.humidity
+        )))
+    }
+}
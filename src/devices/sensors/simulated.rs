@@ -0,0 +1,318 @@
+use std::f32::consts::PI;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::core::traits::climate::{ClimateReading, ClimateSensor};
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+
+/// Inyección de fallos para sensores simulados.
+///
+/// Permite reproducir en desarrollo los errores que aparecen con hardware real
+/// (`Timeout` por sensores desconectados, `InvalidData` por checksums incorrectos)
+/// y así probar la lógica de reintentos, almacenamiento y comunicación.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FailureInjection {
+    /// Probabilidad (0.0–1.0) de que una lectura devuelva `SensorError::Timeout`.
+    pub timeout_probability: f64,
+    /// Probabilidad (0.0–1.0) de que una lectura devuelva `SensorError::InvalidData`.
+    pub invalid_data_probability: f64,
+}
+
+impl FailureInjection {
+    /// Sin fallos: todas las lecturas son exitosas.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Crea una configuración de fallos con las probabilidades indicadas.
+    pub fn new(timeout_probability: f64, invalid_data_probability: f64) -> Self {
+        Self {
+            timeout_probability: timeout_probability.clamp(0.0, 1.0),
+            invalid_data_probability: invalid_data_probability.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Decide si la lectura actual debe fallar.
+    fn roll(&self, rng: &mut StdRng) -> Result<(), SensorError> {
+        let x: f64 = rng.gen();
+        if x < self.timeout_probability {
+            Err(SensorError::Timeout)
+        } else if x < self.timeout_probability + self.invalid_data_probability {
+            Err(SensorError::InvalidData)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Crea el generador aleatorio: reproducible si se indica semilla.
+fn make_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(s) => StdRng::seed_from_u64(s),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// SimulatedClimateSensor: sensor de **temperatura y humedad simulado** (camino aleatorio).
+///
+/// En cada lectura, la temperatura y la humedad varían un paso aleatorio acotado
+/// respecto del valor anterior, produciendo series realistas sin saltos bruscos.
+/// El formato de salida es el mismo que el de [`Dht22Sensor`](super::dht22::Dht22Sensor),
+/// por lo que puede sustituirlo en cualquier pipeline.
+///
+/// # Ejemplo
+/// ```
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::simulated::SimulatedClimateSensor;
+///
+/// let mut sensor = SimulatedClimateSensor::new(22.0, 55.0).with_seed(42);
+/// let lectura = sensor.read().unwrap();
+/// println!("{:?}", lectura);
+/// ```
+pub struct SimulatedClimateSensor {
+    /// Temperatura actual en °C.
+    temperature: f32,
+    /// Humedad relativa actual en %.
+    humidity: f32,
+    /// Variación máxima de temperatura por lectura (°C).
+    temperature_step: f32,
+    /// Variación máxima de humedad por lectura (%).
+    humidity_step: f32,
+    /// Rango permitido de temperatura (°C).
+    temperature_range: (f32, f32),
+    /// Fallos inyectados.
+    failures: FailureInjection,
+    /// Generador aleatorio.
+    rng: StdRng,
+}
+
+impl SimulatedClimateSensor {
+    /// Crea un sensor simulado a partir de valores iniciales.
+    ///
+    /// Usa pasos de ±0.3 °C y ±1 %, con temperatura acotada entre −40 y 80 °C
+    /// (rango del DHT22) y humedad entre 0 y 100 %.
+    pub fn new(initial_temperature: f32, initial_humidity: f32) -> Self {
+        Self {
+            temperature: initial_temperature,
+            humidity: initial_humidity.clamp(0.0, 100.0),
+            temperature_step: 0.3,
+            humidity_step: 1.0,
+            temperature_range: (-40.0, 80.0),
+            failures: FailureInjection::none(),
+            rng: make_rng(None),
+        }
+    }
+
+    /// Configura la variación máxima por lectura.
+    pub fn with_steps(mut self, temperature_step: f32, humidity_step: f32) -> Self {
+        self.temperature_step = temperature_step.abs();
+        self.humidity_step = humidity_step.abs();
+        self
+    }
+
+    /// Configura los fallos inyectados.
+    pub fn with_failures(mut self, failures: FailureInjection) -> Self {
+        self.failures = failures;
+        self
+    }
+
+    /// Fija la semilla del generador para obtener series reproducibles.
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.with_rng(make_rng(Some(seed)))
+    }
+
+    /// Usa el generador indicado (por ejemplo, uno compartido entre varios sensores).
+    pub fn with_rng(mut self, rng: StdRng) -> Self {
+        self.rng = rng;
+        self
+    }
+}
+
+impl ClimateSensor for SimulatedClimateSensor {
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        self.failures.roll(&mut self.rng)?;
+
+        let dt = self.rng.gen_range(-1.0..=1.0) * self.temperature_step;
+        let dh = self.rng.gen_range(-1.0..=1.0) * self.humidity_step;
+        let (t_min, t_max) = self.temperature_range;
+        self.temperature = (self.temperature + dt).clamp(t_min, t_max);
+        self.humidity = (self.humidity + dh).clamp(0.0, 100.0);
+
+        Ok(ClimateReading {
+            temperature: self.temperature,
+            humidity: self.humidity,
+        })
+    }
+}
+
+impl Sensor for SimulatedClimateSensor {
+    type Output = SensorOutput;
+
+    /// Devuelve la siguiente muestra del camino aleatorio con el formato del DHT22.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let reading = self.read_climate()?;
+
+        Ok(SensorOutput::Text(format!(
+            "Temp: {:.1}°C, Hum: {:.1}%",
+            reading.temperature, reading.humidity
+        )))
+    }
+}
+
+/// SimulatedRainSensor: sensor de **lluvia simulado**.
+///
+/// En cada lectura el estado cambia (SECO ↔ HÚMEDO) con la probabilidad indicada.
+/// Devuelve el mismo texto que [`MhRdSensor`](super::mhrd::MhRdSensor) en modo digital.
+pub struct SimulatedRainSensor {
+    /// Estado actual (`true` = mojado).
+    wet: bool,
+    /// Probabilidad (0.0–1.0) de cambiar de estado en cada lectura.
+    toggle_probability: f64,
+    /// Fallos inyectados.
+    failures: FailureInjection,
+    /// Generador aleatorio.
+    rng: StdRng,
+}
+
+impl SimulatedRainSensor {
+    /// Crea un sensor de lluvia simulado que empieza en estado SECO.
+    ///
+    /// # Parámetros
+    /// - `toggle_probability`: probabilidad de cambiar de estado en cada lectura.
+    pub fn new(toggle_probability: f64) -> Self {
+        Self {
+            wet: false,
+            toggle_probability: toggle_probability.clamp(0.0, 1.0),
+            failures: FailureInjection::none(),
+            rng: make_rng(None),
+        }
+    }
+
+    /// Configura los fallos inyectados.
+    pub fn with_failures(mut self, failures: FailureInjection) -> Self {
+        self.failures = failures;
+        self
+    }
+
+    /// Fija la semilla del generador para obtener series reproducibles.
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.with_rng(make_rng(Some(seed)))
+    }
+
+    /// Usa el generador indicado (por ejemplo, uno compartido entre varios sensores).
+    pub fn with_rng(mut self, rng: StdRng) -> Self {
+        self.rng = rng;
+        self
+    }
+}
+
+impl Sensor for SimulatedRainSensor {
+    type Output = SensorOutput;
+
+    /// Devuelve "HÚMEDO" o "SECO", cambiando de estado aleatoriamente.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.failures.roll(&mut self.rng)?;
+
+        if self.rng.gen_bool(self.toggle_probability) {
+            self.wet = !self.wet;
+        }
+
+        Ok(SensorOutput::Text(
+            if self.wet { "HÚMEDO".to_string() } else { "SECO".to_string() }
+        ))
+    }
+}
+
+/// SineWaveSensor: sensor que sigue un **perfil sinusoidal** en el tiempo.
+///
+/// Útil para simular ciclos diarios (temperatura de día/noche) o señales periódicas.
+/// El valor depende del tiempo transcurrido desde la creación del sensor:
+///
+/// `valor = offset + amplitud · sin(2π · t / periodo) + ruido`
+pub struct SineWaveSensor {
+    /// Valor medio de la señal.
+    offset: f32,
+    /// Amplitud de la oscilación.
+    amplitude: f32,
+    /// Periodo de la oscilación.
+    period: Duration,
+    /// Amplitud máxima del ruido uniforme añadido.
+    noise: f32,
+    /// Instante de referencia (fase cero).
+    start: Instant,
+    /// Fallos inyectados.
+    failures: FailureInjection,
+    /// Generador aleatorio.
+    rng: StdRng,
+}
+
+impl SineWaveSensor {
+    /// Crea un sensor sinusoidal sin ruido.
+    ///
+    /// # Parámetros
+    /// - `offset`: valor medio.
+    /// - `amplitude`: amplitud de la oscilación.
+    /// - `period`: periodo completo de la señal.
+    pub fn new(offset: f32, amplitude: f32, period: Duration) -> Self {
+        Self {
+            offset,
+            amplitude,
+            period,
+            noise: 0.0,
+            start: Instant::now(),
+            failures: FailureInjection::none(),
+            rng: make_rng(None),
+        }
+    }
+
+    /// Añade ruido uniforme de ±`noise` a cada lectura.
+    pub fn with_noise(mut self, noise: f32) -> Self {
+        self.noise = noise.abs();
+        self
+    }
+
+    /// Configura los fallos inyectados.
+    pub fn with_failures(mut self, failures: FailureInjection) -> Self {
+        self.failures = failures;
+        self
+    }
+
+    /// Fija la semilla del generador para obtener series reproducibles.
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.with_rng(make_rng(Some(seed)))
+    }
+
+    /// Usa el generador indicado (por ejemplo, uno compartido entre varios sensores).
+    pub fn with_rng(mut self, rng: StdRng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Calcula el valor de la señal para un tiempo transcurrido dado (sin ruido).
+    pub fn value_at(&self, elapsed: Duration) -> f32 {
+        if self.period.is_zero() {
+            return self.offset;
+        }
+        let phase = elapsed.as_secs_f32() / self.period.as_secs_f32();
+        self.offset + self.amplitude * (2.0 * PI * phase).sin()
+    }
+}
+
+impl Sensor for SineWaveSensor {
+    type Output = SensorOutput;
+
+    /// Devuelve el valor actual de la señal como `SensorOutput::Float`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.failures.roll(&mut self.rng)?;
+
+        let mut value = self.value_at(self.start.elapsed());
+        if self.noise > 0.0 {
+            value += self.rng.gen_range(-self.noise..=self.noise);
+        }
+
+        Ok(SensorOutput::Float(value))
+    }
+}
@@ -0,0 +1,408 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::core::traits::climate::{ClimateReading, ClimateSensor};
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+
+/// Subsistema del kernel que expone el canal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SysfsSubsystem {
+    /// `/sys/class/hwmon/hwmon*`
+    Hwmon,
+    /// `/sys/bus/iio/devices/iio:device*`
+    Iio,
+}
+
+/// Magnitud física de un canal, ya convertida a unidades de presentación.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    /// °C
+    Temperature,
+    /// % de humedad relativa
+    Humidity,
+    /// hPa
+    Pressure,
+    /// V
+    Voltage,
+    /// A
+    Current,
+    /// W
+    Power,
+    /// J
+    Energy,
+    /// RPM
+    FanSpeed,
+    /// lx
+    Illuminance,
+    /// Valor procesado sin conversión conocida
+    Other,
+}
+
+impl Quantity {
+    /// Unidad de presentación.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Quantity::Temperature => "°C",
+            Quantity::Humidity => "%",
+            Quantity::Pressure => "hPa",
+            Quantity::Voltage => "V",
+            Quantity::Current => "A",
+            Quantity::Power => "W",
+            Quantity::Energy => "J",
+            Quantity::FanSpeed => "RPM",
+            Quantity::Illuminance => "lx",
+            Quantity::Other => "",
+        }
+    }
+
+    /// Magnitud y factor a unidades de presentación de un tipo de canal hwmon.
+    ///
+    /// Unidades según `Documentation/hwmon/sysfs-interface.rst`.
+    fn from_hwmon(kind: &str) -> Option<(Self, f64)> {
+        match kind {
+            "temp" => Some((Quantity::Temperature, 1e-3)),
+            "humidity" => Some((Quantity::Humidity, 1e-3)),
+            "in" => Some((Quantity::Voltage, 1e-3)),
+            "curr" => Some((Quantity::Current, 1e-3)),
+            "power" => Some((Quantity::Power, 1e-6)),
+            "energy" => Some((Quantity::Energy, 1e-6)),
+            "fan" => Some((Quantity::FanSpeed, 1.0)),
+            _ => None,
+        }
+    }
+
+    /// Magnitud y factor a unidades de presentación de un tipo de canal IIO,
+    /// aplicado tras `(raw + offset) · scale`.
+    ///
+    /// Unidades según `Documentation/ABI/testing/sysfs-bus-iio`.
+    fn from_iio(kind: &str) -> (Self, f64) {
+        match kind {
+            "temp" => (Quantity::Temperature, 1e-3),
+            "humidityrelative" => (Quantity::Humidity, 1e-3),
+            "pressure" => (Quantity::Pressure, 10.0),
+            "voltage" => (Quantity::Voltage, 1e-3),
+            "current" => (Quantity::Current, 1e-3),
+            "power" => (Quantity::Power, 1e-3),
+            "illuminance" => (Quantity::Illuminance, 1.0),
+            _ => (Quantity::Other, 1.0),
+        }
+    }
+}
+
+/// Canal de medida descubierto en sysfs.
+///
+/// El valor se calcula como `(crudo + offset) · scale`, donde `scale` ya incluye
+/// la conversión a la unidad de [`Quantity::unit`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SysfsChannel {
+    /// Subsistema de origen.
+    pub subsystem: SysfsSubsystem,
+    /// Nombre del dispositivo (archivo `name`), por ejemplo `"dht11"` o `"cpu_thermal"`.
+    pub device_name: String,
+    /// Directorio del dispositivo.
+    pub device_path: PathBuf,
+    /// Identificador del canal (`"temp1"`, `"voltage0"`, `"humidityrelative"`...).
+    pub channel: String,
+    /// Etiqueta del canal (archivo `*_label`), si existe.
+    pub label: Option<String>,
+    /// Magnitud medida.
+    pub quantity: Quantity,
+    /// Archivo con el valor crudo o procesado.
+    pub value_path: PathBuf,
+    /// Desplazamiento aplicado al valor crudo.
+    pub offset: f64,
+    /// Factor aplicado tras el desplazamiento.
+    pub scale: f64,
+}
+
+impl SysfsChannel {
+    /// Lee el valor actual del canal.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si el driver del kernel no obtuvo respuesta (`ETIMEDOUT`).
+    /// - `Err(SensorError::IoError)` ante otros errores de lectura (por ejemplo `EIO` por checksum).
+    /// - `Err(SensorError::InvalidData)` si el contenido no es numérico.
+    pub fn read_value(&self) -> Result<f64, SensorError> {
+        let text = fs::read_to_string(&self.value_path).map_err(|e| map_io_error(&e))?;
+        let raw: f64 = text.trim().parse().map_err(|_| SensorError::InvalidData)?;
+        Ok((raw + self.offset) * self.scale)
+    }
+
+    /// Nombre legible: la etiqueta si existe, o `dispositivo/canal`.
+    pub fn display_name(&self) -> String {
+        self.label
+            .clone()
+            .unwrap_or_else(|| format!("{}/{}", self.device_name, self.channel))
+    }
+}
+
+/// Traduce los errores de lectura de sysfs a [`SensorError`].
+fn map_io_error(e: &io::Error) -> SensorError {
+    if e.raw_os_error() == Some(libc::ETIMEDOUT) || e.kind() == io::ErrorKind::TimedOut {
+        SensorError::Timeout
+    } else {
+        SensorError::IoError
+    }
+}
+
+/// Lee un archivo de texto de sysfs sin espacios finales.
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+/// Lee un archivo numérico de sysfs.
+fn read_f64(path: &Path) -> Option<f64> {
+    read_trimmed(path)?.parse().ok()
+}
+
+/// Subdirectorios de `dir` cuyo nombre empieza por `prefix`, ordenados.
+fn list_devices(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    let mut devices: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.file_name().to_string_lossy().starts_with(prefix))
+                .map(|e| e.path())
+                .collect()
+        })
+        .unwrap_or_default();
+    devices.sort();
+    devices
+}
+
+/// Nombres de archivo de un directorio, ordenados.
+fn list_files(dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|e| e.file_name().to_string_lossy().into_owned()).collect())
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+/// Descubre los canales de `/sys/class/hwmon/*` bajo `root`.
+///
+/// Se listan los archivos `<tipo><n>_input` de tipos conocidos (temp, in, curr,
+/// power, energy, humidity, fan).
+pub fn discover_hwmon<P: AsRef<Path>>(root: P) -> Vec<SysfsChannel> {
+    let mut channels = Vec::new();
+    for device in list_devices(&root.as_ref().join("sys/class/hwmon"), "hwmon") {
+        let device_name = read_trimmed(&device.join("name")).unwrap_or_default();
+        for file in list_files(&device) {
+            let Some(channel) = file.strip_suffix("_input") else {
+                continue;
+            };
+            let kind = channel.trim_end_matches(|c: char| c.is_ascii_digit());
+            if kind.len() == channel.len() {
+                continue;
+            }
+            let Some((quantity, scale)) = Quantity::from_hwmon(kind) else {
+                continue;
+            };
+            channels.push(SysfsChannel {
+                subsystem: SysfsSubsystem::Hwmon,
+                device_name: device_name.clone(),
+                device_path: device.clone(),
+                channel: channel.to_string(),
+                label: read_trimmed(&device.join(format!("{}_label", channel))),
+                quantity,
+                value_path: device.join(&file),
+                offset: 0.0,
+                scale,
+            });
+        }
+    }
+    channels
+}
+
+/// Descubre los canales de `/sys/bus/iio/devices/iio:device*` bajo `root`.
+///
+/// Para cada canal `in_<canal>_input` (valor procesado) o `in_<canal>_raw` se busca
+/// `in_<canal>_scale`/`in_<tipo>_scale` y `in_<canal>_offset`/`in_<tipo>_offset`.
+/// Si existen ambos, se prefiere el valor procesado.
+pub fn discover_iio<P: AsRef<Path>>(root: P) -> Vec<SysfsChannel> {
+    let mut channels = Vec::new();
+    for device in list_devices(&root.as_ref().join("sys/bus/iio/devices"), "iio:device") {
+        let device_name = read_trimmed(&device.join("name")).unwrap_or_default();
+        let files = list_files(&device);
+        for file in &files {
+            let Some(rest) = file.strip_prefix("in_") else {
+                continue;
+            };
+            let (channel, processed) = if let Some(c) = rest.strip_suffix("_input") {
+                (c, true)
+            } else if let Some(c) = rest.strip_suffix("_raw") {
+                if files.contains(&format!("in_{}_input", c)) {
+                    continue;
+                }
+                (c, false)
+            } else {
+                continue;
+            };
+            let kind = channel
+                .split(|c: char| c.is_ascii_digit() || c == '_' || c == '-')
+                .next()
+                .unwrap_or(channel);
+            let (quantity, unit_scale) = Quantity::from_iio(kind);
+
+            let (offset, scale) = if processed {
+                (0.0, 1.0)
+            } else {
+                let lookup = |attr: &str| {
+                    read_f64(&device.join(format!("in_{}_{}", channel, attr)))
+                        .or_else(|| read_f64(&device.join(format!("in_{}_{}", kind, attr))))
+                };
+                (lookup("offset").unwrap_or(0.0), lookup("scale").unwrap_or(1.0))
+            };
+
+            channels.push(SysfsChannel {
+                subsystem: SysfsSubsystem::Iio,
+                device_name: device_name.clone(),
+                device_path: device.clone(),
+                channel: channel.to_string(),
+                label: read_trimmed(&device.join(format!("in_{}_label", channel))),
+                quantity,
+                value_path: device.join(file),
+                offset,
+                scale: scale * unit_scale,
+            });
+        }
+    }
+    channels
+}
+
+/// Descubre todos los canales hwmon e IIO bajo `root` (normalmente `"/"`).
+pub fn discover<P: AsRef<Path>>(root: P) -> Vec<SysfsChannel> {
+    let mut channels = discover_hwmon(&root);
+    channels.extend(discover_iio(&root));
+    channels
+}
+
+/// Busca el primer canal de un dispositivo con la magnitud indicada.
+fn find_channel(root: &Path, device_name: &str, quantity: Quantity) -> Result<SysfsChannel, SensorError> {
+    discover(root)
+        .into_iter()
+        .find(|c| c.device_name == device_name && c.quantity == quantity)
+        .ok_or_else(|| {
+            eprintln!("[SYSFS] No se encontró {:?} en el dispositivo '{}'", quantity, device_name);
+            SensorError::InitializationError
+        })
+}
+
+/// SysfsSensor: expone un canal hwmon o IIO del kernel como [`Sensor`].
+///
+/// Devuelve el valor convertido como `SensorOutput::Float`.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::sysfs::{discover, SysfsSensor};
+///
+/// for channel in discover("/") {
+///     let nombre = channel.display_name();
+///     let unidad = channel.quantity.unit();
+///     let mut sensor = SysfsSensor::new(channel);
+///     println!("{}: {:?} {}", nombre, sensor.read(), unidad);
+/// }
+/// ```
+pub struct SysfsSensor {
+    channel: SysfsChannel,
+}
+
+impl SysfsSensor {
+    /// Crea un sensor a partir de un canal descubierto.
+    pub fn new(channel: SysfsChannel) -> Self {
+        Self { channel }
+    }
+
+    /// Busca bajo `root` el canal `channel` del dispositivo `device_name`.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si no existe.
+    pub fn find<P: AsRef<Path>>(root: P, device_name: &str, channel: &str) -> Result<Self, SensorError> {
+        discover(root)
+            .into_iter()
+            .find(|c| c.device_name == device_name && c.channel == channel)
+            .map(Self::new)
+            .ok_or_else(|| {
+                eprintln!("[SYSFS] No se encontró el canal '{}' en '{}'", channel, device_name);
+                SensorError::InitializationError
+            })
+    }
+
+    /// Canal leído.
+    pub fn channel(&self) -> &SysfsChannel {
+        &self.channel
+    }
+}
+
+impl Sensor for SysfsSensor {
+    /// Tipo de salida: [`SensorOutput`] (valor en la unidad de [`Quantity::unit`]).
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Float(self.channel.read_value()? as f32))
+    }
+}
+
+/// SysfsClimateSensor: temperatura y humedad de un driver del kernel.
+///
+/// Pensado para el driver IIO `dht11` del kernel (que también maneja DHT22), activado
+/// con el overlay `dtoverlay=dht11,gpiopin=4`. El kernel mide los tiempos del protocolo
+/// con interrupciones, por lo que es mucho más fiable que el bit-banging en espacio de
+/// usuario de [`DhtBase`](crate::devices::sensors::dht::DhtBase).
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::sysfs::SysfsClimateSensor;
+///
+/// let mut dht = SysfsClimateSensor::dht11().unwrap();
+/// println!("{:?}", dht.read()); // "Temp: 23.0°C, Hum: 41.0%"
+/// ```
+pub struct SysfsClimateSensor {
+    temperature: SysfsChannel,
+    humidity: SysfsChannel,
+}
+
+impl SysfsClimateSensor {
+    /// Busca el driver IIO `dht11` en el sistema.
+    pub fn dht11() -> Result<Self, SensorError> {
+        Self::find("/", "dht11")
+    }
+
+    /// Busca bajo `root` un dispositivo con canales de temperatura y humedad.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si falta alguno de los dos canales.
+    pub fn find<P: AsRef<Path>>(root: P, device_name: &str) -> Result<Self, SensorError> {
+        let root = root.as_ref();
+        Ok(Self {
+            temperature: find_channel(root, device_name, Quantity::Temperature)?,
+            humidity: find_channel(root, device_name, Quantity::Humidity)?,
+        })
+    }
+}
+
+impl ClimateSensor for SysfsClimateSensor {
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        Ok(ClimateReading {
+            temperature: self.temperature.read_value()? as f32,
+            humidity: self.humidity.read_value()? as f32,
+        })
+    }
+}
+
+impl Sensor for SysfsClimateSensor {
+    /// Tipo de salida: [`SensorOutput`] (texto con temperatura y humedad).
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let r = self.read_climate()?;
+        Ok(SensorOutput::Text(format!(
+            "Temp: {:.1}°C, Hum: {:.1}%",
+            r.temperature, r.humidity
+        )))
+    }
+}
@@ -0,0 +1,256 @@
+use std::ffi::CString;
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+
+/// Estado de limitación del firmware de la Raspberry Pi (`get_throttled`).
+///
+/// Los bits 0–3 indican la condición **actual** y los bits 16–19 si ha ocurrido
+/// **desde el arranque**.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThrottleState {
+    /// Valor crudo del registro.
+    pub raw: u32,
+}
+
+impl ThrottleState {
+    /// Tensión de alimentación insuficiente en este momento.
+    pub fn under_voltage(&self) -> bool {
+        self.raw & (1 << 0) != 0
+    }
+
+    /// Frecuencia del ARM limitada en este momento.
+    pub fn frequency_capped(&self) -> bool {
+        self.raw & (1 << 1) != 0
+    }
+
+    /// CPU ralentizada (throttling) en este momento.
+    pub fn throttled(&self) -> bool {
+        self.raw & (1 << 2) != 0
+    }
+
+    /// Límite de temperatura suave activo en este momento.
+    pub fn soft_temp_limit(&self) -> bool {
+        self.raw & (1 << 3) != 0
+    }
+
+    /// Alguna de las condiciones anteriores se ha producido desde el arranque.
+    pub fn occurred_since_boot(&self) -> bool {
+        self.raw & 0xF_0000 != 0
+    }
+
+    /// Alguna condición está activa ahora.
+    pub fn is_active(&self) -> bool {
+        self.raw & 0xF != 0
+    }
+}
+
+/// Métricas del equipo anfitrión.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemMetrics {
+    /// Temperatura máxima de las zonas térmicas (°C), si existen.
+    pub cpu_temperature: Option<f32>,
+    /// Carga media a 1, 5 y 15 minutos.
+    pub load_average: [f32; 3],
+    /// Memoria total (kB).
+    pub memory_total_kb: u64,
+    /// Memoria disponible (kB).
+    pub memory_available_kb: u64,
+    /// Tamaño del sistema de archivos (bytes).
+    pub disk_total_bytes: u64,
+    /// Espacio disponible para usuarios no privilegiados (bytes).
+    pub disk_available_bytes: u64,
+    /// Estado de limitación (solo en Raspberry Pi con firmware que lo expone).
+    pub throttle: Option<ThrottleState>,
+}
+
+impl SystemMetrics {
+    /// Porcentaje de memoria en uso.
+    pub fn memory_used_percent(&self) -> f32 {
+        if self.memory_total_kb == 0 {
+            return 0.0;
+        }
+        100.0 * (self.memory_total_kb - self.memory_available_kb.min(self.memory_total_kb)) as f32
+            / self.memory_total_kb as f32
+    }
+
+    /// Porcentaje de disco en uso.
+    pub fn disk_used_percent(&self) -> f32 {
+        if self.disk_total_bytes == 0 {
+            return 0.0;
+        }
+        100.0 * (self.disk_total_bytes - self.disk_available_bytes.min(self.disk_total_bytes)) as f32
+            / self.disk_total_bytes as f32
+    }
+}
+
+/// SystemSensor: métricas del **propio gateway** leídas de `/sys` y `/proc`.
+///
+/// Informa temperatura de CPU, carga media, memoria, uso de disco y estado de
+/// *throttling*, para detectar Raspberry Pi que se sobrecalientan dentro de cajas
+/// estancas junto a los sensores que leen.
+///
+/// Todas las rutas se resuelven bajo un directorio raíz configurable
+/// ([`SystemSensor::with_root`]), lo que permite probarlo con un árbol de archivos falso.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::system::SystemSensor;
+///
+/// let mut sensor = SystemSensor::new();
+/// println!("{:?}", sensor.read().unwrap());
+/// ```
+pub struct SystemSensor {
+    /// Directorio raíz bajo el que se buscan `sys/` y `proc/`.
+    root: PathBuf,
+    /// Punto de montaje cuyo uso de disco se informa (relativo a `root`).
+    disk_path: PathBuf,
+}
+
+/// Ruta del registro de throttling del firmware (relativa a la raíz).
+const THROTTLED_PATH: &str = "sys/devices/platform/soc/soc:firmware/get_throttled";
+
+impl Default for SystemSensor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemSensor {
+    /// Crea el sensor sobre la raíz real (`/`), informando el uso de disco de `/`.
+    pub fn new() -> Self {
+        Self::with_root("/")
+    }
+
+    /// Crea el sensor sobre un directorio raíz alternativo.
+    pub fn with_root<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            disk_path: PathBuf::new(),
+        }
+    }
+
+    /// Cambia el punto de montaje cuyo uso de disco se informa (por ejemplo `"var/lib/lince"`).
+    pub fn with_disk_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        let path = path.as_ref();
+        self.disk_path = path.strip_prefix("/").unwrap_or(path).to_path_buf();
+        self
+    }
+
+    /// Lee todas las métricas.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si falta `/proc/loadavg` o `/proc/meminfo`, o falla `statvfs`.
+    /// - `Err(SensorError::InvalidData)` si su formato no es el esperado.
+    pub fn read_metrics(&self) -> Result<SystemMetrics, SensorError> {
+        let (memory_total_kb, memory_available_kb) = self.read_meminfo()?;
+        let (disk_total_bytes, disk_available_bytes) = self.read_disk()?;
+        Ok(SystemMetrics {
+            cpu_temperature: self.read_cpu_temperature(),
+            load_average: self.read_load_average()?,
+            memory_total_kb,
+            memory_available_kb,
+            disk_total_bytes,
+            disk_available_bytes,
+            throttle: self.read_throttle(),
+        })
+    }
+
+    /// Temperatura máxima de `sys/class/thermal/thermal_zone*/temp` (miligrados).
+    pub fn read_cpu_temperature(&self) -> Option<f32> {
+        let entries = fs::read_dir(self.root.join("sys/class/thermal")).ok()?;
+        entries
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with("thermal_zone"))
+            .filter_map(|e| fs::read_to_string(e.path().join("temp")).ok())
+            .filter_map(|s| s.trim().parse::<i64>().ok())
+            .map(|m| m as f32 / 1000.0)
+            .reduce(f32::max)
+    }
+
+    /// Carga media de `proc/loadavg`.
+    pub fn read_load_average(&self) -> Result<[f32; 3], SensorError> {
+        let text = fs::read_to_string(self.root.join("proc/loadavg")).map_err(|_| SensorError::IoError)?;
+        let mut fields = text.split_whitespace().map(|f| f.parse::<f32>());
+        let mut load = [0.0; 3];
+        for value in load.iter_mut() {
+            *value = fields
+                .next()
+                .and_then(|f| f.ok())
+                .ok_or(SensorError::InvalidData)?;
+        }
+        Ok(load)
+    }
+
+    /// `MemTotal` y `MemAvailable` de `proc/meminfo` (kB).
+    fn read_meminfo(&self) -> Result<(u64, u64), SensorError> {
+        let text = fs::read_to_string(self.root.join("proc/meminfo")).map_err(|_| SensorError::IoError)?;
+        let campo = |nombre: &str| {
+            text.lines()
+                .find_map(|l| l.strip_prefix(nombre))
+                .and_then(|v| v.trim().trim_end_matches("kB").trim().parse::<u64>().ok())
+        };
+        let total = campo("MemTotal:").ok_or(SensorError::InvalidData)?;
+        // Kernels anteriores a 3.14 no exponen MemAvailable
+        let available = campo("MemAvailable:")
+            .or_else(|| campo("MemFree:"))
+            .ok_or(SensorError::InvalidData)?;
+        Ok((total, available))
+    }
+
+    /// Tamaño y espacio disponible del sistema de archivos mediante `statvfs`.
+    fn read_disk(&self) -> Result<(u64, u64), SensorError> {
+        let path = self.root.join(&self.disk_path);
+        let c_path = CString::new(path.as_os_str().as_bytes()).map_err(|_| SensorError::InvalidData)?;
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        // SAFETY: `c_path` es una cadena C válida y `stat` un búfer del tamaño correcto
+        if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+            eprintln!("[SISTEMA] statvfs falló en {}", path.display());
+            return Err(SensorError::IoError);
+        }
+        let frsize = stat.f_frsize as u64;
+        Ok((stat.f_blocks as u64 * frsize, stat.f_bavail as u64 * frsize))
+    }
+
+    /// Registro `get_throttled` del firmware (hexadecimal), si existe.
+    pub fn read_throttle(&self) -> Option<ThrottleState> {
+        let text = fs::read_to_string(self.root.join(THROTTLED_PATH)).ok()?;
+        let text = text.trim();
+        let hex = text.strip_prefix("0x").unwrap_or(text);
+        u32::from_str_radix(hex, 16).ok().map(|raw| ThrottleState { raw })
+    }
+}
+
+impl Sensor for SystemSensor {
+    /// Tipo de salida: [`SensorOutput`] (texto con todas las métricas).
+    type Output = SensorOutput;
+
+    /// Devuelve, por ejemplo,
+    /// `"CPU: 52.1°C, Carga: 0.42/0.35/0.30, Mem: 23.4%, Disco: 41.2%, Throttling: no"`.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let m = self.read_metrics()?;
+        let cpu = m
+            .cpu_temperature
+            .map_or_else(|| "N/D".to_string(), |t| format!("{:.1}°C", t));
+        let throttling = match m.throttle {
+            None => "N/D",
+            Some(t) if t.is_active() => "activo",
+            Some(t) if t.occurred_since_boot() => "ocurrido",
+            Some(_) => "no",
+        };
+        Ok(SensorOutput::Text(format!(
+            "CPU: {}, Carga: {:.2}/{:.2}/{:.2}, Mem: {:.1}%, Disco: {:.1}%, Throttling: {}",
+            cpu,
+            m.load_average[0],
+            m.load_average[1],
+            m.load_average[2],
+            m.memory_used_percent(),
+            m.disk_used_percent(),
+            throttling
+        )))
+    }
+}
@@ -0,0 +1,338 @@
+use std::thread;
+use std::time::Duration;
+
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+use crate::core::traits::sensor::Sensor;
+use crate::core::{SensorError, SensorOutput};
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto (ADDR sin conectar).
+pub const DEFAULT_ADDRESS: u8 = 0x39;
+/// Dirección I2C con ADDR a GND.
+pub const ADDRESS_LOW: u8 = 0x29;
+/// Dirección I2C con ADDR a VDD.
+pub const ADDRESS_HIGH: u8 = 0x49;
+
+// Bits del byte de comando
+const COMMAND: u8 = 0x80;
+const WORD: u8 = 0x20;
+
+// Registros
+const REG_CONTROL: u8 = 0x00;
+const REG_TIMING: u8 = 0x01;
+const REG_ID: u8 = 0x0A;
+const REG_DATA0: u8 = 0x0C;
+const REG_DATA1: u8 = 0x0E;
+
+const POWER_ON: u8 = 0x03;
+const POWER_OFF: u8 = 0x00;
+
+/// Ganancia del ADC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tsl2561Gain {
+    /// 1x: luz intensa.
+    X1,
+    /// 16x: poca luz.
+    X16,
+}
+
+/// Tiempo de integración.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tsl2561Integration {
+    /// 13.7 ms (máximo 5047 cuentas).
+    Ms13,
+    /// 101 ms (máximo 37177 cuentas).
+    Ms101,
+    /// 402 ms (máximo 65535 cuentas).
+    Ms402,
+}
+
+impl Tsl2561Integration {
+    fn bits(self) -> u8 {
+        match self {
+            Tsl2561Integration::Ms13 => 0b00,
+            Tsl2561Integration::Ms101 => 0b01,
+            Tsl2561Integration::Ms402 => 0b10,
+        }
+    }
+
+    fn duration(self) -> Duration {
+        match self {
+            Tsl2561Integration::Ms13 => Duration::from_millis(14),
+            Tsl2561Integration::Ms101 => Duration::from_millis(102),
+            Tsl2561Integration::Ms402 => Duration::from_millis(403),
+        }
+    }
+
+    /// Cuentas de saturación de los canales.
+    fn max_counts(self) -> u16 {
+        match self {
+            Tsl2561Integration::Ms13 => 5047,
+            Tsl2561Integration::Ms101 => 37177,
+            Tsl2561Integration::Ms402 => 65535,
+        }
+    }
+
+    /// Factor para normalizar a la integración nominal de 402 ms.
+    fn scale(self) -> f32 {
+        match self {
+            Tsl2561Integration::Ms13 => 322.0 / 11.0,
+            Tsl2561Integration::Ms101 => 322.0 / 81.0,
+            Tsl2561Integration::Ms402 => 1.0,
+        }
+    }
+}
+
+/// Encapsulado del chip, que determina los coeficientes del cálculo de lux.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tsl2561Package {
+    /// TSL2561T, FN y CL.
+    T,
+    /// TSL2561CS (ChipScale).
+    Cs,
+}
+
+/// Calcula la iluminancia a partir de los dos canales (datasheet TSL2561, "Calculating Lux").
+///
+/// `ch0` (visible + IR) y `ch1` (solo IR) deben estar normalizados a 402 ms y ganancia 16x.
+///
+/// # Ejemplo
+/// ```
+/// use lince::devices::sensors::tsl2561::{calculate_lux, Tsl2561Package};
+///
+/// // Sin componente infrarroja: lux = 0.0304 · ch0
+/// assert!((calculate_lux(1000.0, 0.0, Tsl2561Package::T) - 30.4).abs() < 1e-3);
+/// ```
+pub fn calculate_lux(ch0: f32, ch1: f32, package: Tsl2561Package) -> f32 {
+    if ch0 <= 0.0 {
+        return 0.0;
+    }
+    let ratio = ch1 / ch0;
+    let lux = match package {
+        Tsl2561Package::T => match ratio {
+            r if r <= 0.50 => 0.0304 * ch0 - 0.062 * ch0 * r.powf(1.4),
+            r if r <= 0.61 => 0.0224 * ch0 - 0.031 * ch1,
+            r if r <= 0.80 => 0.0128 * ch0 - 0.0153 * ch1,
+            r if r <= 1.30 => 0.00146 * ch0 - 0.00112 * ch1,
+            _ => 0.0,
+        },
+        Tsl2561Package::Cs => match ratio {
+            r if r <= 0.52 => 0.0315 * ch0 - 0.0593 * ch0 * r.powf(1.4),
+            r if r <= 0.65 => 0.0229 * ch0 - 0.0291 * ch1,
+            r if r <= 0.80 => 0.0157 * ch0 - 0.0180 * ch1,
+            r if r <= 1.30 => 0.00338 * ch0 - 0.00260 * ch1,
+            _ => 0.0,
+        },
+    };
+    lux.max(0.0)
+}
+
+/// Implementación del sensor de luz **AMS/TAOS TSL2561** sobre I2C.
+///
+/// Combina un fotodiodo de banda ancha (canal 0) y uno infrarrojo (canal 1) para
+/// aproximar la respuesta del ojo humano. Ganancia y tiempo de integración son
+/// configurables, y el auto-ajuste cambia la ganancia según el nivel de luz.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::core::traits::sensor::Sensor;
+/// use lince::devices::sensors::tsl2561::{Tsl2561Integration, Tsl2561Sensor, DEFAULT_ADDRESS};
+///
+/// let mut sensor = Tsl2561Sensor::new(1, DEFAULT_ADDRESS).unwrap();
+/// sensor.set_integration_time(Tsl2561Integration::Ms101).unwrap();
+/// sensor.set_auto_range(true);
+/// println!("{:?}", sensor.read().unwrap()); // Float(812.3)
+/// ```
+pub struct Tsl2561Sensor<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el sensor.
+    i2c: I2C,
+    /// Dirección I2C del sensor.
+    address: u8,
+    /// Encapsulado detectado a partir del registro ID.
+    package: Tsl2561Package,
+    /// Ganancia actual.
+    gain: Tsl2561Gain,
+    /// Tiempo de integración actual.
+    integration: Tsl2561Integration,
+    /// Ajusta la ganancia automáticamente según el nivel de luz.
+    auto_range: bool,
+}
+
+impl Tsl2561Sensor<I2cDriver> {
+    /// Crea una nueva instancia del sensor en un bus I2C del sistema.
+    ///
+    /// # Parámetros
+    /// - `bus`: número del bus I2C (`1` en la Raspberry Pi).
+    /// - `address`: [`DEFAULT_ADDRESS`], [`ADDRESS_LOW`] o [`ADDRESS_HIGH`].
+    pub fn new(bus: u8, address: u8) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address)
+    }
+}
+
+impl<I2C> Tsl2561Sensor<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Crea una nueva instancia del sensor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Enciende el sensor, identifica el encapsulado y configura ganancia 1x y 402 ms.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el sensor no responde.
+    /// - `Err(SensorError::InitializationError)` si el registro ID no corresponde a un TSL2561.
+    pub fn with_i2c(i2c: I2C, address: u8) -> Result<Self, SensorError> {
+        let mut sensor = Self {
+            i2c,
+            address,
+            package: Tsl2561Package::T,
+            gain: Tsl2561Gain::X1,
+            integration: Tsl2561Integration::Ms402,
+            auto_range: false,
+        };
+
+        sensor.write_register(REG_CONTROL, POWER_ON)?;
+        sensor.package = match sensor.read_register(REG_ID)? >> 4 {
+            0x5 => Tsl2561Package::T,
+            0x1 => Tsl2561Package::Cs,
+            id => {
+                eprintln!("[TSL2561] Identificador desconocido: 0x{:X}", id);
+                return Err(SensorError::InitializationError);
+            }
+        };
+        sensor.write_timing()?;
+        Ok(sensor)
+    }
+
+    /// Encapsulado detectado.
+    pub fn package(&self) -> Tsl2561Package {
+        self.package
+    }
+
+    /// Ganancia actual.
+    pub fn gain(&self) -> Tsl2561Gain {
+        self.gain
+    }
+
+    /// Cambia la ganancia del ADC.
+    pub fn set_gain(&mut self, gain: Tsl2561Gain) -> Result<(), SensorError> {
+        self.gain = gain;
+        self.write_timing()
+    }
+
+    /// Tiempo de integración actual.
+    pub fn integration_time(&self) -> Tsl2561Integration {
+        self.integration
+    }
+
+    /// Cambia el tiempo de integración: más largo, más sensible y más lento.
+    pub fn set_integration_time(&mut self, integration: Tsl2561Integration) -> Result<(), SensorError> {
+        self.integration = integration;
+        self.write_timing()
+    }
+
+    /// Activa o desactiva el auto-ajuste de ganancia.
+    pub fn set_auto_range(&mut self, enabled: bool) {
+        self.auto_range = enabled;
+    }
+
+    /// Apaga el sensor. Se enciende de nuevo con [`Tsl2561Sensor::power_up`].
+    pub fn power_down(&mut self) -> Result<(), SensorError> {
+        self.write_register(REG_CONTROL, POWER_OFF)
+    }
+
+    /// Enciende el sensor.
+    pub fn power_up(&mut self) -> Result<(), SensorError> {
+        self.write_register(REG_CONTROL, POWER_ON)
+    }
+
+    /// Devuelve el bus I2C, consumiendo el sensor.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Espera un ciclo de integración completo y devuelve las cuentas `(ch0, ch1)`.
+    pub fn read_channels(&mut self) -> Result<(u16, u16), SensorError> {
+        thread::sleep(self.integration.duration());
+        Ok((self.read_word(REG_DATA0)?, self.read_word(REG_DATA1)?))
+    }
+
+    /// Mide la iluminancia en lux.
+    ///
+    /// Con auto-ajuste activo se pasa a 1x si algún canal se satura y a 16x si la
+    /// señal es lo bastante baja como para no saturar con esa ganancia.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el sensor queda saturado.
+    pub fn read_lux(&mut self) -> Result<f32, SensorError> {
+        let max = self.integration.max_counts();
+        let mut channels = self.read_channels()?;
+
+        if self.auto_range {
+            let saturated = |(ch0, ch1): (u16, u16)| ch0 >= max || ch1 >= max;
+            if self.gain == Tsl2561Gain::X16 && saturated(channels) {
+                self.set_gain(Tsl2561Gain::X1)?;
+                channels = self.read_channels()?;
+            } else if self.gain == Tsl2561Gain::X1 && channels.0 < max / 20 {
+                self.set_gain(Tsl2561Gain::X16)?;
+                channels = self.read_channels()?;
+            }
+        }
+
+        let (ch0, ch1) = channels;
+        if ch0 >= max || ch1 >= max {
+            eprintln!("[TSL2561] Sensor saturado (ch0={}, ch1={})", ch0, ch1);
+            return Err(SensorError::InvalidData);
+        }
+
+        let mut scale = self.integration.scale();
+        if self.gain == Tsl2561Gain::X1 {
+            scale *= 16.0;
+        }
+        Ok(calculate_lux(ch0 as f32 * scale, ch1 as f32 * scale, self.package))
+    }
+
+    fn write_timing(&mut self) -> Result<(), SensorError> {
+        let gain = if self.gain == Tsl2561Gain::X16 { 0x10 } else { 0x00 };
+        self.write_register(REG_TIMING, gain | self.integration.bits())
+    }
+
+    fn write_register(&mut self, reg: u8, value: u8) -> Result<(), SensorError> {
+        self.i2c
+            .write(self.address, &[COMMAND | reg, value])
+            .map_err(|_| SensorError::IoError)
+    }
+
+    fn read_register(&mut self, reg: u8) -> Result<u8, SensorError> {
+        let mut buf = [0u8; 1];
+        self.i2c
+            .write_read(self.address, &[COMMAND | reg], &mut buf)
+            .map_err(|_| SensorError::IoError)?;
+        Ok(buf[0])
+    }
+
+    fn read_word(&mut self, reg: u8) -> Result<u16, SensorError> {
+        let mut buf = [0u8; 2];
+        self.i2c
+            .write_read(self.address, &[COMMAND | WORD | reg], &mut buf)
+            .map_err(|_| SensorError::IoError)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+}
+
+impl<I2C> Sensor for Tsl2561Sensor<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Tipo de salida: [`SensorOutput`] (iluminancia en lux).
+    type Output = SensorOutput;
+
+    /// Lee la iluminancia en lux como `SensorOutput::Float`.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el sensor está saturado.
+    /// - `Err(SensorError::IoError)` si falla la comunicación I2C.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Float(self.read_lux()?))
+    }
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::SensorError;
+
+/// Directorio de los dispositivos 1-Wire del kernel, relativo a la raíz del sistema.
+pub const W1_DEVICES: &str = "sys/bus/w1/devices";
+
+/// Dispositivo esclavo 1-Wire gestionado por el kernel (`/sys/bus/w1/devices/<id>`).
+///
+/// Base común de los drivers de familias que exponen archivos binarios o de texto
+/// (`state`, `output`, `vad`...) en su directorio de sysfs.
+#[derive(Debug, Clone)]
+pub struct W1Device {
+    /// Directorio del dispositivo.
+    dir: PathBuf,
+}
+
+impl W1Device {
+    /// Abre un dispositivo comprobando que existe y que pertenece a la familia esperada.
+    ///
+    /// # Parámetros
+    /// - `root`: raíz del sistema de archivos (`/` en producción).
+    /// - `device_id`: identificador del kernel (ej. `"3a-0000001c5d7e"`).
+    /// - `family`: código de familia esperado.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si el identificador no es de la familia
+    ///   o el dispositivo no está presente.
+    pub fn open<P: AsRef<Path>>(root: P, device_id: &str, family: u8) -> Result<Self, SensorError> {
+        if !device_id.starts_with(&format!("{:02x}-", family)) {
+            eprintln!("[W1] {} no es un dispositivo de la familia {:02x}", device_id, family);
+            return Err(SensorError::InitializationError);
+        }
+        let dir = root.as_ref().join(W1_DEVICES).join(device_id);
+        if !dir.is_dir() {
+            eprintln!(
+                "[W1] Dispositivo no encontrado: {}. Verifica que OneWire está habilitado.",
+                device_id
+            );
+            return Err(SensorError::InitializationError);
+        }
+        Ok(Self { dir })
+    }
+
+    /// Directorio del dispositivo.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Lee el contenido binario de un archivo del dispositivo.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el archivo no puede leerse (el kernel falla la
+    ///   lectura cuando el dispositivo no responde o el CRC no es correcto).
+    pub fn read(&self, file: &str) -> Result<Vec<u8>, SensorError> {
+        fs::read(self.dir.join(file)).map_err(|e| {
+            eprintln!("[W1] Error al leer {}: {}", file, e);
+            SensorError::IoError
+        })
+    }
+
+    /// Lee un archivo de texto que contiene un entero.
+    pub fn read_int(&self, file: &str) -> Result<i64, SensorError> {
+        let data = self.read(file)?;
+        String::from_utf8_lossy(&data).trim().parse().map_err(|_| {
+            eprintln!("[W1] Valor no numérico en {}", file);
+            SensorError::InvalidData
+        })
+    }
+
+    /// Escribe en un archivo del dispositivo.
+    pub fn write(&self, file: &str, data: &[u8]) -> Result<(), SensorError> {
+        fs::write(self.dir.join(file), data).map_err(|e| {
+            eprintln!("[W1] Error al escribir {}: {}", file, e);
+            SensorError::IoError
+        })
+    }
+}
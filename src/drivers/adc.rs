@@ -0,0 +1,32 @@
+use crate::core::SensorError;
+
+/// AnalogInput: canal de entrada analógica leído a través de un ADC.
+///
+/// Abstrae el conversor analógico-digital concreto (MCP3008, ADS1115, etc.)
+/// para que los sensores analógicos trabajen con cuentas crudas sin depender
+/// del bus (SPI/I2C) ni del chip utilizado.
+pub trait AnalogInput {
+    /// Lee el valor crudo del canal, en cuentas del ADC (`0..=max_raw()`).
+    ///
+    /// # Retorno
+    /// - `Ok(u16)`: valor convertido.
+    /// - `Err(SensorError)`: fallo de comunicación con el ADC.
+    fn read_raw(&mut self) -> Result<u16, SensorError>;
+
+    /// Valor crudo máximo que puede devolver el ADC (por ejemplo `1023` para 10 bits).
+    fn max_raw(&self) -> u16;
+
+    /// Tensión correspondiente a `max_raw()` (V). Por defecto 3.3 V, la referencia
+    /// habitual en la Raspberry Pi.
+    fn reference_voltage(&self) -> f32 {
+        3.3
+    }
+
+    /// Lee el canal y lo convierte a voltios.
+    ///
+    /// Los ADC con entradas diferenciales lo sobrescriben para devolver valores negativos.
+    fn read_voltage(&mut self) -> Result<f32, SensorError> {
+        let raw = self.read_raw()?;
+        Ok(raw as f32 / self.max_raw() as f32 * self.reference_voltage())
+    }
+}
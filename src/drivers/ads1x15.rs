@@ -0,0 +1,383 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+use crate::core::SensorError;
+use crate::drivers::adc::AnalogInput;
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto (ADDR a GND). Alternativas: 0x49 (VDD), 0x4A (SDA), 0x4B (SCL).
+pub const DEFAULT_ADDRESS: u8 = 0x48;
+
+const REG_CONVERSION: u8 = 0x00;
+const REG_CONFIG: u8 = 0x01;
+
+/// Bit OS: escribir 1 inicia una conversión; leído a 1 indica que no hay conversión en curso.
+const CONFIG_OS: u16 = 0x8000;
+const CONFIG_MODE_SINGLE: u16 = 0x0100;
+/// Comparador desactivado (COMP_QUE = 11).
+const CONFIG_COMP_DISABLE: u16 = 0x0003;
+
+/// Tasas de muestreo (muestras/s) del ADS1015, indexadas por el campo DR.
+const ADS1015_RATES: [u16; 7] = [128, 250, 490, 920, 1600, 2400, 3300];
+/// Tasas de muestreo (muestras/s) del ADS1115, indexadas por el campo DR.
+const ADS1115_RATES: [u16; 8] = [8, 16, 32, 64, 128, 250, 475, 860];
+
+/// Modelo del conversor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ads1x15Model {
+    /// 12 bits, hasta 3300 muestras/s.
+    Ads1015,
+    /// 16 bits, hasta 860 muestras/s.
+    Ads1115,
+}
+
+impl Ads1x15Model {
+    fn rates(self) -> &'static [u16] {
+        match self {
+            Ads1x15Model::Ads1015 => &ADS1015_RATES,
+            Ads1x15Model::Ads1115 => &ADS1115_RATES,
+        }
+    }
+
+    /// Valor máximo positivo del resultado.
+    fn max_code(self) -> i16 {
+        match self {
+            Ads1x15Model::Ads1015 => 2047,
+            Ads1x15Model::Ads1115 => 32767,
+        }
+    }
+
+    /// Cuentas que corresponden al fondo de escala del PGA (1 LSB = FS / 2^(bits - 1)).
+    fn full_scale_code(self) -> f32 {
+        match self {
+            Ads1x15Model::Ads1015 => 2048.0,
+            Ads1x15Model::Ads1115 => 32768.0,
+        }
+    }
+}
+
+/// Ganancia del amplificador programable (PGA) y su rango a fondo de escala.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ads1x15Gain {
+    /// ±6.144 V
+    TwoThirds,
+    /// ±4.096 V
+    One,
+    /// ±2.048 V
+    Two,
+    /// ±1.024 V
+    Four,
+    /// ±0.512 V
+    Eight,
+    /// ±0.256 V
+    Sixteen,
+}
+
+impl Ads1x15Gain {
+    /// Tensión a fondo de escala (V).
+    pub fn full_scale(self) -> f32 {
+        match self {
+            Ads1x15Gain::TwoThirds => 6.144,
+            Ads1x15Gain::One => 4.096,
+            Ads1x15Gain::Two => 2.048,
+            Ads1x15Gain::Four => 1.024,
+            Ads1x15Gain::Eight => 0.512,
+            Ads1x15Gain::Sixteen => 0.256,
+        }
+    }
+
+    fn bits(self) -> u16 {
+        match self {
+            Ads1x15Gain::TwoThirds => 0b000,
+            Ads1x15Gain::One => 0b001,
+            Ads1x15Gain::Two => 0b010,
+            Ads1x15Gain::Four => 0b011,
+            Ads1x15Gain::Eight => 0b100,
+            Ads1x15Gain::Sixteen => 0b101,
+        }
+    }
+}
+
+/// Entrada del multiplexor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ads1x15Channel {
+    /// AINn respecto a GND (0–3).
+    Single(u8),
+    /// AIN0 − AIN1
+    Diff0_1,
+    /// AIN0 − AIN3
+    Diff0_3,
+    /// AIN1 − AIN3
+    Diff1_3,
+    /// AIN2 − AIN3
+    Diff2_3,
+}
+
+impl Ads1x15Channel {
+    fn mux_bits(self) -> Result<u16, SensorError> {
+        Ok(match self {
+            Ads1x15Channel::Diff0_1 => 0b000,
+            Ads1x15Channel::Diff0_3 => 0b001,
+            Ads1x15Channel::Diff1_3 => 0b010,
+            Ads1x15Channel::Diff2_3 => 0b011,
+            Ads1x15Channel::Single(n) if n < 4 => 0b100 | n as u16,
+            Ads1x15Channel::Single(_) => return Err(SensorError::InitializationError),
+        })
+    }
+}
+
+/// Modo de conversión.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ads1x15Mode {
+    /// Una conversión por lectura; el chip queda en reposo entre lecturas.
+    SingleShot,
+    /// Conversión continua; cada lectura obtiene el último resultado.
+    Continuous,
+}
+
+/// Ads1x15Driver: ADC **TI ADS1015/ADS1115** (12/16 bits, 4 canales) conectado por I2C.
+///
+/// Ofrece mayor resolución que el [`Mcp3008Driver`](crate::drivers::mcp3008::Mcp3008Driver),
+/// ganancia programable (hasta ±256 mV a fondo de escala) y entradas diferenciales.
+/// Implementa [`AnalogInput`] para el canal seleccionado; con [`Ads1x15Driver::read_channel`]
+/// se puede leer cualquier otro canal del mismo chip.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::drivers::adc::AnalogInput;
+/// use lince::drivers::ads1x15::{Ads1x15Channel, Ads1x15Driver, Ads1x15Gain, Ads1x15Model, DEFAULT_ADDRESS};
+///
+/// let mut adc = Ads1x15Driver::new(1, DEFAULT_ADDRESS, Ads1x15Model::Ads1115, Ads1x15Channel::Single(0)).unwrap();
+/// adc.set_gain(Ads1x15Gain::Two).unwrap();
+/// println!("{:.4} V", adc.read_voltage().unwrap());
+/// ```
+pub struct Ads1x15Driver<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el ADC.
+    i2c: I2C,
+    /// Dirección I2C del ADC.
+    address: u8,
+    /// Modelo del ADC.
+    model: Ads1x15Model,
+    /// Canal usado por [`AnalogInput`].
+    channel: Ads1x15Channel,
+    /// Ganancia del PGA.
+    gain: Ads1x15Gain,
+    /// Índice de la tasa de muestreo (campo DR).
+    data_rate: u16,
+    /// Modo de conversión.
+    mode: Ads1x15Mode,
+    /// Canal configurado en el chip en modo continuo.
+    running: Option<Ads1x15Channel>,
+}
+
+impl Ads1x15Driver<I2cDriver> {
+    /// Abre el ADC en un bus I2C del sistema.
+    ///
+    /// # Parámetros
+    /// - `bus`: número del bus I2C (`1` en la Raspberry Pi).
+    /// - `address`: dirección del ADC (0x48–0x4B).
+    /// - `model`: modelo del ADC.
+    /// - `channel`: canal leído por [`AnalogInput`].
+    pub fn new(bus: u8, address: u8, model: Ads1x15Model, channel: Ads1x15Channel) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address, model, channel)
+    }
+}
+
+impl<I2C> Ads1x15Driver<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Crea el driver sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Queda en modo single-shot, ganancia ±4.096 V y 128 (ADS1115) o 1600 (ADS1015) muestras/s.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si el canal no existe.
+    /// - `Err(SensorError::IoError)` si el ADC no responde.
+    pub fn with_i2c(i2c: I2C, address: u8, model: Ads1x15Model, channel: Ads1x15Channel) -> Result<Self, SensorError> {
+        channel.mux_bits()?;
+        let mut adc = Self {
+            i2c,
+            address,
+            model,
+            channel,
+            gain: Ads1x15Gain::One,
+            data_rate: 4,
+            mode: Ads1x15Mode::SingleShot,
+            running: None,
+        };
+        adc.read_register(REG_CONFIG)?;
+        Ok(adc)
+    }
+
+    /// Modelo del ADC.
+    pub fn model(&self) -> Ads1x15Model {
+        self.model
+    }
+
+    /// Canal usado por [`AnalogInput`].
+    pub fn channel(&self) -> Ads1x15Channel {
+        self.channel
+    }
+
+    /// Cambia el canal usado por [`AnalogInput`].
+    pub fn set_channel(&mut self, channel: Ads1x15Channel) -> Result<(), SensorError> {
+        channel.mux_bits()?;
+        self.channel = channel;
+        Ok(())
+    }
+
+    /// Ganancia actual.
+    pub fn gain(&self) -> Ads1x15Gain {
+        self.gain
+    }
+
+    /// Cambia la ganancia del PGA.
+    ///
+    /// La tensión de entrada nunca debe superar VDD + 0.3 V, aunque el fondo de escala sea mayor.
+    pub fn set_gain(&mut self, gain: Ads1x15Gain) -> Result<(), SensorError> {
+        self.gain = gain;
+        self.restart();
+        Ok(())
+    }
+
+    /// Tasa de muestreo actual (muestras/s).
+    pub fn data_rate(&self) -> u16 {
+        self.model.rates()[self.data_rate as usize]
+    }
+
+    /// Cambia la tasa de muestreo.
+    ///
+    /// - ADS1015: 128, 250, 490, 920, 1600, 2400 o 3300 muestras/s.
+    /// - ADS1115: 8, 16, 32, 64, 128, 250, 475 o 860 muestras/s.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si la tasa no es admitida por el modelo.
+    pub fn set_data_rate(&mut self, sps: u16) -> Result<(), SensorError> {
+        self.data_rate = self
+            .model
+            .rates()
+            .iter()
+            .position(|&r| r == sps)
+            .ok_or(SensorError::InitializationError)? as u16;
+        self.restart();
+        Ok(())
+    }
+
+    /// Modo de conversión actual.
+    pub fn mode(&self) -> Ads1x15Mode {
+        self.mode
+    }
+
+    /// Cambia entre single-shot y continuo.
+    pub fn set_mode(&mut self, mode: Ads1x15Mode) -> Result<(), SensorError> {
+        self.mode = mode;
+        self.running = None;
+        if mode == Ads1x15Mode::SingleShot {
+            // Una conversión única deja el chip en reposo
+            self.write_config(self.channel, true)?;
+        }
+        Ok(())
+    }
+
+    /// Devuelve el bus I2C, consumiendo el driver.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Lee el resultado con signo de un canal (en cuentas del modelo).
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si la conversión no termina a tiempo.
+    pub fn read_channel(&mut self, channel: Ads1x15Channel) -> Result<i16, SensorError> {
+        match self.mode {
+            Ads1x15Mode::SingleShot => {
+                self.write_config(channel, true)?;
+                let deadline = Instant::now() + self.conversion_time() * 4;
+                thread::sleep(self.conversion_time());
+                while self.read_register(REG_CONFIG)? & CONFIG_OS == 0 {
+                    if Instant::now() > deadline {
+                        return Err(SensorError::Timeout);
+                    }
+                    thread::sleep(Duration::from_micros(100));
+                }
+            }
+            Ads1x15Mode::Continuous => {
+                if self.running != Some(channel) {
+                    self.write_config(channel, false)?;
+                    self.running = Some(channel);
+                    // Descarta la conversión en curso con la configuración anterior
+                    thread::sleep(self.conversion_time() * 2);
+                }
+            }
+        }
+
+        let raw = self.read_register(REG_CONVERSION)? as i16;
+        Ok(match self.model {
+            Ads1x15Model::Ads1015 => raw >> 4,
+            Ads1x15Model::Ads1115 => raw,
+        })
+    }
+
+    /// Lee un canal y lo convierte a voltios (con signo en canales diferenciales).
+    pub fn read_channel_voltage(&mut self, channel: Ads1x15Channel) -> Result<f32, SensorError> {
+        let code = self.read_channel(channel)?;
+        Ok(code as f32 / self.model.full_scale_code() * self.gain.full_scale())
+    }
+
+    /// Duración de una conversión con la tasa actual (con un 10 % de margen).
+    fn conversion_time(&self) -> Duration {
+        Duration::from_micros(1_100_000 / self.data_rate() as u64)
+    }
+
+    /// Obliga a reescribir la configuración en la próxima lectura continua.
+    fn restart(&mut self) {
+        self.running = None;
+    }
+
+    fn write_config(&mut self, channel: Ads1x15Channel, single_shot: bool) -> Result<(), SensorError> {
+        let mut config = (channel.mux_bits()? << 12)
+            | (self.gain.bits() << 9)
+            | (self.data_rate << 5)
+            | CONFIG_COMP_DISABLE;
+        if single_shot {
+            config |= CONFIG_OS | CONFIG_MODE_SINGLE;
+        }
+        let [high, low] = config.to_be_bytes();
+        self.i2c
+            .write(self.address, &[REG_CONFIG, high, low])
+            .map_err(|_| SensorError::IoError)
+    }
+
+    fn read_register(&mut self, reg: u8) -> Result<u16, SensorError> {
+        let mut buf = [0u8; 2];
+        self.i2c
+            .write_read(self.address, &[reg], &mut buf)
+            .map_err(|_| SensorError::IoError)?;
+        Ok(u16::from_be_bytes(buf))
+    }
+}
+
+impl<I2C> AnalogInput for Ads1x15Driver<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Lee el canal seleccionado; los valores negativos (diferenciales) se recortan a 0.
+    fn read_raw(&mut self) -> Result<u16, SensorError> {
+        Ok(self.read_channel(self.channel)?.max(0) as u16)
+    }
+
+    fn max_raw(&self) -> u16 {
+        self.model.max_code() as u16
+    }
+
+    /// Fondo de escala de la ganancia actual.
+    fn reference_voltage(&self) -> f32 {
+        self.gain.full_scale()
+    }
+
+    fn read_voltage(&mut self) -> Result<f32, SensorError> {
+        self.read_channel_voltage(self.channel)
+    }
+}
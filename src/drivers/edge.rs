@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rppal::gpio::{Gpio, InputPin, Trigger};
+use crate::core::SensorError;
+
+/// Flanco que genera un evento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    /// Transición LOW → HIGH.
+    Rising,
+    /// Transición HIGH → LOW (habitual con contactos reed y pull-up).
+    Falling,
+    /// Ambas transiciones.
+    Both,
+}
+
+impl From<Edge> for Trigger {
+    fn from(edge: Edge) -> Self {
+        match edge {
+            Edge::Rising => Trigger::RisingEdge,
+            Edge::Falling => Trigger::FallingEdge,
+            Edge::Both => Trigger::Both,
+        }
+    }
+}
+
+/// Fuente de pulsos: cualquier elemento que acumule un contador monótono.
+///
+/// Permite que los sensores de conteo funcionen tanto con [`EdgeCounter`]
+/// como con contadores simulados (`Arc<AtomicU64>`) en pruebas.
+pub trait PulseSource {
+    /// Número total de pulsos contados desde la creación.
+    fn count(&self) -> u64;
+}
+
+impl PulseSource for Arc<AtomicU64> {
+    fn count(&self) -> u64 {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+/// Filtro anti-rebote por software.
+///
+/// Acepta un flanco solo si han pasado al menos `debounce` desde el último flanco
+/// aceptado. Los flancos rechazados no reinician la ventana.
+#[derive(Debug, Clone, Copy)]
+pub struct Debouncer {
+    /// Tiempo mínimo entre flancos válidos.
+    debounce: Duration,
+    /// Instante del último flanco aceptado.
+    last: Option<Instant>,
+}
+
+impl Debouncer {
+    /// Crea un filtro con el tiempo mínimo indicado (`Duration::ZERO` lo desactiva).
+    pub fn new(debounce: Duration) -> Self {
+        Self { debounce, last: None }
+    }
+
+    /// Indica si un flanco ocurrido en `now` debe contarse.
+    pub fn accept(&mut self, now: Instant) -> bool {
+        if self.last.is_none_or(|t| now.saturating_duration_since(t) >= self.debounce) {
+            self.last = Some(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// EdgeCounter: cuenta flancos de un pin GPIO mediante **interrupciones**.
+///
+/// A diferencia de [`GpioDriver`](crate::drivers::gpio::GpioDriver), que lee el nivel bajo
+/// demanda, este driver registra una interrupción asíncrona en el kernel: los pulsos
+/// se cuentan en un hilo aparte aunque el programa esté ocupado o dormido.
+///
+/// Incluye un filtro anti-rebote por software ([`Debouncer`]): se ignoran los flancos
+/// que llegan antes de `debounce` desde el último flanco contado.
+pub struct EdgeCounter {
+    /// Pin de entrada con la interrupción configurada (se libera al destruir el driver).
+    _pin: InputPin,
+
+    /// Número del pin BCM usado.
+    pub pin_number: u8,
+
+    /// Contador compartido con el hilo de interrupciones.
+    count: Arc<AtomicU64>,
+}
+
+impl EdgeCounter {
+    /// Crea un contador de flancos en un pin BCM.
+    ///
+    /// # Parámetros
+    /// - `pin_number`: número del pin BCM.
+    /// - `edge`: flanco a contar.
+    /// - `pull_up`: activa la resistencia pull-up interna (contactos secos, reed switches).
+    /// - `debounce`: tiempo mínimo entre flancos válidos (`Duration::ZERO` lo desactiva).
+    ///
+    /// # Retorno
+    /// - `Ok(Self)` si el pin y la interrupción se configuran correctamente.
+    /// - `Err(SensorError::IoError)` si falla el acceso al GPIO.
+    pub fn new(pin_number: u8, edge: Edge, pull_up: bool, debounce: Duration) -> Result<Self, SensorError> {
+        let gpio = Gpio::new().map_err(|_| SensorError::IoError)?;
+        let pin = gpio.get(pin_number).map_err(|_| SensorError::IoError)?;
+        let mut pin = if pull_up { pin.into_input_pullup() } else { pin.into_input() };
+
+        let count = Arc::new(AtomicU64::new(0));
+        let shared = Arc::clone(&count);
+        let mut debouncer = Debouncer::new(debounce);
+
+        pin.set_async_interrupt(edge.into(), move |_| {
+            if debouncer.accept(Instant::now()) {
+                shared.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+        .map_err(|_| SensorError::IoError)?;
+
+        Ok(Self { _pin: pin, pin_number, count })
+    }
+}
+
+impl PulseSource for EdgeCounter {
+    fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+}
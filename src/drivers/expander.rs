@@ -0,0 +1,106 @@
+use std::sync::{Arc, Mutex};
+
+use embedded_hal::digital::v2::{InputPin, OutputPin};
+use crate::core::SensorError;
+
+/// Expansor de GPIO accesible pin a pin.
+///
+/// Lo implementan [`Mcp23017`](crate::drivers::mcp23017::Mcp23017) y
+/// [`Pcf8574`](crate::drivers::pcf8574::Pcf8574). Sobre él se construye [`ExpanderPin`],
+/// que ofrece la misma abstracción de pin que [`GpioDriver`](crate::drivers::gpio::GpioDriver).
+pub trait GpioExpander {
+    /// Número de pines del expansor.
+    fn pin_count(&self) -> u8;
+
+    /// Configura un pin como salida (`true`) o entrada (`false`).
+    fn set_pin_output(&mut self, pin: u8, output: bool) -> Result<(), SensorError>;
+
+    /// Activa o desactiva el pull-up interno de un pin de entrada.
+    fn set_pin_pull_up(&mut self, pin: u8, enabled: bool) -> Result<(), SensorError>;
+
+    /// Escribe el nivel de un pin de salida.
+    fn write_pin(&mut self, pin: u8, high: bool) -> Result<(), SensorError>;
+
+    /// Lee el nivel de un pin.
+    fn read_pin(&mut self, pin: u8) -> Result<bool, SensorError>;
+}
+
+/// ExpanderPin: pin de un [`GpioExpander`] compartido.
+///
+/// Implementa `InputPin` y `OutputPin` de `embedded-hal`, por lo que puede sustituir a
+/// un `GpioDriver` en los sensores y actuadores genéricos (por ejemplo
+/// [`MhRdSensor::with_pin`](crate::devices::sensors::mhrd::MhRdSensor::with_pin) o un relé).
+/// Igual que `GpioDriver`, escribir un nivel pone el pin en modo salida.
+///
+/// # Ejemplo
+/// ```no_run
+/// use std::sync::{Arc, Mutex};
+/// use embedded_hal::digital::v2::OutputPin;
+/// use lince::drivers::expander::ExpanderPin;
+/// use lince::drivers::mcp23017::{Mcp23017, DEFAULT_ADDRESS};
+///
+/// let expander = Arc::new(Mutex::new(Mcp23017::new(1, DEFAULT_ADDRESS).unwrap()));
+/// let mut rele = ExpanderPin::new(&expander, 8).unwrap();  // GPB0
+/// rele.set_high().unwrap();
+/// ```
+pub struct ExpanderPin<E> {
+    /// Expansor compartido con los demás pines.
+    expander: Arc<Mutex<E>>,
+    /// Número de pin en el expansor.
+    pin: u8,
+}
+
+impl<E: GpioExpander> ExpanderPin<E> {
+    /// Crea el manejador del pin `pin`.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si el pin no existe en el expansor.
+    pub fn new(expander: &Arc<Mutex<E>>, pin: u8) -> Result<Self, SensorError> {
+        if pin >= expander.lock().map_err(|_| SensorError::IoError)?.pin_count() {
+            return Err(SensorError::InitializationError);
+        }
+        Ok(Self { expander: expander.clone(), pin })
+    }
+
+    /// Número de pin en el expansor.
+    pub fn pin(&self) -> u8 {
+        self.pin
+    }
+
+    /// Configura el pin como entrada, con o sin pull-up.
+    pub fn set_input(&mut self, pull_up: bool) -> Result<(), SensorError> {
+        let mut expander = self.expander.lock().map_err(|_| SensorError::IoError)?;
+        expander.set_pin_output(self.pin, false)?;
+        expander.set_pin_pull_up(self.pin, pull_up)
+    }
+
+    fn write(&mut self, high: bool) -> Result<(), SensorError> {
+        let mut expander = self.expander.lock().map_err(|_| SensorError::IoError)?;
+        expander.write_pin(self.pin, high)?;
+        expander.set_pin_output(self.pin, true)
+    }
+}
+
+impl<E: GpioExpander> InputPin for ExpanderPin<E> {
+    type Error = SensorError;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        self.expander.lock().map_err(|_| SensorError::IoError)?.read_pin(self.pin)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+impl<E: GpioExpander> OutputPin for ExpanderPin<E> {
+    type Error = SensorError;
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.write(true)
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.write(false)
+    }
+}
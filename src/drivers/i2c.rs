@@ -0,0 +1,82 @@
+use rppal::i2c::I2c;
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+use crate::core::SensorError;
+
+/// I2cDriver: driver mínimo para un bus **I2C** del sistema.
+///
+/// Proporciona una interfaz segura para acceder a `/dev/i2c-N`, compatible con
+/// `rppal` y con los traits bloqueantes de `embedded-hal` (`Read`, `Write` y `WriteRead`).
+/// Los drivers de sensores I2C son genéricos sobre esos traits, por lo que pueden
+/// usar este driver en la Raspberry Pi o un bus simulado en pruebas.
+pub struct I2cDriver {
+    /// Bus gestionado por RPPAL.
+    i2c: I2c,
+
+    /// Dirección del esclavo seleccionada actualmente (`None` si aún no se seleccionó).
+    current_address: Option<u8>,
+}
+
+impl I2cDriver {
+    /// Abre el bus I2C indicado.
+    ///
+    /// # Parámetros
+    /// - `bus`: número del bus (`1` para `/dev/i2c-1`, el bus por defecto en la Raspberry Pi).
+    ///
+    /// # Retorno
+    /// - `Ok(Self)` si el bus se abre correctamente.
+    /// - `Err(SensorError::IoError)` si el bus no existe o no hay permisos.
+    pub fn new(bus: u8) -> Result<Self, SensorError> {
+        let i2c = I2c::with_bus(bus).map_err(|_| SensorError::IoError)?;
+        Ok(Self { i2c, current_address: None })
+    }
+
+    /// Número del bus I2C gestionado.
+    pub fn bus(&self) -> u8 {
+        self.i2c.bus()
+    }
+
+    /// Selecciona la dirección del esclavo solo si cambió respecto a la anterior.
+    fn select(&mut self, address: u8) -> Result<(), rppal::i2c::Error> {
+        if self.current_address != Some(address) {
+            self.i2c.set_slave_address(address as u16)?;
+            self.current_address = Some(address);
+        }
+        Ok(())
+    }
+}
+
+// --------------------------------------------------------------------
+// Implementación de traits de `embedded-hal`
+// --------------------------------------------------------------------
+
+impl Read for I2cDriver {
+    type Error = rppal::i2c::Error;
+
+    /// Lee `buffer.len()` bytes del esclavo `address`.
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.select(address)?;
+        self.i2c.read(buffer)?;
+        Ok(())
+    }
+}
+
+impl Write for I2cDriver {
+    type Error = rppal::i2c::Error;
+
+    /// Escribe `bytes` en el esclavo `address`.
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.select(address)?;
+        self.i2c.write(bytes)?;
+        Ok(())
+    }
+}
+
+impl WriteRead for I2cDriver {
+    type Error = rppal::i2c::Error;
+
+    /// Escribe `bytes` y lee la respuesta en una única transacción (repeated start).
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.select(address)?;
+        self.i2c.write_read(bytes, buffer)
+    }
+}
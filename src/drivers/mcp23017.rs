@@ -0,0 +1,156 @@
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+use crate::core::SensorError;
+use crate::drivers::expander::GpioExpander;
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto (A0–A2 a GND). Rango 0x20–0x27.
+pub const DEFAULT_ADDRESS: u8 = 0x20;
+
+// Registros con IOCON.BANK = 0: los de los puertos A y B son consecutivos.
+const REG_IODIRA: u8 = 0x00;
+const REG_GPPUA: u8 = 0x0C;
+const REG_GPIOA: u8 = 0x12;
+const REG_OLATA: u8 = 0x14;
+
+/// Mcp23017: expansor de 16 GPIO **Microchip MCP23017** por I2C.
+///
+/// Los pines 0–7 corresponden a GPA0–GPA7 y los 8–15 a GPB0–GPB7. Tras el reset todos
+/// son entradas sin pull-up. Para usar pines sueltos con la abstracción de `GpioDriver`,
+/// compártelo en un `Arc<Mutex<_>>` y crea [`ExpanderPin`](crate::drivers::expander::ExpanderPin)s.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::drivers::mcp23017::{Mcp23017, DEFAULT_ADDRESS};
+///
+/// let mut exp = Mcp23017::new(1, DEFAULT_ADDRESS).unwrap();
+/// exp.set_directions(0x00FF).unwrap();  // Puerto A entradas, puerto B salidas
+/// exp.write_outputs(0x0100).unwrap();   // GPB0 en alto
+/// println!("{:016b}", exp.read_inputs().unwrap());
+/// ```
+pub struct Mcp23017<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el expansor.
+    i2c: I2C,
+    /// Dirección I2C del expansor.
+    address: u8,
+    /// Copia de IODIR (1 = entrada).
+    iodir: u16,
+    /// Copia de GPPU (1 = pull-up activado).
+    gppu: u16,
+    /// Copia de OLAT (niveles de salida).
+    olat: u16,
+}
+
+impl Mcp23017<I2cDriver> {
+    /// Abre el expansor en un bus I2C del sistema.
+    pub fn new(bus: u8, address: u8) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address)
+    }
+}
+
+impl<I2C> Mcp23017<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    /// Crea el expansor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Lee la configuración actual para no alterar pines ya configurados.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el expansor no responde.
+    pub fn with_i2c(i2c: I2C, address: u8) -> Result<Self, SensorError> {
+        let mut exp = Self { i2c, address, iodir: 0xFFFF, gppu: 0, olat: 0 };
+        exp.iodir = exp.read_pair(REG_IODIRA)?;
+        exp.gppu = exp.read_pair(REG_GPPUA)?;
+        exp.olat = exp.read_pair(REG_OLATA)?;
+        Ok(exp)
+    }
+
+    /// Configura la dirección de los 16 pines (bit a 1 = entrada).
+    pub fn set_directions(&mut self, iodir: u16) -> Result<(), SensorError> {
+        self.write_pair(REG_IODIRA, iodir)?;
+        self.iodir = iodir;
+        Ok(())
+    }
+
+    /// Configura los pull-ups de los 16 pines (bit a 1 = activado).
+    pub fn set_pull_ups(&mut self, gppu: u16) -> Result<(), SensorError> {
+        self.write_pair(REG_GPPUA, gppu)?;
+        self.gppu = gppu;
+        Ok(())
+    }
+
+    /// Escribe los niveles de salida de los 16 pines.
+    pub fn write_outputs(&mut self, olat: u16) -> Result<(), SensorError> {
+        self.write_pair(REG_OLATA, olat)?;
+        self.olat = olat;
+        Ok(())
+    }
+
+    /// Lee el nivel de los 16 pines.
+    pub fn read_inputs(&mut self) -> Result<u16, SensorError> {
+        self.read_pair(REG_GPIOA)
+    }
+
+    /// Devuelve el bus I2C, consumiendo el driver.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Lee un registro de 16 bits (puerto A en el byte bajo).
+    fn read_pair(&mut self, reg: u8) -> Result<u16, SensorError> {
+        let mut buf = [0u8; 2];
+        self.i2c
+            .write_read(self.address, &[reg], &mut buf)
+            .map_err(|_| SensorError::IoError)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    fn write_pair(&mut self, reg: u8, value: u16) -> Result<(), SensorError> {
+        let [a, b] = value.to_le_bytes();
+        self.i2c
+            .write(self.address, &[reg, a, b])
+            .map_err(|_| SensorError::IoError)
+    }
+}
+
+/// Copia de `value` con el bit `pin` a `set`.
+fn with_bit(value: u16, pin: u8, set: bool) -> u16 {
+    if set { value | (1 << pin) } else { value & !(1 << pin) }
+}
+
+impl<I2C> GpioExpander for Mcp23017<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    fn pin_count(&self) -> u8 {
+        16
+    }
+
+    fn set_pin_output(&mut self, pin: u8, output: bool) -> Result<(), SensorError> {
+        let iodir = with_bit(self.iodir, pin, !output);
+        if iodir != self.iodir {
+            self.set_directions(iodir)?;
+        }
+        Ok(())
+    }
+
+    fn set_pin_pull_up(&mut self, pin: u8, enabled: bool) -> Result<(), SensorError> {
+        let gppu = with_bit(self.gppu, pin, enabled);
+        if gppu != self.gppu {
+            self.set_pull_ups(gppu)?;
+        }
+        Ok(())
+    }
+
+    fn write_pin(&mut self, pin: u8, high: bool) -> Result<(), SensorError> {
+        let olat = with_bit(self.olat, pin, high);
+        if olat != self.olat {
+            self.write_outputs(olat)?;
+        }
+        Ok(())
+    }
+
+    fn read_pin(&mut self, pin: u8) -> Result<bool, SensorError> {
+        Ok(self.read_inputs()? & (1 << pin) != 0)
+    }
+}
@@ -0,0 +1,88 @@
+use rppal::spi::{Bus, Mode, SlaveSelect, Spi};
+use crate::core::SensorError;
+use crate::drivers::adc::AnalogInput;
+
+/// Frecuencia de reloj SPI segura para el MCP3008 alimentado a 3.3 V.
+const SPI_CLOCK_HZ: u32 = 1_350_000;
+
+/// Mcp3008Driver: canal de un ADC **MCP3008** (10 bits, 8 canales) conectado por SPI.
+///
+/// La Raspberry Pi no tiene entradas analógicas, por lo que las salidas analógicas
+/// de los módulos (por ejemplo el pin AO del MH-RD) se leen a través de este ADC.
+/// Cada instancia representa un único canal en modo *single-ended*.
+pub struct Mcp3008Driver {
+    /// Dispositivo SPI gestionado por RPPAL.
+    spi: Spi,
+
+    /// Canal del ADC (0–7).
+    channel: u8,
+
+    /// Tensión del pin VREF (V).
+    reference_voltage: f32,
+}
+
+impl Mcp3008Driver {
+    /// Crea un nuevo `Mcp3008Driver` para un canal concreto.
+    ///
+    /// # Parámetros
+    /// - `spi_bus`: número del bus SPI (`0` para `/dev/spidev0.*`).
+    /// - `chip_select`: línea CE donde está conectado el MCP3008 (`0` o `1`).
+    /// - `channel`: canal del ADC a leer (0–7).
+    ///
+    /// # Retorno
+    /// - `Ok(Self)` si el bus SPI se abre correctamente.
+    /// - `Err(SensorError::InitializationError)` si algún parámetro es inválido.
+    /// - `Err(SensorError::IoError)` si falla la apertura del bus SPI.
+    pub fn new(spi_bus: u8, chip_select: u8, channel: u8) -> Result<Self, SensorError> {
+        if channel > 7 {
+            return Err(SensorError::InitializationError);
+        }
+
+        let bus = match spi_bus {
+            0 => Bus::Spi0,
+            1 => Bus::Spi1,
+            _ => return Err(SensorError::InitializationError),
+        };
+        let slave = match chip_select {
+            0 => SlaveSelect::Ss0,
+            1 => SlaveSelect::Ss1,
+            2 => SlaveSelect::Ss2,
+            _ => return Err(SensorError::InitializationError),
+        };
+
+        let spi = Spi::new(bus, slave, SPI_CLOCK_HZ, Mode::Mode0)
+            .map_err(|_| SensorError::IoError)?;
+
+        Ok(Self { spi, channel, reference_voltage: 3.3 })
+    }
+
+    /// Cambia la tensión de referencia (pin VREF) usada para convertir a voltios.
+    pub fn set_reference_voltage(&mut self, volts: f32) {
+        self.reference_voltage = volts;
+    }
+}
+
+impl AnalogInput for Mcp3008Driver {
+    /// Realiza una conversión *single-ended* del canal configurado.
+    ///
+    /// La trama enviada es `[start, SGL|canal, 0]`; los 10 bits del resultado
+    /// llegan en los dos últimos bytes de la respuesta.
+    fn read_raw(&mut self) -> Result<u16, SensorError> {
+        let write = [0x01, 0x80 | (self.channel << 4), 0x00];
+        let mut read = [0u8; 3];
+
+        self.spi
+            .transfer(&mut read, &write)
+            .map_err(|_| SensorError::IoError)?;
+
+        Ok((((read[1] & 0x03) as u16) << 8) | read[2] as u16)
+    }
+
+    fn max_raw(&self) -> u16 {
+        1023
+    }
+
+    fn reference_voltage(&self) -> f32 {
+        self.reference_voltage
+    }
+}
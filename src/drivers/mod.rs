@@ -1 +1,14 @@
+pub mod adc;
+pub mod ads1x15;
+pub mod edge;
+pub mod expander;
 pub mod gpio;
+pub mod i2c;
+pub mod mcp23017;
+pub mod mcp3008;
+pub mod modbus;
+pub mod onewire;
+pub mod pcf8574;
+pub mod serial;
+pub mod shared_bus;
+pub mod tca9548a;
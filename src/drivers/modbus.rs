@@ -0,0 +1,347 @@
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::core::SensorError;
+use crate::drivers::serial::{SerialDriver, SerialPort};
+
+/// Función 0x03: lectura de registros de retención.
+pub const READ_HOLDING_REGISTERS: u8 = 0x03;
+/// Función 0x04: lectura de registros de entrada.
+pub const READ_INPUT_REGISTERS: u8 = 0x04;
+/// Función 0x06: escritura de un registro.
+pub const WRITE_SINGLE_REGISTER: u8 = 0x06;
+/// Función 0x10: escritura de varios registros.
+pub const WRITE_MULTIPLE_REGISTERS: u8 = 0x10;
+
+/// Máximo de registros por lectura admitido por el protocolo.
+pub const MAX_READ_REGISTERS: u16 = 125;
+
+/// Puerto TCP estándar de Modbus.
+pub const DEFAULT_TCP_PORT: u16 = 502;
+
+/// Tiempo de espera por defecto de una respuesta.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// CRC-16/MODBUS (polinomio 0xA001 reflejado, valor inicial 0xFFFF).
+///
+/// # Ejemplo
+/// ```
+/// use lince::drivers::modbus::crc16;
+///
+/// // Lectura de CO2 del SenseAir S8: FE 04 00 03 00 01 D5 C5
+/// assert_eq!(crc16(&[0xFE, 0x04, 0x00, 0x03, 0x00, 0x01]).to_le_bytes(), [0xD5, 0xC5]);
+/// ```
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for byte in data {
+        crc ^= *byte as u16;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xA001 } else { crc >> 1 };
+        }
+    }
+    crc
+}
+
+/// Cliente **Modbus** independiente del transporte.
+///
+/// Los transportes ([`ModbusRtuClient`] y [`ModbusTcpClient`]) solo implementan
+/// [`ModbusClient::transact`], que envía una PDU (código de función + datos) a una
+/// unidad y devuelve la PDU de respuesta. Las funciones de registro se construyen encima.
+///
+/// Una respuesta de excepción (función | 0x80) se traduce en `SensorError::DeviceFault`.
+pub trait ModbusClient {
+    /// Envía `pdu` a la unidad `unit` y devuelve la PDU de respuesta.
+    fn transact(&mut self, unit: u8, pdu: &[u8]) -> Result<Vec<u8>, SensorError>;
+
+    /// Lee `count` registros de retención (función 0x03) desde `address`.
+    fn read_holding_registers(&mut self, unit: u8, address: u16, count: u16) -> Result<Vec<u16>, SensorError> {
+        read_registers(self, unit, READ_HOLDING_REGISTERS, address, count)
+    }
+
+    /// Lee `count` registros de entrada (función 0x04) desde `address`.
+    fn read_input_registers(&mut self, unit: u8, address: u16, count: u16) -> Result<Vec<u16>, SensorError> {
+        read_registers(self, unit, READ_INPUT_REGISTERS, address, count)
+    }
+
+    /// Escribe un registro de retención (función 0x06).
+    fn write_single_register(&mut self, unit: u8, address: u16, value: u16) -> Result<(), SensorError> {
+        let [a_hi, a_lo] = address.to_be_bytes();
+        let [v_hi, v_lo] = value.to_be_bytes();
+        let request = [WRITE_SINGLE_REGISTER, a_hi, a_lo, v_hi, v_lo];
+        let response = self.transact(unit, &request)?;
+        // La respuesta es un eco de la petición
+        if response != request {
+            return Err(SensorError::InvalidData);
+        }
+        Ok(())
+    }
+
+    /// Escribe varios registros de retención consecutivos (función 0x10).
+    fn write_multiple_registers(&mut self, unit: u8, address: u16, values: &[u16]) -> Result<(), SensorError> {
+        if values.is_empty() || values.len() > 123 {
+            return Err(SensorError::InvalidData);
+        }
+        let count = values.len() as u16;
+        let mut request = vec![WRITE_MULTIPLE_REGISTERS];
+        request.extend_from_slice(&address.to_be_bytes());
+        request.extend_from_slice(&count.to_be_bytes());
+        request.push((values.len() * 2) as u8);
+        for value in values {
+            request.extend_from_slice(&value.to_be_bytes());
+        }
+        let response = self.transact(unit, &request)?;
+        if response.len() != 5 || response[..5] != request[..5] {
+            return Err(SensorError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+/// Lectura de registros con la función 0x03 o 0x04.
+fn read_registers<C: ModbusClient + ?Sized>(
+    client: &mut C,
+    unit: u8,
+    function: u8,
+    address: u16,
+    count: u16,
+) -> Result<Vec<u16>, SensorError> {
+    if count == 0 || count > MAX_READ_REGISTERS {
+        return Err(SensorError::InvalidData);
+    }
+    let [a_hi, a_lo] = address.to_be_bytes();
+    let [c_hi, c_lo] = count.to_be_bytes();
+    let response = client.transact(unit, &[function, a_hi, a_lo, c_hi, c_lo])?;
+
+    // Respuesta: función, número de bytes, datos
+    if response.len() != 2 + 2 * count as usize || response[1] as usize != 2 * count as usize {
+        return Err(SensorError::InvalidData);
+    }
+    Ok(response[2..]
+        .chunks_exact(2)
+        .map(|w| u16::from_be_bytes([w[0], w[1]]))
+        .collect())
+}
+
+/// Comprueba la función de la respuesta y traduce las excepciones.
+fn check_function(request: &[u8], response: &[u8]) -> Result<(), SensorError> {
+    match response.first() {
+        Some(&f) if f == request[0] => Ok(()),
+        Some(&f) if f == request[0] | 0x80 => {
+            eprintln!(
+                "[MODBUS] Excepción 0x{:02X} en la función 0x{:02X}",
+                response.get(1).copied().unwrap_or(0),
+                request[0]
+            );
+            Err(SensorError::DeviceFault)
+        }
+        _ => Err(SensorError::InvalidData),
+    }
+}
+
+/// ModbusRtuClient: cliente **Modbus RTU** sobre un puerto serie (RS-485 o UART).
+///
+/// Cada trama lleva la dirección de la unidad y un CRC-16. Entre tramas se respeta
+/// el silencio de 3.5 caracteres que exige el protocolo.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::drivers::modbus::{ModbusClient, ModbusRtuClient};
+///
+/// let mut bus = ModbusRtuClient::new("/dev/ttyUSB0", 9600).unwrap();
+/// let regs = bus.read_input_registers(1, 0x0000, 2).unwrap();
+/// ```
+pub struct ModbusRtuClient<S = SerialDriver> {
+    /// Puerto serie del bus.
+    port: S,
+    /// Tiempo máximo de espera de una respuesta.
+    timeout: Duration,
+    /// Silencio mínimo entre tramas.
+    frame_gap: Duration,
+}
+
+impl ModbusRtuClient<SerialDriver> {
+    /// Abre el bus en un puerto serie del sistema (8N1).
+    pub fn new<P: AsRef<Path>>(path: P, baud_rate: u32) -> Result<Self, SensorError> {
+        let mut client = Self::with_port(SerialDriver::new(path, baud_rate)?);
+        client.frame_gap = frame_gap(baud_rate);
+        Ok(client)
+    }
+}
+
+impl<S: SerialPort> ModbusRtuClient<S> {
+    /// Crea el cliente sobre cualquier puerto que implemente [`SerialPort`].
+    pub fn with_port(port: S) -> Self {
+        Self { port, timeout: DEFAULT_TIMEOUT, frame_gap: frame_gap(9600) }
+    }
+
+    /// Cambia el tiempo de espera de las respuestas.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Devuelve el puerto serie, consumiendo el cliente.
+    pub fn release(self) -> S {
+        self.port
+    }
+
+    /// Lee y verifica la respuesta de `unit` a `request`.
+    fn read_response(&mut self, unit: u8, request: &[u8]) -> Result<Vec<u8>, SensorError> {
+        // Cabecera: dirección, función y primer byte de datos
+        let mut frame = vec![0u8; 3];
+        self.port.read_exact_timeout(&mut frame, self.timeout)?;
+
+        let remaining = if frame[1] & 0x80 != 0 {
+            2
+        } else {
+            match request[0] {
+                READ_HOLDING_REGISTERS | READ_INPUT_REGISTERS => frame[2] as usize + 2,
+                _ => 5,
+            }
+        };
+        frame.resize(3 + remaining, 0);
+        self.port.read_exact_timeout(&mut frame[3..], self.timeout)?;
+
+        let (body, crc) = frame.split_at(frame.len() - 2);
+        if crc16(body).to_le_bytes() != crc || body[0] != unit {
+            return Err(SensorError::InvalidData);
+        }
+        Ok(body[1..].to_vec())
+    }
+}
+
+/// Silencio de 3.5 caracteres (11 bits) con un mínimo de 1.75 ms, según la especificación.
+fn frame_gap(baud_rate: u32) -> Duration {
+    if baud_rate > 19200 {
+        Duration::from_micros(1750)
+    } else {
+        Duration::from_micros(38_500_000 / baud_rate as u64)
+    }
+}
+
+impl<S: SerialPort> ModbusClient for ModbusRtuClient<S> {
+    fn transact(&mut self, unit: u8, pdu: &[u8]) -> Result<Vec<u8>, SensorError> {
+        let mut frame = Vec::with_capacity(pdu.len() + 3);
+        frame.push(unit);
+        frame.extend_from_slice(pdu);
+        frame.extend_from_slice(&crc16(&frame).to_le_bytes());
+
+        thread::sleep(self.frame_gap);
+        self.port.clear_input()?;
+        self.port.write_all(&frame)?;
+
+        let response = self.read_response(unit, pdu)?;
+        check_function(pdu, &response)?;
+        Ok(response)
+    }
+}
+
+/// ModbusTcpClient: cliente **Modbus TCP** sobre un socket.
+///
+/// Añade la cabecera MBAP (identificador de transacción, protocolo, longitud y unidad)
+/// y reconecta automáticamente si la conexión se pierde.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::drivers::modbus::{ModbusClient, ModbusTcpClient};
+///
+/// let mut plc = ModbusTcpClient::connect("192.168.1.50:502").unwrap();
+/// let regs = plc.read_holding_registers(1, 100, 4).unwrap();
+/// ```
+pub struct ModbusTcpClient {
+    /// Dirección del servidor.
+    addr: SocketAddr,
+    /// Conexión activa (se reabre tras un error).
+    stream: Option<TcpStream>,
+    /// Tiempo máximo de conexión y de espera de respuesta.
+    timeout: Duration,
+    /// Identificador de la última transacción.
+    transaction: u16,
+}
+
+impl ModbusTcpClient {
+    /// Conecta con un servidor Modbus TCP.
+    ///
+    /// # Retorno
+    /// - `Err(SensorError::InitializationError)` si la dirección no es válida.
+    /// - `Err(SensorError::IoError)` si no se puede establecer la conexión.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> Result<Self, SensorError> {
+        let addr = addr
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+            .ok_or(SensorError::InitializationError)?;
+        let mut client = Self { addr, stream: None, timeout: DEFAULT_TIMEOUT, transaction: 0 };
+        client.stream()?;
+        Ok(client)
+    }
+
+    /// Cambia el tiempo de espera de conexión y respuesta.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+        self.stream = None;
+    }
+
+    /// Devuelve la conexión activa, abriéndola si es necesario.
+    fn stream(&mut self) -> Result<&mut TcpStream, SensorError> {
+        if self.stream.is_none() {
+            let stream = TcpStream::connect_timeout(&self.addr, self.timeout).map_err(|e| {
+                eprintln!("[MODBUS] No se pudo conectar a {}: {}", self.addr, e);
+                SensorError::IoError
+            })?;
+            stream.set_read_timeout(Some(self.timeout)).map_err(|_| SensorError::IoError)?;
+            stream.set_write_timeout(Some(self.timeout)).map_err(|_| SensorError::IoError)?;
+            stream.set_nodelay(true).map_err(|_| SensorError::IoError)?;
+            self.stream = Some(stream);
+        }
+        Ok(self.stream.as_mut().unwrap())
+    }
+
+    fn exchange(&mut self, transaction: u16, unit: u8, pdu: &[u8]) -> Result<Vec<u8>, SensorError> {
+        let mut frame = Vec::with_capacity(pdu.len() + 7);
+        frame.extend_from_slice(&transaction.to_be_bytes());
+        frame.extend_from_slice(&[0, 0]);
+        frame.extend_from_slice(&(pdu.len() as u16 + 1).to_be_bytes());
+        frame.push(unit);
+        frame.extend_from_slice(pdu);
+
+        let stream = self.stream()?;
+        stream.write_all(&frame).map_err(io_error)?;
+
+        let mut header = [0u8; 7];
+        stream.read_exact(&mut header).map_err(io_error)?;
+        let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+        if !(2..=254).contains(&length) {
+            return Err(SensorError::InvalidData);
+        }
+        let mut response = vec![0u8; length - 1];
+        stream.read_exact(&mut response).map_err(io_error)?;
+
+        if header[..2] != transaction.to_be_bytes() || header[2..4] != [0, 0] || header[6] != unit {
+            return Err(SensorError::InvalidData);
+        }
+        Ok(response)
+    }
+}
+
+/// Traduce errores de socket: el vencimiento del tiempo de espera es `Timeout`.
+fn io_error(e: std::io::Error) -> SensorError {
+    match e.kind() {
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => SensorError::Timeout,
+        _ => SensorError::IoError,
+    }
+}
+
+impl ModbusClient for ModbusTcpClient {
+    fn transact(&mut self, unit: u8, pdu: &[u8]) -> Result<Vec<u8>, SensorError> {
+        self.transaction = self.transaction.wrapping_add(1);
+        let response = self.exchange(self.transaction, unit, pdu).inspect_err(|_| {
+            // Tras un error la conexión puede quedar desincronizada: se reabre en la próxima petición
+            self.stream = None;
+        })?;
+        check_function(pdu, &response)?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,395 @@
+use std::fmt;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rppal::gpio::{Level, Mode};
+use crate::core::SensorError;
+use crate::drivers::gpio::GpioDriver;
+
+/// Comando SEARCH ROM.
+pub const SEARCH_ROM: u8 = 0xF0;
+/// Comando ALARM SEARCH: solo responden los dispositivos con alarma activa.
+pub const ALARM_SEARCH: u8 = 0xEC;
+/// Comando READ ROM (solo con un único dispositivo en el bus).
+pub const READ_ROM: u8 = 0x33;
+/// Comando MATCH ROM: selecciona un dispositivo por su ROM.
+pub const MATCH_ROM: u8 = 0x55;
+/// Comando SKIP ROM: se dirige a todos los dispositivos a la vez.
+pub const SKIP_ROM: u8 = 0xCC;
+
+/// CRC-8 de Dallas/Maxim (polinomio x⁸ + x⁵ + x⁴ + 1, reflejado 0x8C).
+///
+/// El CRC de un bloque que incluye su propio CRC al final es `0`.
+///
+/// # Ejemplo
+/// ```
+/// use lince::drivers::onewire::crc8;
+///
+/// // ROM de ejemplo de la nota de aplicación 27 de Maxim
+/// assert_eq!(crc8(&[0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00]), 0xA2);
+/// assert_eq!(crc8(&[0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA2]), 0);
+/// ```
+pub fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for byte in data {
+        let mut b = *byte;
+        for _ in 0..8 {
+            let mix = (crc ^ b) & 1;
+            crc >>= 1;
+            if mix != 0 {
+                crc ^= 0x8C;
+            }
+            b >>= 1;
+        }
+    }
+    crc
+}
+
+/// Código ROM de 64 bits de un dispositivo 1-Wire: familia, número de serie de 48 bits y CRC.
+///
+/// Se representa igual que los identificadores del kernel en `/sys/bus/w1/devices`.
+///
+/// # Ejemplo
+/// ```
+/// use lince::drivers::onewire::RomId;
+///
+/// let rom: RomId = "28-0316a2794aff".parse().unwrap();
+/// assert_eq!(rom.family(), 0x28);
+/// assert_eq!(rom.to_string(), "28-0316a2794aff");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RomId(pub [u8; 8]);
+
+impl RomId {
+    /// Código de familia (0x28 = DS18B20, 0x3A = DS2413...).
+    pub fn family(&self) -> u8 {
+        self.0[0]
+    }
+
+    /// Número de serie de 48 bits.
+    pub fn serial(&self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes[..6].copy_from_slice(&self.0[1..7]);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Indica si el CRC del código es correcto.
+    pub fn is_valid(&self) -> bool {
+        crc8(&self.0) == 0
+    }
+}
+
+impl fmt::Display for RomId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02x}-{:012x}", self.family(), self.serial())
+    }
+}
+
+impl FromStr for RomId {
+    type Err = SensorError;
+
+    /// Interpreta un identificador del kernel (`"ff-ssssssssssss"`) y calcula su CRC.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (family, serial) = s.split_once('-').ok_or(SensorError::InvalidData)?;
+        if family.len() != 2 || serial.len() != 12 {
+            return Err(SensorError::InvalidData);
+        }
+        let family = u8::from_str_radix(family, 16).map_err(|_| SensorError::InvalidData)?;
+        let serial = u64::from_str_radix(serial, 16).map_err(|_| SensorError::InvalidData)?;
+
+        let mut rom = [0u8; 8];
+        rom[0] = family;
+        rom[1..7].copy_from_slice(&serial.to_le_bytes()[..6]);
+        rom[7] = crc8(&rom[..7]);
+        Ok(RomId(rom))
+    }
+}
+
+/// Pin en colector abierto para un bus 1-Wire.
+///
+/// El maestro solo fuerza el nivel bajo; el nivel alto lo aporta la resistencia de
+/// pull-up al soltar la línea, de modo que los esclavos pueden tirar de ella hacia abajo.
+pub trait OpenDrainPin {
+    /// Fuerza la línea a nivel bajo.
+    fn drive_low(&mut self);
+
+    /// Suelta la línea (pasa a alta impedancia).
+    fn release(&mut self);
+
+    /// Lee el nivel actual de la línea.
+    fn is_high(&self) -> bool;
+
+    /// Espera `duration` dentro de una ranura de tiempo.
+    ///
+    /// Por defecto usa `spin_sleep`, con precisión de microsegundos; un pin simulado puede
+    /// sustituirlo por un reloj virtual.
+    fn delay(&mut self, duration: Duration) {
+        spin_sleep::sleep(duration);
+    }
+}
+
+impl OpenDrainPin for GpioDriver {
+    fn drive_low(&mut self) {
+        self.write_level(Level::Low);
+        self.set_mode(Mode::Output);
+    }
+
+    fn release(&mut self) {
+        self.set_mode(Mode::Input);
+    }
+
+    fn is_high(&self) -> bool {
+        self.read_bool()
+    }
+}
+
+/// OneWireBus: maestro **1-Wire** por software sobre un pin GPIO.
+///
+/// Alternativa al overlay `w1-gpio` del kernel cuando no puede cargarse (contenedores,
+/// otras placas). Implementa reset/presencia, lectura y escritura de bits con la
+/// temporización estándar, búsqueda de ROM, selección por ROM y CRC-8.
+///
+/// Requiere una resistencia de pull-up (4.7 kΩ) entre la línea de datos y 3.3 V.
+/// Como la temporización es de microsegundos, una carga alta del sistema puede
+/// corromper algún intercambio; los datos se validan siempre con CRC.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::drivers::onewire::OneWireBus;
+///
+/// let mut bus = OneWireBus::new(4).unwrap();
+/// for rom in bus.search().unwrap() {
+///     println!("{}", rom);  // 28-0316a2794aff
+/// }
+/// ```
+pub struct OneWireBus<P = GpioDriver> {
+    /// Línea de datos.
+    pin: P,
+}
+
+impl OneWireBus<GpioDriver> {
+    /// Crea un bus 1-Wire en el pin BCM indicado.
+    pub fn new(pin: u8) -> Result<Self, SensorError> {
+        Ok(Self::with_pin(GpioDriver::new(pin)?))
+    }
+}
+
+impl<P: OpenDrainPin> OneWireBus<P> {
+    /// Crea el bus sobre cualquier pin en colector abierto.
+    pub fn with_pin(mut pin: P) -> Self {
+        pin.release();
+        Self { pin }
+    }
+
+    /// Devuelve el pin, consumiendo el bus.
+    pub fn release(self) -> P {
+        self.pin
+    }
+
+    /// Pulso de reset y detección de presencia.
+    ///
+    /// # Retorna
+    /// - `Ok(true)` si al menos un dispositivo responde.
+    /// - `Ok(false)` si no hay dispositivos.
+    /// - `Err(SensorError::IoError)` si la línea está en cortocircuito a masa
+    ///   (falta la resistencia de pull-up).
+    pub fn reset(&mut self) -> Result<bool, SensorError> {
+        self.pin.release();
+        let start = Instant::now();
+        while !self.pin.is_high() {
+            if start.elapsed() > Duration::from_micros(500) {
+                eprintln!("[1-WIRE] La línea no sube: revisa la resistencia de pull-up");
+                return Err(SensorError::IoError);
+            }
+            self.pin.delay(Duration::from_micros(5));
+        }
+
+        self.pin.drive_low();
+        self.pin.delay(Duration::from_micros(480));
+        self.pin.release();
+        self.pin.delay(Duration::from_micros(70));
+        let presence = !self.pin.is_high();
+        self.pin.delay(Duration::from_micros(410));
+        Ok(presence)
+    }
+
+    /// Escribe un bit en una ranura de tiempo.
+    pub fn write_bit(&mut self, bit: bool) {
+        self.pin.drive_low();
+        if bit {
+            self.pin.delay(Duration::from_micros(6));
+            self.pin.release();
+            self.pin.delay(Duration::from_micros(64));
+        } else {
+            self.pin.delay(Duration::from_micros(60));
+            self.pin.release();
+            self.pin.delay(Duration::from_micros(10));
+        }
+    }
+
+    /// Lee un bit en una ranura de tiempo.
+    pub fn read_bit(&mut self) -> bool {
+        self.pin.drive_low();
+        self.pin.delay(Duration::from_micros(6));
+        self.pin.release();
+        self.pin.delay(Duration::from_micros(9));
+        let bit = self.pin.is_high();
+        self.pin.delay(Duration::from_micros(55));
+        bit
+    }
+
+    /// Escribe un byte, bit menos significativo primero.
+    pub fn write_byte(&mut self, byte: u8) {
+        for i in 0..8 {
+            self.write_bit(byte & (1 << i) != 0);
+        }
+    }
+
+    /// Lee un byte, bit menos significativo primero.
+    pub fn read_byte(&mut self) -> u8 {
+        (0..8).fold(0, |byte, i| if self.read_bit() { byte | (1 << i) } else { byte })
+    }
+
+    /// Escribe varios bytes.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|b| self.write_byte(*b));
+    }
+
+    /// Llena `buf` con bytes leídos del bus.
+    pub fn read_bytes(&mut self, buf: &mut [u8]) {
+        buf.iter_mut().for_each(|b| *b = self.read_byte());
+    }
+
+    /// Reset seguido de MATCH ROM: los comandos siguientes solo los atiende `rom`.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si ningún dispositivo responde al reset.
+    pub fn select(&mut self, rom: &RomId) -> Result<(), SensorError> {
+        self.reset_with_presence()?;
+        self.write_byte(MATCH_ROM);
+        self.write_bytes(&rom.0);
+        Ok(())
+    }
+
+    /// Reset seguido de SKIP ROM: los comandos siguientes los atienden todos los dispositivos.
+    pub fn skip(&mut self) -> Result<(), SensorError> {
+        self.reset_with_presence()?;
+        self.write_byte(SKIP_ROM);
+        Ok(())
+    }
+
+    /// Lee la ROM del único dispositivo del bus.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InvalidData)` si el CRC falla (p. ej. hay varios dispositivos).
+    pub fn read_rom(&mut self) -> Result<RomId, SensorError> {
+        self.reset_with_presence()?;
+        self.write_byte(READ_ROM);
+        let mut rom = [0u8; 8];
+        self.read_bytes(&mut rom);
+        let rom = RomId(rom);
+        if !rom.is_valid() {
+            return Err(SensorError::InvalidData);
+        }
+        Ok(rom)
+    }
+
+    /// Enumera todos los dispositivos del bus (algoritmo SEARCH ROM).
+    pub fn search(&mut self) -> Result<Vec<RomId>, SensorError> {
+        self.search_with(SEARCH_ROM)
+    }
+
+    /// Enumera los dispositivos de una familia.
+    pub fn search_family(&mut self, family: u8) -> Result<Vec<RomId>, SensorError> {
+        Ok(self.search()?.into_iter().filter(|r| r.family() == family).collect())
+    }
+
+    /// Enumera los dispositivos con una alarma activa (ALARM SEARCH).
+    pub fn alarm_search(&mut self) -> Result<Vec<RomId>, SensorError> {
+        self.search_with(ALARM_SEARCH)
+    }
+
+    /// Espera a que termine una operación lenta (p. ej. una conversión de temperatura),
+    /// leyendo bits hasta que el dispositivo responda con un 1.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si no termina antes de `timeout`.
+    pub fn wait_ready(&mut self, timeout: Duration) -> Result<(), SensorError> {
+        let start = Instant::now();
+        while !self.read_bit() {
+            if start.elapsed() > timeout {
+                return Err(SensorError::Timeout);
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        Ok(())
+    }
+
+    fn reset_with_presence(&mut self) -> Result<(), SensorError> {
+        if !self.reset()? {
+            return Err(SensorError::Timeout);
+        }
+        Ok(())
+    }
+
+    /// Búsqueda binaria del árbol de ROMs (nota de aplicación 187 de Maxim).
+    fn search_with(&mut self, command: u8) -> Result<Vec<RomId>, SensorError> {
+        let mut found = Vec::new();
+        let mut rom = [0u8; 8];
+        // Posición (1–64) de la última bifurcación en la que se tomó el camino 0
+        let mut last_discrepancy = 0;
+
+        loop {
+            if !self.reset()? {
+                return Ok(found);
+            }
+            self.write_byte(command);
+
+            let mut last_zero = 0;
+            for position in 1..=64 {
+                let (byte, mask) = ((position - 1) / 8, 1u8 << ((position - 1) % 8));
+                let id_bit = self.read_bit();
+                let cmp_bit = self.read_bit();
+
+                let direction = match (id_bit, cmp_bit) {
+                    // Ningún dispositivo participa: en ALARM SEARCH es normal
+                    (true, true) if position == 1 => return Ok(found),
+                    (true, true) => return Err(SensorError::InvalidData),
+                    (id, cmp) if id != cmp => id,
+                    // Bifurcación: dispositivos con 0 y con 1 en esta posición
+                    _ => {
+                        let direction = if position < last_discrepancy {
+                            rom[byte] & mask != 0
+                        } else {
+                            position == last_discrepancy
+                        };
+                        if !direction {
+                            last_zero = position;
+                        }
+                        direction
+                    }
+                };
+
+                if direction {
+                    rom[byte] |= mask;
+                } else {
+                    rom[byte] &= !mask;
+                }
+                self.write_bit(direction);
+            }
+
+            let id = RomId(rom);
+            if !id.is_valid() {
+                eprintln!("[1-WIRE] CRC inválido en la ROM {}", id);
+                return Err(SensorError::InvalidData);
+            }
+            found.push(id);
+
+            last_discrepancy = last_zero;
+            if last_discrepancy == 0 {
+                return Ok(found);
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use embedded_hal::blocking::i2c::{Read, Write};
+use crate::core::SensorError;
+use crate::drivers::expander::GpioExpander;
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto del PCF8574 (A0–A2 a GND). Rango 0x20–0x27.
+pub const DEFAULT_ADDRESS: u8 = 0x20;
+
+/// Dirección I2C por defecto del PCF8574A. Rango 0x38–0x3F.
+pub const DEFAULT_ADDRESS_A: u8 = 0x38;
+
+/// Pcf8574: expansor de 8 GPIO **NXP/TI PCF8574** por I2C.
+///
+/// Sus pines son cuasi-bidireccionales: un `1` deja el pin en alto con un pull-up débil
+/// (sirve como entrada) y un `0` lo fuerza a nivel bajo. No tiene registro de dirección,
+/// así que configurar un pin como entrada equivale a escribirle un `1`.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::drivers::pcf8574::{Pcf8574, DEFAULT_ADDRESS};
+///
+/// let mut exp = Pcf8574::new(1, DEFAULT_ADDRESS).unwrap();
+/// exp.write_port(0b1111_1110).unwrap();  // P0 a nivel bajo (relé activo en bajo)
+/// println!("{:08b}", exp.read_port().unwrap());
+/// ```
+pub struct Pcf8574<I2C = I2cDriver> {
+    /// Bus I2C donde está conectado el expansor.
+    i2c: I2C,
+    /// Dirección I2C del expansor.
+    address: u8,
+    /// Último valor escrito en el puerto.
+    latch: u8,
+}
+
+impl Pcf8574<I2cDriver> {
+    /// Abre el expansor en un bus I2C del sistema.
+    pub fn new(bus: u8, address: u8) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address)
+    }
+}
+
+impl<I2C> Pcf8574<I2C>
+where
+    I2C: Write + Read,
+{
+    /// Crea el expansor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Deja todos los pines en alto (estado de reset, válidos como entradas).
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el expansor no responde.
+    pub fn with_i2c(i2c: I2C, address: u8) -> Result<Self, SensorError> {
+        let mut exp = Self { i2c, address, latch: 0xFF };
+        exp.write_port(0xFF)?;
+        Ok(exp)
+    }
+
+    /// Escribe los 8 pines (`1` = alto/entrada, `0` = forzado a bajo).
+    pub fn write_port(&mut self, value: u8) -> Result<(), SensorError> {
+        self.i2c
+            .write(self.address, &[value])
+            .map_err(|_| SensorError::IoError)?;
+        self.latch = value;
+        Ok(())
+    }
+
+    /// Lee el nivel de los 8 pines.
+    pub fn read_port(&mut self) -> Result<u8, SensorError> {
+        let mut buf = [0u8; 1];
+        self.i2c
+            .read(self.address, &mut buf)
+            .map_err(|_| SensorError::IoError)?;
+        Ok(buf[0])
+    }
+
+    /// Devuelve el bus I2C, consumiendo el driver.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    fn set_latch_bit(&mut self, pin: u8, high: bool) -> Result<(), SensorError> {
+        let latch = if high { self.latch | (1 << pin) } else { self.latch & !(1 << pin) };
+        if latch != self.latch {
+            self.write_port(latch)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I2C> GpioExpander for Pcf8574<I2C>
+where
+    I2C: Write + Read,
+{
+    fn pin_count(&self) -> u8 {
+        8
+    }
+
+    /// Un pin de entrada se libera escribiéndole un `1`; como salida conserva su nivel.
+    fn set_pin_output(&mut self, pin: u8, output: bool) -> Result<(), SensorError> {
+        if !output {
+            self.set_latch_bit(pin, true)?;
+        }
+        Ok(())
+    }
+
+    /// El pull-up débil está siempre presente en los pines en alto.
+    fn set_pin_pull_up(&mut self, _pin: u8, _enabled: bool) -> Result<(), SensorError> {
+        Ok(())
+    }
+
+    fn write_pin(&mut self, pin: u8, high: bool) -> Result<(), SensorError> {
+        self.set_latch_bit(pin, high)
+    }
+
+    fn read_pin(&mut self, pin: u8) -> Result<bool, SensorError> {
+        Ok(self.read_port()? & (1 << pin) != 0)
+    }
+}
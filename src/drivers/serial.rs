@@ -0,0 +1,96 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use rppal::uart::{Parity, Queue, Uart};
+use crate::core::SensorError;
+
+/// Puerto serie bloqueante con tiempo de espera.
+///
+/// Los drivers de sensores UART son genéricos sobre este trait, de modo que pueden
+/// usar [`SerialDriver`] en la Raspberry Pi o un puerto simulado en pruebas.
+pub trait SerialPort {
+    /// Envía todos los bytes de `data`.
+    fn write_all(&mut self, data: &[u8]) -> Result<(), SensorError>;
+
+    /// Lee los bytes disponibles en `buf`, esperando como máximo `timeout` al primero.
+    ///
+    /// Devuelve el número de bytes leídos (`0` si venció el tiempo de espera).
+    fn read_timeout(&mut self, buf: &mut [u8], timeout: Duration) -> Result<usize, SensorError>;
+
+    /// Descarta los bytes recibidos pendientes de leer.
+    fn clear_input(&mut self) -> Result<(), SensorError>;
+
+    /// Llena `buf` por completo antes de `timeout`.
+    ///
+    /// # Retorno
+    /// - `Err(SensorError::Timeout)` si no llegan suficientes bytes a tiempo.
+    fn read_exact_timeout(&mut self, buf: &mut [u8], timeout: Duration) -> Result<(), SensorError> {
+        let deadline = Instant::now() + timeout;
+        let mut filled = 0;
+        while filled < buf.len() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(SensorError::Timeout);
+            }
+            filled += self.read_timeout(&mut buf[filled..], remaining)?;
+        }
+        Ok(())
+    }
+}
+
+/// SerialDriver: driver mínimo para un puerto **UART** del sistema.
+///
+/// Abre cualquier dispositivo de caracteres serie (`/dev/serial0`, `/dev/ttyUSB0` o
+/// un pseudo-terminal) en modo crudo 8N1 mediante `rppal`.
+pub struct SerialDriver {
+    /// Puerto gestionado por RPPAL.
+    uart: Uart,
+}
+
+impl SerialDriver {
+    /// Abre un puerto serie en modo 8N1.
+    ///
+    /// # Parámetros
+    /// - `path`: ruta del dispositivo (por ejemplo `"/dev/serial0"`).
+    /// - `baud_rate`: velocidad en baudios.
+    ///
+    /// # Retorno
+    /// - `Ok(Self)` si el puerto se abre y configura correctamente.
+    /// - `Err(SensorError::IoError)` si el dispositivo no existe o no hay permisos.
+    pub fn new<P: AsRef<Path>>(path: P, baud_rate: u32) -> Result<Self, SensorError> {
+        let uart = Uart::with_path(path, baud_rate, Parity::None, 8, 1).map_err(|e| {
+            eprintln!("[SERIE] No se pudo abrir el puerto: {}", e);
+            SensorError::IoError
+        })?;
+        Ok(Self { uart })
+    }
+
+    /// Velocidad actual en baudios.
+    pub fn baud_rate(&self) -> u32 {
+        self.uart.baud_rate()
+    }
+}
+
+impl SerialPort for SerialDriver {
+    fn write_all(&mut self, data: &[u8]) -> Result<(), SensorError> {
+        self.uart.set_write_mode(true).map_err(|_| SensorError::IoError)?;
+        let mut sent = 0;
+        while sent < data.len() {
+            sent += self.uart.write(&data[sent..]).map_err(|_| SensorError::IoError)?;
+        }
+        self.uart.drain().map_err(|_| SensorError::IoError)
+    }
+
+    fn read_timeout(&mut self, buf: &mut [u8], timeout: Duration) -> Result<usize, SensorError> {
+        // VTIME tiene resolución de 0.1 s: se redondea hacia arriba
+        let timeout = Duration::from_millis(timeout.as_millis().div_ceil(100).clamp(1, 255) as u64 * 100);
+        self.uart
+            .set_read_mode(0, timeout)
+            .map_err(|_| SensorError::IoError)?;
+        self.uart.read(buf).map_err(|_| SensorError::IoError)
+    }
+
+    fn clear_input(&mut self) -> Result<(), SensorError> {
+        self.uart.flush(Queue::Input).map_err(|_| SensorError::IoError)
+    }
+}
@@ -0,0 +1,260 @@
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use embedded_hal::blocking::i2c;
+use embedded_hal::blocking::spi;
+use embedded_hal::digital::v2::OutputPin;
+use crate::core::SensorError;
+use crate::drivers::i2c::I2cDriver;
+
+/// Tiempo máximo por defecto para obtener el bus.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Estado compartido: el bus está en `slot` cuando nadie lo usa.
+struct BusState<B> {
+    slot: Mutex<Option<B>>,
+    released: Condvar,
+}
+
+/// SharedBus: gestor de un bus físico (I2C, SPI...) compartido por varios dispositivos.
+///
+/// Reparte [`BusProxy`]s (I2C) o [`SpiProxy`]s (SPI, con su chip select), uno por
+/// dispositivo. Cada driver de sensor es dueño de su proxy
+/// y lo usa como si fuera el bus completo; el gestor garantiza que solo una transacción
+/// accede al bus en cada momento, aunque los sensores se lean desde hilos distintos.
+///
+/// Si el bus no queda libre antes del tiempo de espera del proxy, la transacción falla con
+/// `SensorError::Timeout` en lugar de bloquear el hilo indefinidamente.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::devices::sensors::bh1750::Bh1750Sensor;
+/// use lince::devices::sensors::sht::{ShtModel, ShtSensor};
+/// use lince::drivers::shared_bus::SharedBus;
+///
+/// let bus = SharedBus::i2c(1).unwrap();
+/// let mut luz = Bh1750Sensor::with_i2c(bus.proxy(), 0x23).unwrap();
+/// let mut clima = ShtSensor::with_i2c(bus.proxy(), 0x44, ShtModel::Sht31).unwrap();
+///
+/// // Cada sensor puede moverse a su propio hilo
+/// std::thread::spawn(move || loop { let _ = luz.read_lux(); });
+/// ```
+pub struct SharedBus<B> {
+    /// Bus y condición de liberación, compartidos con los proxies.
+    state: Arc<BusState<B>>,
+    /// Tiempo de espera de los proxies creados con [`SharedBus::proxy`].
+    timeout: Duration,
+}
+
+impl SharedBus<I2cDriver> {
+    /// Abre un bus I2C del sistema para compartirlo.
+    pub fn i2c(bus: u8) -> Result<Self, SensorError> {
+        Ok(Self::new(I2cDriver::new(bus)?))
+    }
+}
+
+impl<B> SharedBus<B> {
+    /// Toma posesión de un bus para compartirlo.
+    pub fn new(bus: B) -> Self {
+        Self {
+            state: Arc::new(BusState { slot: Mutex::new(Some(bus)), released: Condvar::new() }),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Cambia el tiempo de espera de los proxies que se creen a partir de ahora.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Crea un proxy para un dispositivo con el tiempo de espera por defecto.
+    pub fn proxy(&self) -> BusProxy<B> {
+        self.proxy_with_timeout(self.timeout)
+    }
+
+    /// Crea un proxy con un tiempo de espera propio (por ejemplo, más largo para un
+    /// dispositivo que realiza transacciones lentas).
+    pub fn proxy_with_timeout(&self, timeout: Duration) -> BusProxy<B> {
+        BusProxy { state: self.state.clone(), timeout }
+    }
+
+    /// Crea un proxy SPI para el dispositivo cuyo chip select es `cs` (activo en bajo).
+    ///
+    /// El chip select se desactiva al crearlo y solo se activa mientras el proxy tiene el
+    /// bus, de modo que los demás dispositivos del bus ignoran sus transferencias.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si no se puede escribir el pin.
+    pub fn spi_proxy<CS: OutputPin>(&self, mut cs: CS) -> Result<SpiProxy<B, CS>, SensorError> {
+        cs.set_high().map_err(|_| SensorError::IoError)?;
+        Ok(SpiProxy { proxy: self.proxy(), cs })
+    }
+}
+
+/// Bus prestado durante una transacción; se devuelve al soltarlo, incluso si hay un pánico.
+struct BusGuard<'a, B> {
+    state: &'a BusState<B>,
+    bus: Option<B>,
+}
+
+impl<B> Drop for BusGuard<'_, B> {
+    fn drop(&mut self) {
+        if let Ok(mut slot) = self.state.slot.lock() {
+            *slot = self.bus.take();
+        }
+        self.state.released.notify_one();
+    }
+}
+
+/// BusProxy: manejador de un dispositivo sobre un [`SharedBus`].
+///
+/// Implementa los traits bloqueantes de I2C (`Read`, `Write`, `WriteRead`) de
+/// `embedded-hal` cuando el bus subyacente los implementa, de modo que puede pasarse a
+/// cualquier constructor `with_i2c`. En I2C el dispositivo se elige por dirección; para SPI,
+/// donde se elige por chip select, usa [`SpiProxy`]. Cada llamada es una transacción
+/// independiente; para agrupar varias operaciones sin que otro dispositivo se intercale,
+/// usa [`BusProxy::transaction`].
+pub struct BusProxy<B> {
+    /// Estado compartido con el gestor.
+    state: Arc<BusState<B>>,
+    /// Tiempo máximo de espera para obtener el bus.
+    timeout: Duration,
+}
+
+impl<B> Clone for BusProxy<B> {
+    fn clone(&self) -> Self {
+        Self { state: self.state.clone(), timeout: self.timeout }
+    }
+}
+
+impl<B> BusProxy<B> {
+    /// Tiempo máximo de espera para obtener el bus.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Cambia el tiempo máximo de espera para obtener el bus.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Ejecuta `f` con acceso exclusivo al bus.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si el bus no queda libre a tiempo.
+    /// - `Err(SensorError::IoError)` si el bus se perdió por un pánico en otro hilo.
+    pub fn transaction<T>(&mut self, f: impl FnOnce(&mut B) -> T) -> Result<T, SensorError> {
+        let mut guard = self.acquire()?;
+        Ok(f(guard.bus.as_mut().expect("bus prestado")))
+    }
+
+    fn acquire(&self) -> Result<BusGuard<'_, B>, SensorError> {
+        let deadline = Instant::now() + self.timeout;
+        let mut slot = self.state.slot.lock().map_err(|_| SensorError::IoError)?;
+        loop {
+            if let Some(bus) = slot.take() {
+                return Ok(BusGuard { state: &self.state, bus: Some(bus) });
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                eprintln!("[BUS] Bus ocupado durante más de {:?}", self.timeout);
+                return Err(SensorError::Timeout);
+            }
+            slot = self
+                .state
+                .released
+                .wait_timeout(slot, remaining)
+                .map_err(|_| SensorError::IoError)?
+                .0;
+        }
+    }
+}
+
+/// SpiProxy: manejador de un dispositivo SPI sobre un [`SharedBus`].
+///
+/// Cada transacción activa el chip select del dispositivo al obtener el bus y lo desactiva
+/// antes de devolverlo. Implementa `Transfer<u8>` y `Write<u8>` de `embedded-hal`.
+pub struct SpiProxy<B, CS> {
+    /// Acceso exclusivo al bus.
+    proxy: BusProxy<B>,
+    /// Chip select del dispositivo (activo en bajo).
+    cs: CS,
+}
+
+impl<B, CS: OutputPin> SpiProxy<B, CS> {
+    /// Cambia el tiempo máximo de espera para obtener el bus.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.proxy.set_timeout(timeout);
+    }
+
+    /// Ejecuta `f` con acceso exclusivo al bus y el chip select activo.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::Timeout)` si el bus no queda libre a tiempo.
+    /// - `Err(SensorError::IoError)` si no se puede escribir el chip select o el bus se
+    ///   perdió por un pánico en otro hilo.
+    pub fn transaction<T>(&mut self, f: impl FnOnce(&mut B) -> T) -> Result<T, SensorError> {
+        let cs = &mut self.cs;
+        self.proxy.transaction(|bus| {
+            cs.set_low().map_err(|_| SensorError::IoError)?;
+            let result = f(bus);
+            cs.set_high().map_err(|_| SensorError::IoError)?;
+            Ok(result)
+        })?
+    }
+
+    /// Devuelve el pin de chip select, consumiendo el proxy.
+    pub fn release(self) -> CS {
+        self.cs
+    }
+}
+
+// --------------------------------------------------------------------
+// Implementación de traits de `embedded-hal`
+// --------------------------------------------------------------------
+
+impl<B: i2c::Read> i2c::Read for BusProxy<B> {
+    type Error = SensorError;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.transaction(|bus| bus.read(address, buffer))?
+            .map_err(|_| SensorError::IoError)
+    }
+}
+
+impl<B: i2c::Write> i2c::Write for BusProxy<B> {
+    type Error = SensorError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.transaction(|bus| bus.write(address, bytes))?
+            .map_err(|_| SensorError::IoError)
+    }
+}
+
+impl<B: i2c::WriteRead> i2c::WriteRead for BusProxy<B> {
+    type Error = SensorError;
+
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.transaction(|bus| bus.write_read(address, bytes, buffer))?
+            .map_err(|_| SensorError::IoError)
+    }
+}
+
+impl<B: spi::Transfer<u8>, CS: OutputPin> spi::Transfer<u8> for SpiProxy<B, CS> {
+    type Error = SensorError;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.transaction(|bus| bus.transfer(words).map(|_| ()))?
+            .map_err(|_| SensorError::IoError)?;
+        Ok(words)
+    }
+}
+
+impl<B: spi::Write<u8>, CS: OutputPin> spi::Write<u8> for SpiProxy<B, CS> {
+    type Error = SensorError;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.transaction(|bus| bus.write(words))?
+            .map_err(|_| SensorError::IoError)
+    }
+}
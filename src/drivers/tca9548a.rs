@@ -0,0 +1,161 @@
+use std::sync::{Arc, Mutex};
+
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+use crate::core::SensorError;
+use crate::drivers::i2c::I2cDriver;
+
+/// Dirección I2C por defecto (A0–A2 a GND). Rango 0x70–0x77.
+pub const DEFAULT_ADDRESS: u8 = 0x70;
+
+/// Número de canales del multiplexor.
+pub const CHANNELS: u8 = 8;
+
+/// Estado compartido entre los canales del multiplexor.
+struct MuxState<I2C> {
+    /// Bus aguas arriba.
+    i2c: I2C,
+    /// Canal seleccionado actualmente (`None` = todos desconectados o desconocido).
+    selected: Option<u8>,
+}
+
+/// Tca9548a: multiplexor I2C **TI TCA9548A** de 8 canales.
+///
+/// Permite conectar dispositivos con la misma dirección (por ejemplo varios BME280 en
+/// 0x76) en canales distintos. Cada [`MuxChannel`] es un bus I2C transparente que
+/// implementa los traits de `embedded-hal`: antes de cada transacción selecciona su
+/// canal si no estaba ya activo. Los canales pueden usarse desde distintos hilos.
+///
+/// # Ejemplo
+/// ```no_run
+/// use lince::devices::sensors::bme280::{Bme280Config, Bme280Sensor};
+/// use lince::drivers::tca9548a::{Tca9548a, DEFAULT_ADDRESS};
+///
+/// let mux = Tca9548a::new(1, DEFAULT_ADDRESS).unwrap();
+/// let interior = Bme280Sensor::with_i2c(mux.channel(0).unwrap(), 0x76, Bme280Config::default()).unwrap();
+/// let exterior = Bme280Sensor::with_i2c(mux.channel(1).unwrap(), 0x76, Bme280Config::default()).unwrap();
+/// ```
+pub struct Tca9548a<I2C = I2cDriver> {
+    /// Dirección I2C del multiplexor.
+    address: u8,
+    /// Bus y canal activo, compartidos con los canales.
+    state: Arc<Mutex<MuxState<I2C>>>,
+}
+
+impl Tca9548a<I2cDriver> {
+    /// Abre el multiplexor en un bus I2C del sistema.
+    pub fn new(bus: u8, address: u8) -> Result<Self, SensorError> {
+        Self::with_i2c(I2cDriver::new(bus)?, address)
+    }
+}
+
+impl<I2C> Tca9548a<I2C>
+where
+    I2C: Write + WriteRead + Read,
+{
+    /// Crea el multiplexor sobre cualquier bus compatible con `embedded-hal`.
+    ///
+    /// Desconecta todos los canales para partir de un estado conocido.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::IoError)` si el multiplexor no responde.
+    pub fn with_i2c(mut i2c: I2C, address: u8) -> Result<Self, SensorError> {
+        i2c.write(address, &[0x00]).map_err(|_| {
+            eprintln!("[TCA9548A] El multiplexor no responde en 0x{:02X}", address);
+            SensorError::IoError
+        })?;
+        Ok(Self { address, state: Arc::new(Mutex::new(MuxState { i2c, selected: None })) })
+    }
+
+    /// Devuelve un manejador de bus para el canal `channel` (0–7).
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::InitializationError)` si el canal no existe.
+    pub fn channel(&self, channel: u8) -> Result<MuxChannel<I2C>, SensorError> {
+        if channel >= CHANNELS {
+            return Err(SensorError::InitializationError);
+        }
+        Ok(MuxChannel { address: self.address, channel, state: self.state.clone() })
+    }
+
+    /// Desconecta todos los canales.
+    pub fn disable_all(&self) -> Result<(), SensorError> {
+        let mut state = self.state.lock().map_err(|_| SensorError::IoError)?;
+        state.i2c.write(self.address, &[0x00]).map_err(|_| SensorError::IoError)?;
+        state.selected = None;
+        Ok(())
+    }
+}
+
+/// Canal de un [`Tca9548a`] que se comporta como un bus I2C independiente.
+///
+/// Implementa `Read`, `Write` y `WriteRead` de `embedded-hal`, por lo que cualquier
+/// driver I2C del framework puede usarlo con su constructor `with_i2c`.
+pub struct MuxChannel<I2C = I2cDriver> {
+    /// Dirección I2C del multiplexor.
+    address: u8,
+    /// Canal del multiplexor (0–7).
+    channel: u8,
+    /// Estado compartido del multiplexor.
+    state: Arc<Mutex<MuxState<I2C>>>,
+}
+
+impl<I2C> MuxChannel<I2C> {
+    /// Canal del multiplexor.
+    pub fn channel(&self) -> u8 {
+        self.channel
+    }
+}
+
+impl<I2C> MuxChannel<I2C>
+where
+    I2C: Write,
+{
+    /// Bloquea el bus, selecciona el canal si hace falta y ejecuta `f`.
+    fn with_bus<T>(
+        &mut self,
+        f: impl FnOnce(&mut I2C) -> Result<T, SensorError>,
+    ) -> Result<T, SensorError> {
+        let mut state = self.state.lock().map_err(|_| SensorError::IoError)?;
+        if state.selected != Some(self.channel) {
+            state.selected = None;
+            state.i2c.write(self.address, &[1 << self.channel]).map_err(|_| SensorError::IoError)?;
+            state.selected = Some(self.channel);
+        }
+        f(&mut state.i2c)
+    }
+}
+
+impl<I2C> Read for MuxChannel<I2C>
+where
+    I2C: Write + Read,
+{
+    type Error = SensorError;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.with_bus(|i2c| i2c.read(address, buffer).map_err(|_| SensorError::IoError))
+    }
+}
+
+impl<I2C> Write for MuxChannel<I2C>
+where
+    I2C: Write,
+{
+    type Error = SensorError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.with_bus(|i2c| i2c.write(address, bytes).map_err(|_| SensorError::IoError))
+    }
+}
+
+impl<I2C> WriteRead for MuxChannel<I2C>
+where
+    I2C: Write + WriteRead,
+{
+    type Error = SensorError;
+
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.with_bus(|i2c| {
+            i2c.write_read(address, bytes, buffer).map_err(|_| SensorError::IoError)
+        })
+    }
+}
@@ -23,6 +23,7 @@ pub mod drivers;
 
 // Reexportar interfaces clave para una API unificada
 pub use core::traits::{
+    climate::ClimateSensor,
     communicator::Communicator,
     sensor::Sensor,
     storage::Storage,
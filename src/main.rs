@@ -1,33 +1,49 @@
+use std::env;
 use std::thread;
 use std::time::Duration;
 
 use lince::devices::sensors::dht11::Dht11Sensor;
 use lince::devices::sensors::dht22::Dht22Sensor;
+use lince::devices::sensors::simulated::{FailureInjection, SimulatedClimateSensor};
 use lince::storage::memory::MemoryStorage;
-use lince::core::traits::sensor::Sensor;
 use lince::core::traits::storage::Storage;
 use lince::network::mqtt::MqttCommunicator;
 use lince::core::traits::communicator::Communicator;
 use lince::core::SensorOutput;
+use lince::devices::sensors::discovery::BoxedSensor;
 
 fn main() {
+    // `--simular` sustituye el hardware por sensores simulados (sin Raspberry Pi)
+    let simular = env::args().any(|a| a == "--simular");
+
     println!("Iniciando lectura de sensores DHT...");
 
     // Inicializar sensores
-    let mut dht22 = match Dht22Sensor::new(23) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("Error inicializando DHT22: {:?}", e);
-            return;
-        }
-    };
+    let (mut dht22, mut dht11): (BoxedSensor, BoxedSensor) = if simular {
+        println!("Modo simulado: usando sensores virtuales");
+        let fallos = FailureInjection::new(0.1, 0.1);
+        (
+            Box::new(SimulatedClimateSensor::new(24.0, 58.0).with_failures(fallos)),
+            Box::new(SimulatedClimateSensor::new(23.0, 60.0).with_failures(fallos)),
+        )
+    } else {
+        let dht22 = match Dht22Sensor::new(23) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("Error inicializando DHT22: {:?}", e);
+                return;
+            }
+        };
 
-    let mut dht11 = match Dht11Sensor::new(17) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("Error inicializando DHT11: {:?}", e);
-            return;
-        }
+        let dht11 = match Dht11Sensor::new(17) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("Error inicializando DHT11: {:?}", e);
+                return;
+            }
+        };
+
+        (Box::new(dht22), Box::new(dht11))
     };
 
     // Almacenamiento en memoria
@@ -19,6 +19,12 @@ impl ConsoleCommunicator {
     }
 }
 
+impl Default for ConsoleCommunicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Communicator for ConsoleCommunicator {
     /// Envía datos a la consola.
     ///
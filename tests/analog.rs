@@ -0,0 +1,106 @@
+//! Pruebas de `AnalogCalibration` (dos puntos, tabla, entradas no válidas) y de la
+//! palabra de configuración del ADS1x15 sobre un bus simulado.
+
+mod common;
+
+use common::{assert_close, MockI2c};
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::analog::{AnalogCalibration, AnalogSensor};
+use lince::drivers::adc::AnalogInput;
+use lince::drivers::ads1x15::{
+    Ads1x15Channel, Ads1x15Driver, Ads1x15Gain, Ads1x15Mode, Ads1x15Model, DEFAULT_ADDRESS,
+};
+
+const CONVERSION: u8 = 0x00;
+const CONFIG: u8 = 0x01;
+
+fn ads(model: Ads1x15Model, conversion: u16) -> Ads1x15Driver<MockI2c> {
+    let mut i2c = MockI2c::words(DEFAULT_ADDRESS);
+    i2c.set_word(CONVERSION, conversion);
+    Ads1x15Driver::with_i2c(i2c, DEFAULT_ADDRESS, model, Ads1x15Channel::Single(0)).unwrap()
+}
+
+#[test]
+fn two_point_calibration_is_linear_and_extrapolates() {
+    // Transductor de presión 0.5–4.5 V para 0–10 bar
+    let cal = AnalogCalibration::two_point((0.5, 0.0), (4.5, 10.0)).unwrap();
+    assert_eq!(cal, AnalogCalibration::Linear { slope: 2.5, offset: -1.25 });
+    assert_close(cal.apply(2.5), 5.0, 1e-4);
+    assert_close(cal.apply(0.0), -1.25, 1e-4);
+    assert!(cal.apply(f32::NAN).is_nan());
+
+    assert_eq!(AnalogCalibration::two_point((1.0, 0.0), (1.0, 5.0)), None);
+}
+
+#[test]
+fn table_interpolates_between_sorted_points_and_clamps_outside() {
+    let cal = AnalogCalibration::table(vec![(2.8, 0.0), (1.2, 100.0), (2.1, 40.0), (1.5, 80.0)]).unwrap();
+    assert_eq!(
+        cal,
+        AnalogCalibration::Table(vec![(1.2, 100.0), (1.5, 80.0), (2.1, 40.0), (2.8, 0.0)])
+    );
+    assert_close(cal.apply(1.8), 60.0, 1e-4);
+    assert_close(cal.apply(2.45), 20.0, 1e-4);
+    assert_close(cal.apply(2.1), 40.0, 1e-4);
+    assert_close(cal.apply(0.5), 100.0, 1e-4);
+    assert_close(cal.apply(3.3), 0.0, 1e-4);
+
+    assert_eq!(AnalogCalibration::table(vec![(1.0, 0.0)]), None);
+    assert_eq!(AnalogCalibration::table(vec![(1.0, 0.0), (1.0, 5.0)]), None);
+}
+
+#[test]
+fn nan_input_and_short_tables_return_nan() {
+    let cal = AnalogCalibration::table(vec![(0.0, 0.0), (1.0, 10.0)]).unwrap();
+    assert!(cal.apply(f32::NAN).is_nan());
+    assert!(AnalogCalibration::Table(Vec::new()).apply(0.5).is_nan());
+    assert!(AnalogCalibration::Table(vec![(1.0, 5.0)]).apply(0.5).is_nan());
+
+    // El sensor rechaza esas tablas al construirse
+    let adc = ads(Ads1x15Model::Ads1115, 0);
+    let result = AnalogSensor::new(Box::new(adc), AnalogCalibration::Table(vec![(1.0, 5.0)]), "%");
+    assert!(matches!(result, Err(SensorError::InitializationError)));
+}
+
+#[test]
+fn ads1x15_config_word_and_scaling() {
+    // 16384 cuentas con ±4.096 V = 16384 / 32768 · 4.096 = 2.048 V
+    let mut adc = ads(Ads1x15Model::Ads1115, 16384);
+    assert_close(adc.read_voltage().unwrap(), 2.048, 1e-6);
+    // OS | AIN0 | ±4.096 V | single-shot | 128 SPS | comparador desactivado
+    assert_eq!(adc.release().written_words(CONFIG), vec![0xC383]);
+
+    let mut adc = ads(Ads1x15Model::Ads1115, (-8192i16) as u16);
+    adc.set_gain(Ads1x15Gain::Two).unwrap();
+    adc.set_data_rate(860).unwrap();
+    assert_eq!(adc.set_data_rate(100), Err(SensorError::InitializationError));
+    assert_close(adc.read_channel_voltage(Ads1x15Channel::Diff0_1).unwrap(), -0.512, 1e-4);
+    // Las lecturas negativas se recortan a 0 en AnalogInput
+    assert_eq!(adc.read_raw(), Ok(0));
+    assert_eq!(adc.release().written_words(CONFIG), vec![0x85E3, 0xC5E3]);
+
+    // ADS1015: resultado de 12 bits alineado a la izquierda; modo continuo sin OS ni MODE
+    let mut adc = ads(Ads1x15Model::Ads1015, 0x7FF0);
+    adc.set_mode(Ads1x15Mode::Continuous).unwrap();
+    assert_eq!(adc.read_channel(Ads1x15Channel::Single(3)), Ok(2047));
+    assert_eq!(adc.read_channel(Ads1x15Channel::Single(3)), Ok(2047));
+    // El código máximo queda un LSB por debajo del fondo de escala: 2047 / 2048 · 4.096 V
+    assert_close(adc.read_channel_voltage(Ads1x15Channel::Single(3)).unwrap(), 4.094, 1e-6);
+    assert_eq!(adc.release().written_words(CONFIG), vec![0x7283]);
+
+    let i2c = MockI2c::words(DEFAULT_ADDRESS);
+    let result = Ads1x15Driver::with_i2c(i2c, DEFAULT_ADDRESS, Ads1x15Model::Ads1115, Ads1x15Channel::Single(4));
+    assert!(matches!(result, Err(SensorError::InitializationError)));
+}
+
+#[test]
+fn analog_sensor_applies_the_calibration_to_the_adc_voltage() {
+    let cal = AnalogCalibration::table(vec![(2.8, 0.0), (1.2, 100.0)]).unwrap();
+    // 2.0 V con ±4.096 V: mitad de la tabla
+    let mut sensor = AnalogSensor::new(Box::new(ads(Ads1x15Model::Ads1115, 16000)), cal, "%").unwrap();
+    sensor.set_samples(3);
+    let Ok(SensorOutput::Float(value)) = sensor.read() else { panic!("lectura inesperada") };
+    assert!((value - 50.0).abs() < 0.01, "{}", value);
+    assert_eq!(sensor.unit(), "%");
+}
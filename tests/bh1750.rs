@@ -0,0 +1,112 @@
+//! Pruebas del BH1750 con un sensor simulado: conversión a lux y auto-ajuste de rango
+//! subiendo (saturación) y bajando (pocas cuentas) de escalón.
+
+use embedded_hal::blocking::i2c::{Read, Write};
+use lince::core::traits::sensor::Sensor;
+use lince::core::SensorOutput;
+use lince::devices::sensors::bh1750::{
+    raw_to_lux, Bh1750Resolution, Bh1750Sensor, DEFAULT_ADDRESS, MAX_MEASUREMENT_TIME,
+    MIN_MEASUREMENT_TIME,
+};
+
+/// BH1750 simulado: mide una iluminancia fija según la resolución y el MTreg
+/// programados, saturando en 65535 cuentas como el chip real.
+struct FakeBh1750 {
+    lux: f32,
+    measurement_time: u8,
+    resolution: Bh1750Resolution,
+    /// Cuentas devueltas en cada medición, en orden.
+    readings: Vec<u16>,
+}
+
+impl FakeBh1750 {
+    fn new(lux: f32) -> Self {
+        Self { lux, measurement_time: 69, resolution: Bh1750Resolution::High, readings: Vec::new() }
+    }
+}
+
+impl Write for FakeBh1750 {
+    type Error = ();
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), ()> {
+        assert_eq!(address, DEFAULT_ADDRESS);
+        match bytes[0] {
+            b @ 0x40..=0x47 => self.measurement_time = (self.measurement_time & 0x1F) | (b & 0x07) << 5,
+            b @ 0x60..=0x7F => self.measurement_time = (self.measurement_time & 0xE0) | (b & 0x1F),
+            0x20 => self.resolution = Bh1750Resolution::High,
+            0x21 => self.resolution = Bh1750Resolution::High2,
+            0x23 => self.resolution = Bh1750Resolution::Low,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Read for FakeBh1750 {
+    type Error = ();
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), ()> {
+        assert_eq!(address, DEFAULT_ADDRESS);
+        let mut counts = self.lux * 1.2 * self.measurement_time as f32 / 69.0;
+        if self.resolution == Bh1750Resolution::High2 {
+            counts *= 2.0;
+        }
+        let counts = counts.round().min(65535.0) as u16;
+        self.readings.push(counts);
+        buffer.copy_from_slice(&counts.to_be_bytes());
+        Ok(())
+    }
+}
+
+#[test]
+fn counts_convert_to_lux_for_each_resolution_and_mtreg() {
+    assert!((raw_to_lux(1200, Bh1750Resolution::High, 69) - 1000.0).abs() < 1e-3);
+    assert!((raw_to_lux(1200, Bh1750Resolution::Low, 69) - 1000.0).abs() < 1e-3);
+    assert!((raw_to_lux(1200, Bh1750Resolution::High2, 69) - 500.0).abs() < 1e-3);
+    assert!((raw_to_lux(1200, Bh1750Resolution::High, 138) - 500.0).abs() < 1e-3);
+
+    let mut sensor = Bh1750Sensor::with_i2c(FakeBh1750::new(250.0), DEFAULT_ADDRESS).unwrap();
+    assert!((sensor.read_lux().unwrap() - 250.0).abs() < 1e-3);
+    // Sin auto-ajuste una lectura saturada no cambia de escalón
+    let mut sensor = Bh1750Sensor::with_i2c(FakeBh1750::new(100_000.0), DEFAULT_ADDRESS).unwrap();
+    assert!((sensor.read_lux().unwrap() - 54_612.5).abs() < 0.5);
+    assert_eq!(sensor.release().readings, vec![65535]);
+}
+
+#[test]
+fn auto_range_steps_down_in_bright_light() {
+    let mut sensor = Bh1750Sensor::with_i2c(FakeBh1750::new(50_000.0), DEFAULT_ADDRESS).unwrap();
+    sensor.set_measurement_time(MAX_MEASUREMENT_TIME).unwrap();
+    sensor.set_resolution(Bh1750Resolution::High2);
+    sensor.set_auto_range(true);
+
+    let lux = sensor.read_lux().unwrap();
+    // High2/254 y High/69 saturan (≥ 60000 cuentas); High/31 mide 46 522 cuentas
+    assert_eq!(sensor.resolution(), Bh1750Resolution::High);
+    assert_eq!(sensor.measurement_time(), MIN_MEASUREMENT_TIME);
+    assert!((lux - 50_000.0).abs() < 2.0, "{}", lux);
+    assert_eq!(sensor.release().readings, vec![65535, 60000, 26957]);
+}
+
+#[test]
+fn auto_range_steps_up_in_dim_light_and_stops_at_the_last_step() {
+    let mut sensor = Bh1750Sensor::with_i2c(FakeBh1750::new(5.0), DEFAULT_ADDRESS).unwrap();
+    sensor.set_auto_range(true);
+
+    let lux = sensor.read_lux().unwrap();
+    assert_eq!(sensor.resolution(), Bh1750Resolution::High2);
+    assert_eq!(sensor.measurement_time(), MAX_MEASUREMENT_TIME);
+    assert!((lux - 5.0).abs() < 0.1, "{}", lux);
+    // Con pocas cuentas en el escalón más sensible no se sigue ajustando
+    assert_eq!(sensor.release().readings, vec![6, 44]);
+}
+
+#[test]
+fn auto_range_keeps_the_step_when_counts_are_in_range() {
+    let mut sensor = Bh1750Sensor::with_i2c(FakeBh1750::new(2_000.0), DEFAULT_ADDRESS).unwrap();
+    sensor.set_auto_range(true);
+    let Ok(SensorOutput::Float(lux)) = sensor.read() else { panic!("lectura inesperada") };
+    assert!((lux - 2_000.0).abs() < 1e-2);
+    assert_eq!(sensor.measurement_time(), 69);
+    assert_eq!(sensor.release().readings, vec![2400]);
+}
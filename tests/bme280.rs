@@ -0,0 +1,125 @@
+mod common;
+
+use common::MockI2c;
+use lince::core::traits::climate::ClimateSensor;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::bme280::{
+    Bme280Config, Bme280Mode, Bme280Sensor, Bme280Variant, Filter, Oversampling, Standby,
+    DEFAULT_ADDRESS,
+};
+
+// Ejemplo de calibración y lecturas del datasheet del BMP280 (sección 3.12).
+const DATASHEET_T: [i32; 3] = [27504, 26435, -1000];
+const DATASHEET_P: [i32; 9] = [36477, -10685, 3024, 2855, 140, -7, 15500, -14600, 6000];
+const ADC_T: u32 = 519888;
+const ADC_P: u32 = 415148;
+
+/// Registros de calibración 0x88..0xA1 a partir de los coeficientes.
+fn calib_block1(h1: u8) -> [u8; 26] {
+    let mut block = [0u8; 26];
+    for (i, v) in DATASHEET_T.iter().chain(DATASHEET_P.iter()).enumerate() {
+        block[i * 2..i * 2 + 2].copy_from_slice(&(*v as u16).to_le_bytes());
+    }
+    block[25] = h1;
+    block
+}
+
+/// Valor de 20 bits en el formato msb/lsb/xlsb de los registros de datos.
+fn adc20(v: u32) -> [u8; 3] {
+    [(v >> 12) as u8, (v >> 4) as u8, ((v & 0x0F) << 4) as u8]
+}
+
+fn mock(chip_id: u8) -> MockI2c {
+    let mut i2c = MockI2c::new(DEFAULT_ADDRESS);
+    i2c.set(0xD0, &[chip_id]);
+    i2c.set(0x88, &calib_block1(75));
+    // dig_H2 = 362, dig_H3 = 0, dig_H4 = 313, dig_H5 = 50, dig_H6 = 30
+    i2c.set(0xE1, &[0x6A, 0x01, 0x00, 0x13, 0x29, 0x03, 0x1E]);
+    i2c.set(0xF7, &adc20(ADC_P));
+    i2c.set(0xFA, &adc20(ADC_T));
+    i2c.set(0xFD, &30000u16.to_be_bytes());
+    i2c
+}
+
+#[test]
+fn bmp280_matches_datasheet_compensation() {
+    let mut sensor = Bme280Sensor::with_i2c(mock(0x58), DEFAULT_ADDRESS, Bme280Config::default()).unwrap();
+    assert_eq!(sensor.variant(), Bme280Variant::Bmp280);
+
+    let m = sensor.read_measurement().unwrap();
+    assert!((m.temperature - 25.08).abs() < 0.01, "temperatura {}", m.temperature);
+    assert!((m.pressure - 1006.5327).abs() < 0.01, "presión {}", m.pressure);
+    assert_eq!(m.humidity, None);
+
+    assert_eq!(
+        sensor.read().unwrap(),
+        SensorOutput::Text("Temp: 25.1°C, Pres: 1006.5 hPa".to_string())
+    );
+    assert_eq!(sensor.read_climate(), Err(SensorError::InvalidData));
+}
+
+#[test]
+fn bme280_decodes_humidity_calibration() {
+    let mut sensor = Bme280Sensor::with_i2c(mock(0x60), DEFAULT_ADDRESS, Bme280Config::default()).unwrap();
+    assert_eq!(sensor.variant(), Bme280Variant::Bme280);
+
+    let calib = *sensor.calibration();
+    assert_eq!((calib.dig_h1, calib.dig_h2, calib.dig_h3), (75, 362, 0));
+    assert_eq!((calib.dig_h4, calib.dig_h5, calib.dig_h6), (313, 50, 30));
+
+    let reading = sensor.read_climate().unwrap();
+    assert!((reading.temperature - 25.08).abs() < 0.01);
+    assert!((reading.humidity - 55.0).abs() < 0.01, "humedad {}", reading.humidity);
+}
+
+#[test]
+fn unknown_chip_id_is_rejected() {
+    let result = Bme280Sensor::with_i2c(mock(0x42), DEFAULT_ADDRESS, Bme280Config::default());
+    assert!(matches!(result, Err(SensorError::InitializationError)));
+}
+
+#[test]
+fn wrong_address_is_io_error() {
+    let result = Bme280Sensor::with_i2c(mock(0x60), 0x77, Bme280Config::default());
+    assert!(matches!(result, Err(SensorError::IoError)));
+}
+
+#[test]
+fn init_resets_and_writes_configuration() {
+    let config = Bme280Config {
+        temperature_oversampling: Oversampling::X2,
+        pressure_oversampling: Oversampling::X16,
+        humidity_oversampling: Oversampling::X4,
+        filter: Filter::X16,
+        standby: Standby::Ms62_5,
+        mode: Bme280Mode::Normal,
+    };
+    let sensor = Bme280Sensor::with_i2c(mock(0x60), DEFAULT_ADDRESS, config).unwrap();
+    let i2c = sensor.release();
+
+    assert_eq!(i2c.written(0xE0), vec![0xB6]);
+    assert_eq!(i2c.written(0xF5), vec![(0b001 << 5) | (0b100 << 2)]);
+    assert_eq!(i2c.written(0xF2), vec![0b011]);
+    // Primero sleep (para escribir config) y luego normal
+    assert_eq!(i2c.written(0xF4), vec![(0b010 << 5) | (0b101 << 2), (0b010 << 5) | (0b101 << 2) | 0b11]);
+}
+
+#[test]
+fn forced_mode_triggers_one_conversion_per_read() {
+    let mut sensor = Bme280Sensor::with_i2c(mock(0x60), DEFAULT_ADDRESS, Bme280Config::default()).unwrap();
+    sensor.read_measurement().unwrap();
+    sensor.read_measurement().unwrap();
+
+    let i2c = sensor.release();
+    let forced = i2c.written(0xF4).into_iter().filter(|v| v & 0b11 == 0b01).count();
+    assert_eq!(forced, 2);
+}
+
+#[test]
+fn stuck_measurement_times_out() {
+    let mut i2c = mock(0x60);
+    i2c.set(0xF3, &[0x08]);
+    let mut sensor = Bme280Sensor::with_i2c(i2c, DEFAULT_ADDRESS, Bme280Config::default()).unwrap();
+    assert_eq!(sensor.read_measurement(), Err(SensorError::Timeout));
+}
@@ -0,0 +1,652 @@
+//! Utilidades compartidas por las pruebas de integración.
+
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+use embedded_hal::digital::v2::{InputPin, OutputPin};
+use lince::core::SensorError;
+use lince::drivers::onewire::OpenDrainPin;
+use lince::drivers::serial::SerialPort;
+
+/// Comprueba que `actual` está a menos de `tolerance` de `expected`.
+#[track_caller]
+pub fn assert_close(actual: impl Into<f64>, expected: impl Into<f64>, tolerance: f64) {
+    let (actual, expected) = (actual.into(), expected.into());
+    assert!((actual - expected).abs() <= tolerance, "{} != {} (±{})", actual, expected, tolerance);
+}
+
+/// Directorio temporal único (`lince-<nombre>-<pid>`) para montar árboles `/sys` o
+/// `/proc` falsos; se borra al soltarlo, aunque la prueba falle.
+pub struct TempRoot(PathBuf);
+
+impl TempRoot {
+    /// Crea el directorio vacío, descartando restos de una ejecución anterior.
+    pub fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("lince-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Self(root)
+    }
+
+    /// Crea el directorio de un dispositivo 1-Wire del kernel y devuelve su ruta.
+    pub fn w1_device(&self, id: &str) -> PathBuf {
+        let dir = self.0.join("sys/bus/w1/devices").join(id);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+}
+
+impl Deref for TempRoot {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempRoot {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempRoot {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Error del bus simulado (equivale a un NACK).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MockI2cError;
+
+/// Bus I2C simulado con un **mapa de registros** de 256 bytes.
+///
+/// - `write(addr, [reg, v0, v1, ...])` escribe `v0, v1, ...` a partir de `reg`.
+/// - `write_read(addr, [reg], buf)` lee `buf.len()` registros a partir de `reg`.
+/// - `read(addr, buf)` continúa desde el último registro direccionado, como el puntero
+///   de registro de un chip real.
+///
+/// Con [`MockI2c::words`] el mapa es de 256 registros de **16 bits** (big-endian en el
+/// bus), como en los INA219/INA226: cada par de bytes corresponde a un registro.
+///
+/// Con [`MockI2c::port`] el dispositivo no tiene registros, como un PCF8574 o el registro
+/// de control de un TCA9548A.
+///
+/// Las transacciones a otra dirección responden con error (NACK).
+/// Todas las escrituras quedan registradas en `writes` para poder verificarlas.
+pub struct MockI2c {
+    pub address: u8,
+    pub registers: [u8; 256],
+    pub words: [u16; 256],
+    pub word_registers: bool,
+    pub single_register: bool,
+    pub writes: Vec<Vec<u8>>,
+    pointer: usize,
+}
+
+impl MockI2c {
+    pub fn new(address: u8) -> Self {
+        Self {
+            address,
+            registers: [0; 256],
+            words: [0; 256],
+            word_registers: false,
+            single_register: false,
+            writes: Vec::new(),
+            pointer: 0,
+        }
+    }
+
+    /// Dispositivo con un único registro y sin puntero: cada byte escrito, incluido el
+    /// de `write_read`, pasa a ser su valor (y queda registrado) y cada byte leído lo
+    /// devuelve.
+    pub fn port(address: u8, value: u8) -> Self {
+        let mut i2c = Self { single_register: true, ..Self::new(address) };
+        i2c.registers[0] = value;
+        i2c
+    }
+
+    /// Dispositivo con registros de 16 bits.
+    pub fn words(address: u8) -> Self {
+        Self { word_registers: true, ..Self::new(address) }
+    }
+
+    /// Fija el valor de un registro de 16 bits.
+    pub fn set_word(&mut self, register: u8, value: u16) {
+        self.words[register as usize] = value;
+    }
+
+    /// Valores de 16 bits escritos en `register` mediante transacciones `[reg, hi, lo]`.
+    pub fn written_words(&self, register: u8) -> Vec<u16> {
+        self.writes
+            .iter()
+            .filter(|w| w.len() >= 3 && w[0] == register)
+            .map(|w| u16::from_be_bytes([w[1], w[2]]))
+            .collect()
+    }
+
+    /// Copia `values` en el mapa de registros a partir de `start`.
+    pub fn set(&mut self, start: u8, values: &[u8]) {
+        let start = start as usize;
+        self.registers[start..start + values.len()].copy_from_slice(values);
+    }
+
+    /// Últimos valores escritos en `register` mediante transacciones `[reg, valor]`.
+    pub fn written(&self, register: u8) -> Vec<u8> {
+        self.writes
+            .iter()
+            .filter(|w| w.len() >= 2 && w[0] == register)
+            .map(|w| w[1])
+            .collect()
+    }
+
+    fn check(&self, address: u8) -> Result<(), MockI2cError> {
+        if address == self.address { Ok(()) } else { Err(MockI2cError) }
+    }
+}
+
+impl Write for MockI2c {
+    type Error = MockI2cError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check(address)?;
+        self.writes.push(bytes.to_vec());
+        if self.single_register {
+            if let Some(&value) = bytes.last() {
+                self.registers[0] = value;
+            }
+            return Ok(());
+        }
+        if let Some((&reg, values)) = bytes.split_first() {
+            self.pointer = reg as usize;
+            if self.word_registers {
+                for (i, w) in values.chunks_exact(2).enumerate() {
+                    self.words[(reg as usize + i) & 0xFF] = u16::from_be_bytes([w[0], w[1]]);
+                }
+            } else {
+                for (i, v) in values.iter().enumerate() {
+                    self.registers[(reg as usize + i) & 0xFF] = *v;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WriteRead for MockI2c {
+    type Error = MockI2cError;
+
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if self.single_register {
+            self.write(address, bytes)?;
+            return self.read(address, buffer);
+        }
+        self.check(address)?;
+        let reg = *bytes.first().ok_or(MockI2cError)? as usize;
+        self.pointer = reg;
+        self.read(address, buffer)
+    }
+}
+
+impl Read for MockI2c {
+    type Error = MockI2cError;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.check(address)?;
+        if self.single_register {
+            buffer.fill(self.registers[0]);
+            return Ok(());
+        }
+        let reg = self.pointer;
+        if self.word_registers {
+            self.pointer = (reg + buffer.len().div_ceil(2)) & 0xFF;
+            for (i, w) in buffer.chunks_mut(2).enumerate() {
+                let value = self.words[(reg + i) & 0xFF].to_be_bytes();
+                w.copy_from_slice(&value[..w.len()]);
+            }
+            return Ok(());
+        }
+        self.pointer = (reg + buffer.len()) & 0xFF;
+        for (i, b) in buffer.iter_mut().enumerate() {
+            *b = self.registers[(reg + i) & 0xFF];
+        }
+        Ok(())
+    }
+}
+
+/// Bus I2C simulado con varios [`MockI2c`], uno por dirección.
+///
+/// Las direcciones sin dispositivo responden con error (NACK).
+#[derive(Default)]
+pub struct MockBus {
+    pub devices: HashMap<u8, MockI2c>,
+}
+
+impl MockBus {
+    /// Añade un dispositivo en su dirección.
+    pub fn with(mut self, device: MockI2c) -> Self {
+        self.devices.insert(device.address, device);
+        self
+    }
+
+    fn device(&mut self, address: u8) -> Result<&mut MockI2c, MockI2cError> {
+        self.devices.get_mut(&address).ok_or(MockI2cError)
+    }
+}
+
+impl Write for MockBus {
+    type Error = MockI2cError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.device(address)?.write(address, bytes)
+    }
+}
+
+impl WriteRead for MockBus {
+    type Error = MockI2cError;
+
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.device(address)?.write_read(address, bytes, buffer)
+    }
+}
+
+impl Read for MockBus {
+    type Error = MockI2cError;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.device(address)?.read(address, buffer)
+    }
+}
+
+/// Waveform: forma de onda digital descrita como una secuencia de segmentos.
+///
+/// Cada segmento es un nivel (`true` = HIGH) mantenido durante una duración.
+/// Al terminar el último segmento, el pin vuelve al nivel de reposo (`idle`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waveform {
+    /// Segmentos `(nivel, duración)` en orden.
+    pub segments: Vec<(bool, Duration)>,
+    /// Nivel antes de empezar y después de terminar la forma de onda.
+    pub idle: bool,
+}
+
+impl Waveform {
+    /// Crea una forma de onda vacía con el nivel de reposo indicado.
+    pub fn new(idle: bool) -> Self {
+        Self { segments: Vec::new(), idle }
+    }
+
+    /// Añade un segmento de nivel `level` durante `duration`.
+    pub fn then(mut self, level: bool, duration: Duration) -> Self {
+        self.segments.push((level, duration));
+        self
+    }
+
+    /// Añade un pulso en alto: `delay` en reposo seguido de `width` en HIGH.
+    pub fn pulse(self, delay: Duration, width: Duration) -> Self {
+        let idle = self.idle;
+        self.then(idle, delay).then(!idle, width)
+    }
+
+    /// Nivel de la forma de onda transcurrido `elapsed` desde su inicio.
+    pub fn level_at(&self, elapsed: Duration) -> bool {
+        let mut t = Duration::ZERO;
+        for (level, duration) in &self.segments {
+            t += *duration;
+            if elapsed < t {
+                return *level;
+            }
+        }
+        self.idle
+    }
+}
+
+/// Estado compartido de un [`MockPin`].
+struct MockPinState {
+    /// Nivel actual cuando no se reproduce ninguna forma de onda.
+    level: bool,
+    /// Forma de onda en reproducción y su instante de inicio.
+    playing: Option<(Waveform, Instant)>,
+    /// Historial de niveles escritos como salida.
+    writes: Vec<bool>,
+    /// Pin y forma de onda que se disparan en cada flanco de bajada de este pin.
+    on_falling_edge: Option<(MockPin, Waveform)>,
+}
+
+/// MockPin: pin GPIO simulado para pruebas sin hardware.
+///
+/// Implementa `InputPin` y `OutputPin` de `embedded-hal`, por lo que puede sustituir a
+/// `GpioDriver` en cualquier driver genérico.
+/// Como entrada puede reproducir una [`Waveform`] con temporización real; como salida
+/// registra los niveles escritos y puede disparar una forma de onda en otro pin
+/// (por ejemplo, el eco de un HC-SR04 tras el pulso de disparo).
+///
+/// Los clones comparten el mismo estado, de modo que la prueba conserva un
+/// manejador para inspeccionar el pin después de entregarlo al driver.
+#[derive(Clone)]
+pub struct MockPin {
+    state: Arc<Mutex<MockPinState>>,
+}
+
+impl MockPin {
+    /// Crea un pin simulado con el nivel inicial indicado.
+    pub fn new(level: bool) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockPinState {
+                level,
+                playing: None,
+                writes: Vec::new(),
+                on_falling_edge: None,
+            })),
+        }
+    }
+
+    /// Empieza a reproducir una forma de onda desde este instante.
+    pub fn play(&self, waveform: Waveform) {
+        let mut state = self.state.lock().unwrap();
+        state.level = waveform.idle;
+        state.playing = Some((waveform, Instant::now()));
+    }
+
+    /// Reproduce `waveform` en `target` cada vez que este pin pasa de HIGH a LOW.
+    pub fn on_falling_edge(&self, target: &MockPin, waveform: Waveform) {
+        self.state.lock().unwrap().on_falling_edge = Some((target.clone(), waveform));
+    }
+
+    /// Niveles escritos en el pin como salida, en orden.
+    pub fn writes(&self) -> Vec<bool> {
+        self.state.lock().unwrap().writes.clone()
+    }
+
+    /// Nivel actual del pin.
+    pub fn level(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        match &state.playing {
+            Some((waveform, start)) => waveform.level_at(start.elapsed()),
+            None => state.level,
+        }
+    }
+
+    fn write(&self, level: bool) {
+        let trigger = {
+            let mut state = self.state.lock().unwrap();
+            let previous = state.level;
+            state.level = level;
+            state.playing = None;
+            state.writes.push(level);
+            if previous && !level { state.on_falling_edge.clone() } else { None }
+        };
+        if let Some((target, waveform)) = trigger {
+            target.play(waveform);
+        }
+    }
+}
+
+impl InputPin for MockPin {
+    type Error = core::convert::Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.level())
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(!self.level())
+    }
+}
+
+impl OutputPin for MockPin {
+    type Error = core::convert::Infallible;
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.write(true);
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.write(false);
+        Ok(())
+    }
+}
+
+/// Respuesta de un [`FakeSerial`] a los bytes escritos.
+pub type Responder = Box<dyn FnMut(&[u8]) -> Vec<u8>>;
+
+/// Puerto serie simulado que responde a cada escritura.
+///
+/// Cada bloque escrito con `write_all` queda registrado en `written` y se entrega a
+/// `respond`; los bytes que devuelve quedan pendientes de lectura. Sin bytes
+/// pendientes, `read_timeout` espera el tiempo indicado y devuelve `0`.
+pub struct FakeSerial {
+    pub written: Vec<Vec<u8>>,
+    pending: VecDeque<u8>,
+    respond: Responder,
+}
+
+impl FakeSerial {
+    pub fn new(respond: impl FnMut(&[u8]) -> Vec<u8> + 'static) -> Self {
+        Self { written: Vec::new(), pending: VecDeque::new(), respond: Box::new(respond) }
+    }
+}
+
+impl SerialPort for FakeSerial {
+    fn write_all(&mut self, data: &[u8]) -> Result<(), SensorError> {
+        self.written.push(data.to_vec());
+        let response = (self.respond)(data);
+        self.pending.extend(response);
+        Ok(())
+    }
+
+    fn read_timeout(&mut self, buf: &mut [u8], timeout: Duration) -> Result<usize, SensorError> {
+        if self.pending.is_empty() {
+            std::thread::sleep(timeout);
+            return Ok(0);
+        }
+        let n = buf.len().min(self.pending.len());
+        for (b, v) in buf.iter_mut().zip(self.pending.drain(..n)) {
+            *b = v;
+        }
+        Ok(n)
+    }
+
+    fn clear_input(&mut self) -> Result<(), SensorError> {
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+/// Dispositivo 1-Wire simulado: ROM de 64 bits y scratchpad de 9 bytes.
+#[derive(Clone)]
+pub struct OneWireSlave {
+    pub rom: [u8; 8],
+    pub scratchpad: [u8; 9],
+}
+
+/// Fase del protocolo en la que se encuentran los esclavos simulados.
+enum OneWirePhase {
+    RomCommand,
+    Search { position: usize, step: u8 },
+    Match { position: usize },
+    ReadRom { position: usize },
+    Function,
+    Converting,
+    Scratchpad { position: usize },
+    Idle,
+}
+
+struct OneWireState {
+    slaves: Vec<OneWireSlave>,
+    /// Esclavos que siguen atendiendo tras SEARCH ROM / MATCH ROM.
+    active: Vec<bool>,
+    phase: OneWirePhase,
+    /// Bits del byte de comando recibidos hasta ahora.
+    command: Vec<bool>,
+    /// Reloj virtual: avanza con [`OpenDrainPin::delay`].
+    now: Duration,
+    /// Instante (en el reloj virtual) en el que el maestro forzó la línea a nivel bajo.
+    low_since: Option<Duration>,
+    /// Pulso de presencia pendiente de leer tras un reset.
+    presence: bool,
+    /// Ranura corta aún sin clasificar: lectura si el maestro muestrea, escritura de un 1 si no.
+    short_slot: bool,
+}
+
+/// Línea 1-Wire con **esclavos simulados** que responden a reset, SEARCH ROM, MATCH ROM,
+/// SKIP ROM, READ ROM, CONVERT T (`0x44`) y READ SCRATCHPAD (`0xBE`).
+///
+/// Los retardos del maestro avanzan un reloj virtual, de modo que la simulación no depende
+/// del planificador. Las ranuras se clasifican por la duración del nivel bajo en ese reloj:
+/// más de 300 µs es un reset, más de 30 µs la escritura de un 0 y, si es más corta, una
+/// lectura cuando el maestro muestrea la línea o la escritura de un 1 en caso contrario.
+/// Como en el bus real, la línea queda en bajo si cualquiera de los esclavos activos
+/// responde con un 0.
+pub struct OneWireSim {
+    state: RefCell<OneWireState>,
+}
+
+impl OneWireSim {
+    pub fn new(slaves: Vec<OneWireSlave>) -> Self {
+        let active = vec![false; slaves.len()];
+        Self {
+            state: RefCell::new(OneWireState {
+                slaves,
+                active,
+                phase: OneWirePhase::Idle,
+                command: Vec::new(),
+                now: Duration::ZERO,
+                low_since: None,
+                presence: false,
+                short_slot: false,
+            }),
+        }
+    }
+}
+
+impl OneWireState {
+    /// Nivel resultante de que cada esclavo activo envíe `bit(esclavo)`.
+    fn wired_and(&self, bit: impl Fn(&OneWireSlave) -> bool) -> bool {
+        self.slaves.iter().zip(&self.active).filter(|(_, active)| **active).all(|(slave, _)| bit(slave))
+    }
+
+    fn reset(&mut self) {
+        self.active = vec![true; self.slaves.len()];
+        self.phase = OneWirePhase::RomCommand;
+        self.command.clear();
+        self.presence = true;
+    }
+
+    /// Recibe un byte de comando bit a bit; devuelve el byte al completarse.
+    fn command_bit(&mut self, bit: bool) -> Option<u8> {
+        self.command.push(bit);
+        if self.command.len() < 8 {
+            return None;
+        }
+        let byte = self.command.iter().rev().fold(0, |byte, b| (byte << 1) | *b as u8);
+        self.command.clear();
+        Some(byte)
+    }
+
+    fn write(&mut self, bit: bool) {
+        match self.phase {
+            OneWirePhase::RomCommand => {
+                self.phase = match self.command_bit(bit) {
+                    None => return,
+                    Some(0xF0) => OneWirePhase::Search { position: 0, step: 0 },
+                    Some(0x55) => OneWirePhase::Match { position: 0 },
+                    Some(0x33) => OneWirePhase::ReadRom { position: 0 },
+                    Some(0xCC) => OneWirePhase::Function,
+                    Some(_) => OneWirePhase::Idle,
+                }
+            }
+            OneWirePhase::Search { position, step: 2 } | OneWirePhase::Match { position } => {
+                for (slave, active) in self.slaves.iter().zip(self.active.iter_mut()) {
+                    *active &= rom_bit(&slave.rom, position) == bit;
+                }
+                self.phase = match self.phase {
+                    _ if position == 63 => OneWirePhase::Function,
+                    OneWirePhase::Match { .. } => OneWirePhase::Match { position: position + 1 },
+                    _ => OneWirePhase::Search { position: position + 1, step: 0 },
+                };
+            }
+            OneWirePhase::Function => {
+                self.phase = match self.command_bit(bit) {
+                    None => return,
+                    Some(0x44) => OneWirePhase::Converting,
+                    Some(0xBE) => OneWirePhase::Scratchpad { position: 0 },
+                    Some(_) => OneWirePhase::Idle,
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn read(&mut self) -> bool {
+        match self.phase {
+            OneWirePhase::Search { position, step } if step < 2 => {
+                self.phase = OneWirePhase::Search { position, step: step + 1 };
+                self.wired_and(|slave| rom_bit(&slave.rom, position) == (step == 0))
+            }
+            OneWirePhase::ReadRom { position } if position < 64 => {
+                self.phase = OneWirePhase::ReadRom { position: position + 1 };
+                self.wired_and(|slave| rom_bit(&slave.rom, position))
+            }
+            OneWirePhase::Scratchpad { position } if position < 72 => {
+                self.phase = OneWirePhase::Scratchpad { position: position + 1 };
+                self.wired_and(|slave| slave.scratchpad[position / 8] & (1 << (position % 8)) != 0)
+            }
+            // Conversión terminada o esclavos en silencio: la línea queda en alto
+            _ => true,
+        }
+    }
+}
+
+fn rom_bit(rom: &[u8; 8], position: usize) -> bool {
+    rom[position / 8] & (1 << (position % 8)) != 0
+}
+
+impl OpenDrainPin for OneWireSim {
+    fn drive_low(&mut self) {
+        let state = self.state.get_mut();
+        if std::mem::take(&mut state.short_slot) {
+            state.write(true);
+        }
+        state.low_since = Some(state.now);
+    }
+
+    fn release(&mut self) {
+        let state = self.state.get_mut();
+        let now = state.now;
+        match state.low_since.take() {
+            Some(since) if now - since >= Duration::from_micros(300) => state.reset(),
+            Some(since) if now - since >= Duration::from_micros(30) => state.write(false),
+            Some(_) => state.short_slot = true,
+            None if std::mem::take(&mut state.short_slot) => state.write(true),
+            None => {}
+        }
+    }
+
+    fn is_high(&self) -> bool {
+        let mut state = self.state.borrow_mut();
+        if state.low_since.is_some() {
+            false
+        } else if std::mem::take(&mut state.presence) {
+            state.slaves.is_empty()
+        } else if std::mem::take(&mut state.short_slot) {
+            state.read()
+        } else {
+            true
+        }
+    }
+
+    fn delay(&mut self, duration: Duration) {
+        self.state.get_mut().now += duration;
+    }
+}
@@ -0,0 +1,181 @@
+//! Pruebas del descubrimiento de sensores: exploración de un bus I2C simulado con
+//! varios chips, construcción de los INA detectados y enumeración de árboles `/sys`
+//! falsos para 1-Wire e IIO.
+
+mod common;
+
+use std::fs;
+
+use common::{MockBus, MockI2c, TempRoot};
+use embedded_hal::blocking::i2c::WriteRead;
+use lince::core::SensorOutput;
+use lince::devices::sensors::bme280::Bme280Variant;
+use lince::devices::sensors::discovery::{discover_i2c, discover_iio, discover_w1, scan_i2c, SensorSpec};
+use lince::devices::sensors::ina::InaModel;
+use lince::drivers::shared_bus::SharedBus;
+
+fn device(address: u8, registers: &[(u8, &[u8])]) -> MockI2c {
+    let mut i2c = MockI2c::new(address);
+    for (reg, values) in registers {
+        i2c.set(*reg, values);
+    }
+    i2c
+}
+
+/// BH1750 simulado: no tiene puntero de registro y cada lectura devuelve la última medida.
+fn bh1750(address: u8, raw: u16) -> MockI2c {
+    let mut i2c = MockI2c::new(address);
+    for reg in (0..=254).step_by(2) {
+        i2c.set(reg, &raw.to_be_bytes());
+    }
+    i2c
+}
+
+#[test]
+fn i2c_scan_identifies_known_chips_by_id_registers() {
+    let mut bus = MockBus::default()
+        .with(bh1750(0x23, 0x0190))
+        .with(device(0x39, &[(0x8A, &[0x50])]))        // TSL2561, ID 0x5_
+        .with(device(0x41, &[(0x00, &[0x39, 0x9F])]))  // INA219, configuración de reset
+        .with(device(0x68, &[]))                       // chip desconocido
+        .with(device(0x76, &[(0xD0, &[0x60])]));       // BME280
+
+    assert_eq!(scan_i2c(&mut bus), vec![0x23, 0x39, 0x41, 0x68, 0x76]);
+
+    let specs = discover_i2c(&mut bus, 1);
+    assert_eq!(
+        specs,
+        vec![
+            SensorSpec::Bh1750 { bus: 1, address: 0x23 },
+            SensorSpec::Tsl2561 { bus: 1, address: 0x39 },
+            SensorSpec::Ina { bus: 1, address: 0x41, model: InaModel::Ina219 },
+            SensorSpec::Bme280 { bus: 1, address: 0x76, variant: Bme280Variant::Bme280 },
+        ]
+    );
+    assert_eq!(specs[2].to_string(), "INA219 (i2c-1, 0x41)");
+    assert_eq!(specs[0].to_string(), "BH1750? (i2c-1, 0x23)");
+}
+
+#[test]
+fn expanders_at_0x23_are_not_bh1750_and_are_left_untouched() {
+    // PCF8574 con las salidas en alto (relés activos en bajo apagados)
+    let mut bus = MockBus::default().with(MockI2c::port(0x23, 0xFF));
+    assert_eq!(discover_i2c(&mut bus, 1), vec![]);
+    assert!(bus.devices[&0x23].writes.is_empty());
+    assert_eq!(bus.devices[&0x23].registers[0], 0xFF);
+
+    // MCP23017 recién reiniciado: IODIRA/IODIRB en 0xFF
+    let mut bus = MockBus::default().with(device(0x23, &[(0x00, &[0xFF, 0xFF])]));
+    assert_eq!(discover_i2c(&mut bus, 1), vec![]);
+
+    // MCP23017 en uso, con el puntero en GPIO y salidas distintas en OLATA/OLATB
+    let mut mcp23017 = device(0x23, &[(0x00, &[0xFF, 0xFF]), (0x14, &[0x0F, 0xF0])]);
+    mcp23017.write_read(0x23, &[0x12], &mut [0u8; 2]).unwrap();
+    let mut bus = MockBus::default().with(mcp23017);
+    assert_eq!(discover_i2c(&mut bus, 1), vec![]);
+    assert!(bus.devices[&0x23].writes.is_empty());
+}
+
+#[test]
+fn bmp280_is_told_apart_and_specs_build_on_a_given_bus() {
+    let mut bus = MockBus::default()
+        .with(device(0x77, &[(0xD0, &[0x58])]))
+        .with(bh1750(0x23, 0x0190));
+    let specs = discover_i2c(&mut bus, 1);
+    assert_eq!(specs[1], SensorSpec::Bme280 { bus: 1, address: 0x77, variant: Bme280Variant::Bmp280 });
+
+    let mut luz = specs[0].build_with_i2c(bus).unwrap();
+    // 400 cuentas / 1.2 en alta resolución
+    assert_eq!(luz.read().unwrap(), SensorOutput::Float(400.0 / 1.2));
+}
+
+#[test]
+fn mux_at_0x76_is_not_bme280_and_keeps_its_channels() {
+    // TCA9548A con los canales 0 y 2 activos
+    let mut bus = MockBus::default().with(MockI2c::port(0x76, 0x05)).with(device(0x77, &[(0xD0, &[0x60])]));
+    assert_eq!(
+        discover_i2c(&mut bus, 1),
+        vec![SensorSpec::Bme280 { bus: 1, address: 0x77, variant: Bme280Variant::Bme280 }]
+    );
+    assert_eq!(bus.devices[&0x76].registers[0], 0x05);
+    assert_eq!(bus.devices[&0x76].writes, vec![vec![0xD0], vec![0x05]]);
+}
+
+#[test]
+fn ads1115_is_not_mistaken_for_ina219() {
+    // Puntero de 2 bits: cada registro se repite cada 4 direcciones
+    let mut ads1115 = MockI2c::words(0x48);
+    for reg in (0..=0xFF).step_by(4) {
+        for (offset, value) in [0x1234, 0x8583, 0x8000, 0x7FFF].into_iter().enumerate() {
+            ads1115.set_word(reg + offset as u8, value);
+        }
+    }
+    let mut bus = MockBus::default().with(ads1115);
+    assert_eq!(discover_i2c(&mut bus, 1), vec![]);
+    assert!(bus.devices[&0x48].writes.is_empty());
+}
+
+#[test]
+fn discovered_ina219_and_ina226_build_with_default_calibration() {
+    let mut ina219 = MockI2c::words(0x40);
+    ina219.set_word(0x00, 0x399F);
+    let mut ina226 = MockI2c::words(0x41);
+    ina226.set_word(0xFE, 0x5449);
+    ina226.set_word(0xFF, 0x2260);
+    let mut bus = MockBus::default().with(ina219).with(ina226);
+
+    let specs = discover_i2c(&mut bus, 1);
+    assert_eq!(
+        specs,
+        vec![
+            SensorSpec::Ina { bus: 1, address: 0x40, model: InaModel::Ina219 },
+            SensorSpec::Ina { bus: 1, address: 0x41, model: InaModel::Ina226 },
+        ]
+    );
+
+    let bus = SharedBus::new(bus);
+    for spec in &specs {
+        let mut sensor = spec.build_with_i2c(bus.proxy()).unwrap();
+        assert!(sensor.read().is_ok(), "{}", spec);
+    }
+
+    // Cada chip recibió un registro de calibración
+    let calibrations = bus
+        .proxy()
+        .transaction(|b| [0x40, 0x41].map(|address| b.devices[&address].written_words(0x05)))
+        .unwrap();
+    assert!(calibrations.iter().all(|writes| writes.len() == 1 && writes[0] != 0));
+}
+
+#[test]
+fn w1_devices_are_mapped_by_family_code() {
+    let root = TempRoot::new("discovery-w1");
+    let devices = root.join("sys/bus/w1/devices");
+    for id in ["3a-0000001c5d7e", "28-0316a2794aff", "w1_bus_master1", "01-000012345678"] {
+        fs::create_dir_all(devices.join(id)).unwrap();
+    }
+
+    let specs = discover_w1(&root);
+    assert_eq!(
+        specs,
+        vec![
+            SensorSpec::Ds18b20 { device_id: "28-0316a2794aff".into() },
+            SensorSpec::Ds2413 { device_id: "3a-0000001c5d7e".into() },
+        ]
+    );
+    assert_eq!(specs[0].to_string(), "DS18B20 (28-0316a2794aff)");
+}
+
+#[test]
+fn iio_channels_become_ready_sensors() {
+    let root = TempRoot::new("discovery-iio");
+    let dev = root.join("sys/bus/iio/devices/iio:device0");
+    fs::create_dir_all(&dev).unwrap();
+    fs::write(dev.join("name"), "dht11\n").unwrap();
+    fs::write(dev.join("in_temp_input"), "21500\n").unwrap();
+
+    let specs = discover_iio(&root);
+    assert_eq!(specs.len(), 1);
+    let mut sensor = specs[0].build().unwrap();
+    assert_eq!(sensor.read().unwrap(), SensorOutput::Float(21.5));
+}
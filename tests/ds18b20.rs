@@ -0,0 +1,119 @@
+//! Pruebas del DS18B20 sobre el formato `w1_slave` del kernel: CRC, firmas de fallo
+//! y reintentos con un árbol `/sys/bus/w1/devices` falso; y sobre el bus 1-Wire por
+//! software con esclavos simulados.
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use common::{OneWireSim, OneWireSlave, TempRoot};
+use lince::devices::sensors::ds18b20::{Ds18b20Fault, Ds18b20Sensor, FAMILY_CODE};
+use lince::drivers::onewire::{crc8, OneWireBus, RomId};
+
+const ID: &str = "28-0316a2794aff";
+
+/// Contenido de `w1_slave` con el CRC indicado y la temperatura en milésimas de grado.
+fn w1_slave(crc_ok: bool, millis: i32) -> String {
+    format!(
+        "50 05 4b 46 7f ff 0c 10 1c : crc=1c {}\n50 05 4b 46 7f ff 0c 10 1c t={}\n",
+        if crc_ok { "YES" } else { "NO" },
+        millis
+    )
+}
+
+/// Crea el archivo `w1_slave` de un DS18B20 en una raíz temporal única.
+fn fake_device(test: &str, contents: &str) -> (TempRoot, PathBuf) {
+    let root = TempRoot::new(&format!("ds18b20-{}", test));
+    let file = root.w1_device(ID).join("w1_slave");
+    fs::write(&file, contents).unwrap();
+    (root, file)
+}
+
+#[test]
+fn parse_w1_slave_checks_crc_and_fault_signatures() {
+    assert_eq!(Ds18b20Sensor::parse_w1_slave(&w1_slave(true, 21_500)), Ok(21.5));
+    assert_eq!(Ds18b20Sensor::parse_w1_slave(&w1_slave(true, -10_125)), Ok(-10.125));
+    assert_eq!(Ds18b20Sensor::parse_w1_slave(&w1_slave(false, 21_500)), Err(SensorError::InvalidData));
+
+    // Firmas de fallo: reset, ceros y bus flotante (0x07FF / 16 = 127.9375 °C)
+    for millis in [85_000, 0, 127_937] {
+        assert_eq!(Ds18b20Sensor::parse_w1_slave(&w1_slave(true, millis)), Err(SensorError::DeviceFault));
+    }
+    assert_eq!(Ds18b20Fault::from_raw(85_000), Some(Ds18b20Fault::PowerOnReset));
+    assert_eq!(Ds18b20Fault::from_raw(0), Some(Ds18b20Fault::Zero));
+    assert_eq!(Ds18b20Fault::from_raw(127_937), Some(Ds18b20Fault::Disconnected));
+    assert_eq!(Ds18b20Fault::from_raw(85_062), None);
+
+    // Fuera de rango físico y sin marcador
+    assert_eq!(Ds18b20Sensor::parse_w1_slave(&w1_slave(true, 130_000)), Err(SensorError::InvalidData));
+    assert_eq!(Ds18b20Sensor::parse_w1_slave("crc=1c YES\n"), Err(SensorError::InvalidData));
+}
+
+#[test]
+fn persistent_fault_fails_after_retries() {
+    let (root, _) = fake_device("fault", &w1_slave(true, 85_000));
+
+    let mut sensor = Ds18b20Sensor::with_root(&root, ID, 0).unwrap();
+    assert_eq!(sensor.read(), Err(SensorError::DeviceFault));
+
+    // Dos reintentos con 200 ms de pausa entre ellos
+    sensor.set_max_retries(2);
+    let start = Instant::now();
+    assert_eq!(sensor.read(), Err(SensorError::DeviceFault));
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[test]
+fn retry_recovers_once_conversion_completes() {
+    let (root, file) = fake_device("retry", &w1_slave(true, 85_000));
+    let mut sensor = Ds18b20Sensor::with_root(&root, ID, 3).unwrap();
+
+    // La conversión real llega entre el primer intento y el primer reintento
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        fs::write(file, w1_slave(true, 22_500)).unwrap();
+    });
+    assert_eq!(sensor.read(), Ok(SensorOutput::Text("22.50 °C".into())));
+    writer.join().unwrap();
+}
+
+/// DS18B20 simulado con la temperatura cruda indicada (1/16 °C) y un CRC correcto.
+fn bus_slave(id: &str, raw: i16) -> OneWireSlave {
+    let rom: RomId = id.parse().unwrap();
+    let [lsb, msb] = raw.to_le_bytes();
+    let mut scratchpad = [lsb, msb, 0x4B, 0x46, 0x7F, 0xFF, 0x0F, 0x10, 0];
+    scratchpad[8] = crc8(&scratchpad[..8]);
+    OneWireSlave { rom: rom.0, scratchpad }
+}
+
+#[test]
+fn bus_sensors_read_their_own_scratchpad() {
+    let slaves = vec![bus_slave(ID, 0x0191), bus_slave("28-0316a2794a00", -401)];
+    let bus = Arc::new(Mutex::new(OneWireBus::with_pin(OneWireSim::new(slaves))));
+
+    let mut roms = bus.lock().unwrap().search_family(FAMILY_CODE).unwrap();
+    roms.sort();
+    let mut sensors: Vec<_> = roms.into_iter().map(|rom| Ds18b20Sensor::on_bus(bus.clone(), rom).unwrap()).collect();
+    assert_eq!(sensors[0].read(), Ok(SensorOutput::Text("-25.06 °C".into())));
+    assert_eq!(sensors[1].read(), Ok(SensorOutput::Text("25.06 °C".into())));
+
+    // Solo se aceptan ROMs de la familia del DS18B20
+    let other: RomId = "10-000801b6a2c4".parse().unwrap();
+    assert!(Ds18b20Sensor::on_bus(bus, other).is_err());
+}
+
+#[test]
+fn bus_scratchpad_with_bad_crc_is_invalid() {
+    let mut slave = bus_slave(ID, 0x0191);
+    slave.scratchpad[8] ^= 0xFF;
+    let bus = Arc::new(Mutex::new(OneWireBus::with_pin(OneWireSim::new(vec![slave]))));
+
+    let mut sensor = Ds18b20Sensor::on_bus(bus, ID.parse().unwrap()).unwrap();
+    assert_eq!(sensor.read(), Err(SensorError::InvalidData));
+}
@@ -0,0 +1,159 @@
+//! Pruebas del multiplexor TCA9548A y de los expansores MCP23017/PCF8574 con buses
+//! I2C simulados, incluido un `MhRdSensor` sobre un pin de expansor.
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use common::{MockI2c, MockI2cError};
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+use embedded_hal::digital::v2::OutputPin;
+use lince::core::traits::sensor::Sensor;
+use lince::core::SensorOutput;
+use lince::devices::sensors::mhrd::MhRdSensor;
+use lince::drivers::expander::ExpanderPin;
+use lince::drivers::mcp23017::{Mcp23017, DEFAULT_ADDRESS};
+use lince::drivers::pcf8574::Pcf8574;
+use lince::drivers::tca9548a::{Tca9548a, DEFAULT_ADDRESS as MUX_ADDRESS};
+
+/// Bus aguas arriba de un TCA9548A con un dispositivo simulado por canal.
+#[derive(Clone, Default)]
+struct MuxBus {
+    inner: Arc<Mutex<MuxBusState>>,
+}
+
+#[derive(Default)]
+struct MuxBusState {
+    /// Máscara de canales activos escrita en el multiplexor.
+    selected: u8,
+    /// Escrituras recibidas por el multiplexor.
+    selections: Vec<u8>,
+    /// Dispositivos por canal.
+    devices: HashMap<u8, MockI2c>,
+}
+
+impl MuxBus {
+    fn add(&self, channel: u8, device: MockI2c) {
+        self.inner.lock().unwrap().devices.insert(channel, device);
+    }
+
+    fn register(&self, channel: u8, reg: u8) -> u8 {
+        self.inner.lock().unwrap().devices[&channel].registers[reg as usize]
+    }
+
+    fn set(&self, channel: u8, reg: u8, values: &[u8]) {
+        self.inner.lock().unwrap().devices.get_mut(&channel).unwrap().set(reg, values);
+    }
+
+    fn selections(&self) -> Vec<u8> {
+        self.inner.lock().unwrap().selections.clone()
+    }
+
+    /// Ejecuta `f` sobre el dispositivo del canal activo.
+    fn with_device<T>(
+        &mut self,
+        f: impl FnOnce(&mut MockI2c) -> Result<T, MockI2cError>,
+    ) -> Result<T, MockI2cError> {
+        let mut state = self.inner.lock().unwrap();
+        let channel = state.selected.trailing_zeros() as u8;
+        state.devices.get_mut(&channel).ok_or(MockI2cError).and_then(f)
+    }
+}
+
+impl Write for MuxBus {
+    type Error = MockI2cError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        if address == MUX_ADDRESS {
+            let mut state = self.inner.lock().unwrap();
+            state.selected = bytes[0];
+            state.selections.push(bytes[0]);
+            return Ok(());
+        }
+        self.with_device(|d| d.write(address, bytes))
+    }
+}
+
+impl WriteRead for MuxBus {
+    type Error = MockI2cError;
+
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.with_device(|d| d.write_read(address, bytes, buffer))
+    }
+}
+
+impl Read for MuxBus {
+    type Error = MockI2cError;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.with_device(|d| d.read(address, buffer))
+    }
+}
+
+/// MCP23017 simulado en estado de reset: todos los pines como entradas.
+fn mcp23017() -> MockI2c {
+    let mut i2c = MockI2c::new(DEFAULT_ADDRESS);
+    i2c.set(0x00, &[0xFF, 0xFF]);
+    i2c
+}
+
+#[test]
+fn mux_channels_isolate_devices_with_same_address() {
+    let bus = MuxBus::default();
+    bus.add(0, mcp23017());
+    bus.add(5, mcp23017());
+    let mux = Tca9548a::with_i2c(bus.clone(), MUX_ADDRESS).unwrap();
+
+    let mut a = Mcp23017::with_i2c(mux.channel(0).unwrap(), DEFAULT_ADDRESS).unwrap();
+    let mut b = Mcp23017::with_i2c(mux.channel(5).unwrap(), DEFAULT_ADDRESS).unwrap();
+    a.write_outputs(0x00AA).unwrap();
+    b.write_outputs(0x5500).unwrap();
+
+    assert_eq!((bus.register(0, 0x14), bus.register(0, 0x15)), (0xAA, 0x00));
+    assert_eq!((bus.register(5, 0x14), bus.register(5, 0x15)), (0x00, 0x55));
+    // Reset, canal 0 (3 lecturas + 1 escritura), canal 5 y vuelta al 0
+    assert_eq!(bus.selections(), vec![0x00, 0x01, 0x20, 0x01, 0x20]);
+    assert!(mux.channel(8).is_err());
+}
+
+#[test]
+fn rain_sensor_and_relay_on_expander_pins() {
+    let bus = MuxBus::default();
+    bus.add(2, mcp23017());
+    let mux = Tca9548a::with_i2c(bus.clone(), MUX_ADDRESS).unwrap();
+    let expander = Arc::new(Mutex::new(
+        Mcp23017::with_i2c(mux.channel(2).unwrap(), DEFAULT_ADDRESS).unwrap(),
+    ));
+
+    // MH-RD activo en bajo en GPA3
+    let mut lluvia = MhRdSensor::with_pin(ExpanderPin::new(&expander, 3).unwrap(), true);
+    bus.set(2, 0x12, &[0b1111_0111, 0xFF]);
+    assert_eq!(lluvia.read().unwrap(), SensorOutput::Text("HÚMEDO".into()));
+    bus.set(2, 0x12, &[0xFF, 0xFF]);
+    assert_eq!(lluvia.read().unwrap(), SensorOutput::Text("SECO".into()));
+
+    // Relé en GPB0: escribir un nivel lo pasa a salida
+    let mut rele = ExpanderPin::new(&expander, 8).unwrap();
+    rele.set_high().unwrap();
+    assert_eq!(bus.register(2, 0x15) & 0x01, 0x01);
+    assert_eq!(bus.register(2, 0x01) & 0x01, 0x00);
+    assert_eq!(bus.register(2, 0x00), 0xFF);
+
+    assert!(ExpanderPin::new(&expander, 16).is_err());
+}
+
+#[test]
+fn pcf8574_pins_are_quasi_bidirectional() {
+    let expander = Arc::new(Mutex::new(Pcf8574::with_i2c(MockI2c::new(0x20), 0x20).unwrap()));
+    let mut p0 = ExpanderPin::new(&expander, 0).unwrap();
+    let mut p5 = ExpanderPin::new(&expander, 5).unwrap();
+
+    p0.set_low().unwrap();
+    p5.set_low().unwrap();
+    p0.set_input(true).unwrap();
+    drop((p0, p5));
+
+    let i2c = Arc::try_unwrap(expander).ok().unwrap().into_inner().unwrap().release();
+    assert_eq!(i2c.writes, vec![vec![0xFF], vec![0xFE], vec![0xDE], vec![0xDF]]);
+}
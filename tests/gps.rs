@@ -0,0 +1,159 @@
+//! Pruebas del decodificador NMEA: validación de sentencias, hemisferios, campos
+//! vacíos, acumulación del fix, marca de tiempo Unix y vigencia de la posición compartida.
+
+mod common;
+
+use std::thread;
+use std::time::Duration;
+
+use common::{assert_close, FakeSerial};
+use lince::core::SensorError;
+use lince::devices::sensors::gps::{
+    nmea_checksum, parse_sentence, FixQuality, FixType, GpsFix, GpsSensor, NmeaDate, NmeaSentence, NmeaTime,
+};
+use lince::drivers::serial::SerialPort;
+
+/// Construye una sentencia completa `$body*HH` con su checksum.
+fn sentence(body: &str) -> String {
+    format!("${}*{:02X}", body, nmea_checksum(body))
+}
+
+#[test]
+fn malformed_sentences_are_rejected() {
+    let valid = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+    assert!(parse_sentence(valid).is_ok());
+    assert_eq!(parse_sentence(&valid.replace("*47", "*48")), Err(SensorError::InvalidData));
+    assert_eq!(parse_sentence(&valid[1..]), Err(SensorError::InvalidData));
+    assert_eq!(parse_sentence(&valid.replace("*47", "")), Err(SensorError::InvalidData));
+    assert_eq!(parse_sentence(&valid.replace("*47", "*ZZ")), Err(SensorError::InvalidData));
+    assert_eq!(parse_sentence(&sentence("G")), Err(SensorError::InvalidData));
+
+    // Bytes corruptos decodificados como U+FFFD: se rechazan sin entrar en los campos
+    let body = "GPRMC,12\u{FFFD}519,A,48\u{FFFD}7.038,N,01131.000,E,022.4,084.4,23\u{FFFD}394,,";
+    assert_eq!(parse_sentence(&sentence(body)), Err(SensorError::InvalidData));
+    let body = "GPGGA,\u{FFFD}\u{FFFD}\u{FFFD},4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,";
+    assert_eq!(parse_sentence(&sentence(body)), Err(SensorError::InvalidData));
+}
+
+#[test]
+fn hemispheres_sign_the_coordinates_and_empty_fields_are_none() {
+    let line = sentence("GNRMC,220516.50,A,3352.128,S,15112.558,W,001.0,270.0,130624,,");
+    let Ok(NmeaSentence::Rmc(rmc)) = parse_sentence(&line) else { panic!("RMC esperada") };
+    assert!(rmc.valid);
+    assert_close(rmc.latitude.unwrap(), -(33.0 + 52.128 / 60.0), 1e-6);
+    assert_close(rmc.longitude.unwrap(), -(151.0 + 12.558 / 60.0), 1e-6);
+    assert_eq!(rmc.time, Some(NmeaTime { hour: 22, minute: 5, second: 16.5 }));
+    assert_eq!(rmc.date, Some(NmeaDate { day: 13, month: 6, year: 2024 }));
+
+    // Receptor sin fix: campos vacíos
+    let line = sentence("GPGGA,,,,,,0,00,,,M,,M,,");
+    let Ok(NmeaSentence::Gga(gga)) = parse_sentence(&line) else { panic!("GGA esperada") };
+    assert_eq!(gga.time, None);
+    assert_eq!(gga.latitude, None);
+    assert_eq!(gga.longitude, None);
+    assert_eq!(gga.fix_quality, FixQuality::Invalid);
+    assert_eq!(gga.satellites, 0);
+    assert_eq!(gga.hdop, None);
+    assert_eq!(gga.altitude, None);
+
+    // Hemisferio desconocido o coordenada sin grados
+    let line = sentence("GPGGA,123519,4807.038,X,1.5,E,1,08,0.9,545.4,M,46.9,M,,");
+    let Ok(NmeaSentence::Gga(gga)) = parse_sentence(&line) else { panic!("GGA esperada") };
+    assert_eq!((gga.latitude, gga.longitude), (None, None));
+
+    let line = sentence("GPGSA,A,3,04,05,,09,12,,,,,,,,2.5,1.3,2.1");
+    let Ok(NmeaSentence::Gsa(gsa)) = parse_sentence(&line) else { panic!("GSA esperada") };
+    assert_eq!(gsa.fix_type, FixType::Fix3D);
+    assert_eq!(gsa.satellites, vec![4, 5, 9, 12]);
+    assert_eq!((gsa.pdop, gsa.hdop, gsa.vdop), (Some(2.5), Some(1.3), Some(2.1)));
+
+    assert_eq!(parse_sentence(&sentence("GPGSV,1,1,00")), Ok(NmeaSentence::Other("GSV".into())));
+}
+
+#[test]
+fn fix_accumulates_sentences_and_keeps_position_without_fix() {
+    let mut fix = GpsFix::default();
+    assert!(!fix.has_fix());
+
+    for body in [
+        "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+        "GPRMC,123519,A,4807.038,N,01131.000,E,010.0,084.4,230394,003.1,W",
+        "GPGSA,A,3,04,05,,09,12,,,,,,,,2.5,1.3,2.1",
+    ] {
+        fix.update(&parse_sentence(&sentence(body)).unwrap());
+    }
+    assert!(fix.has_fix());
+    assert_close(fix.latitude.unwrap(), 48.1173, 1e-6);
+    assert_close(fix.longitude.unwrap(), 11.0 + 31.0 / 60.0, 1e-6);
+    assert_eq!(fix.altitude, Some(545.4));
+    assert_eq!(fix.satellites, 8);
+    assert_eq!(fix.fix_type, FixType::Fix3D);
+    assert_eq!(fix.hdop, Some(1.3));
+    assert!((fix.speed_kmh.unwrap() - 18.52).abs() < 1e-4);
+
+    // Se pierde el fix: la posición anterior se conserva, la calidad no
+    fix.update(&parse_sentence(&sentence("GPGGA,123520,,,,,0,00,,,M,,M,,")).unwrap());
+    fix.update(&parse_sentence(&sentence("GPRMC,123520,V,,,,,,,230394,,")).unwrap());
+    assert!(!fix.has_fix());
+    assert_close(fix.latitude.unwrap(), 48.1173, 1e-6);
+    assert_eq!(fix.time, Some(NmeaTime { hour: 12, minute: 35, second: 20.0 }));
+    assert_eq!(fix.hdop, Some(1.3));
+}
+
+/// Receptor simulado que emite los ciclos indicados, uno tras otro.
+fn receiver(cycles: &[[&str; 2]]) -> GpsSensor<FakeSerial> {
+    let mut port = FakeSerial::new(|data| data.to_vec());
+    for body in cycles.iter().flatten() {
+        port.write_all(format!("{}\r\n", sentence(body)).as_bytes()).unwrap();
+    }
+    GpsSensor::with_port(port)
+}
+
+const FIX: [&str; 2] = [
+    "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,",
+    "GPRMC,123519,A,4807.038,N,01131.000,E,010.0,084.4,230394,003.1,W",
+];
+const NO_FIX: [&str; 2] = ["GPGGA,123520,,,,,0,00,,,M,,M,,", "GPRMC,123520,V,,,,,,,230394,,"];
+
+#[test]
+fn shared_location_is_cleared_without_fix() {
+    let mut gps = receiver(&[FIX, NO_FIX]);
+    let location = gps.location();
+    assert!(location.current().is_none());
+
+    assert!(gps.read_fix().unwrap().has_fix());
+    assert_close(location.current().unwrap().latitude.unwrap(), 48.1173, 1e-6);
+
+    // El receptor pierde el fix: el estado conserva la posición, el handle no
+    assert!(!gps.read_fix().unwrap().has_fix());
+    assert!(location.current().is_none());
+}
+
+#[test]
+fn shared_location_expires_when_the_receiver_goes_silent() {
+    let mut gps = receiver(&[FIX]);
+    let location = gps.location().with_max_age(Duration::from_millis(50));
+    gps.read_fix().unwrap();
+    assert!(location.current().is_some());
+    thread::sleep(Duration::from_millis(80));
+    assert!(location.current().is_none());
+    // Otro handle del mismo receptor, con la antigüedad por defecto, sigue viéndola
+    assert!(gps.location().current().is_some());
+}
+
+#[test]
+fn unix_time_requires_date_and_time() {
+    let mut fix = GpsFix::default();
+    assert_eq!(fix.unix_time_ms(), None);
+
+    fix.time = Some(NmeaTime { hour: 12, minute: 34, second: 56.5 });
+    assert_eq!(fix.unix_time_ms(), None);
+
+    // Día bisiesto
+    fix.date = Some(NmeaDate { day: 29, month: 2, year: 2024 });
+    assert_eq!(fix.unix_time_ms(), Some(1_709_210_096_500));
+
+    fix.date = Some(NmeaDate { day: 1, month: 1, year: 2000 });
+    fix.time = Some(NmeaTime { hour: 0, minute: 0, second: 0.0 });
+    assert_eq!(fix.unix_time_ms(), Some(946_684_800_000));
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MockPin, Waveform};
+use lince::core::traits::sensor::Sensor;
+use lince::core::SensorError;
+use lince::devices::sensors::hcsr04::{speed_of_sound, HcSr04Sensor};
+
+/// Conecta TRIG y ECHO simulados: cada disparo reproduce `echo` en el pin ECHO.
+fn sensor_with_echo(echo: Waveform) -> (HcSr04Sensor<MockPin, MockPin>, MockPin) {
+    let trigger = MockPin::new(false);
+    let echo_pin = MockPin::new(false);
+    trigger.on_falling_edge(&echo_pin, echo);
+    (HcSr04Sensor::with_pins(trigger.clone(), echo_pin), trigger)
+}
+
+fn echo_pulse(width_us: u64) -> Waveform {
+    Waveform::new(false).pulse(Duration::from_micros(200), Duration::from_micros(width_us))
+}
+
+#[test]
+fn trigger_pulse_is_low_high_low() {
+    let (mut sensor, trigger) = sensor_with_echo(echo_pulse(2_000));
+    sensor.set_samples(1);
+    sensor.read_distance().unwrap();
+    assert_eq!(trigger.writes(), vec![false, true, false]);
+}
+
+#[test]
+fn echo_width_converts_to_distance() {
+    // 5831 µs a 20 °C ≈ 100 cm
+    let (mut sensor, _) = sensor_with_echo(echo_pulse(5_831));
+    sensor.set_samples(3);
+    let d = sensor.read_distance().unwrap();
+    assert!((d - 100.0).abs() < 2.0, "distancia {}", d);
+    assert!(sensor.read().is_ok());
+}
+
+#[test]
+fn temperature_compensation_changes_distance() {
+    let (mut sensor, _) = sensor_with_echo(echo_pulse(5_000));
+    assert!(speed_of_sound(-10.0) < speed_of_sound(30.0));
+
+    sensor.set_temperature(-10.0);
+    let cold = sensor.echo_to_distance(Duration::from_micros(5_000));
+    sensor.set_temperature(30.0);
+    let warm = sensor.echo_to_distance(Duration::from_micros(5_000));
+    assert!(warm > cold);
+    assert!((warm / cold - speed_of_sound(30.0) / speed_of_sound(-10.0)).abs() < 1e-4);
+}
+
+#[test]
+fn missing_echo_times_out() {
+    let (mut sensor, _) = sensor_with_echo(Waveform::new(false));
+    sensor.set_samples(1);
+    assert_eq!(sensor.read_distance(), Err(SensorError::Timeout));
+}
+
+#[test]
+fn out_of_range_is_rejected() {
+    // Demasiado cerca: 500 µs ≈ 8.6 cm, por debajo de un mínimo de 50 cm
+    // (margen amplio frente a la latencia del planificador)
+    let (mut sensor, _) = sensor_with_echo(echo_pulse(500));
+    sensor.set_range(50.0, 400.0).unwrap();
+    sensor.set_samples(2);
+    assert_eq!(sensor.read_distance(), Err(SensorError::InvalidData));
+
+    // Eco mantenido más allá del máximo (sin obstáculo)
+    let (mut sensor, _) = sensor_with_echo(echo_pulse(45_000));
+    sensor.set_samples(1);
+    assert_eq!(sensor.read_distance(), Err(SensorError::InvalidData));
+
+    // Rango personalizado: 100 cm queda fuera de 10–50 cm
+    let (mut sensor, _) = sensor_with_echo(echo_pulse(5_831));
+    sensor.set_range(10.0, 50.0).unwrap();
+    sensor.set_samples(1);
+    assert_eq!(sensor.read_distance(), Err(SensorError::InvalidData));
+}
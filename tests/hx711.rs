@@ -0,0 +1,80 @@
+//! Pruebas del HX711 con pines simulados: pulsos de ganancia, reaplicación de la
+//! ganancia al despertar, extensión de signo y calibración.
+
+mod common;
+
+use common::{MockPin, Waveform};
+use lince::core::SensorError;
+use lince::devices::sensors::hx711::{Hx711Calibration, Hx711Gain, Hx711Sensor};
+
+/// Pulsos de reloj (flancos de subida) escritos en PD_SCK.
+fn pulses(sck: &MockPin) -> usize {
+    sck.writes().iter().filter(|&&level| level).count()
+}
+
+/// HX711 simulado con DOUT siempre en bajo: dato listo y todos los bits a cero.
+fn hx711(gain: Hx711Gain) -> (Hx711Sensor<MockPin, MockPin>, MockPin, MockPin) {
+    let dout = MockPin::new(false);
+    let sck = MockPin::new(false);
+    let sensor = Hx711Sensor::with_pins(dout.clone(), sck.clone(), gain).unwrap();
+    (sensor, dout, sck)
+}
+
+#[test]
+fn each_conversion_clocks_24_bits_plus_gain_pulses() {
+    for (gain, extra) in [(Hx711Gain::A128, 1), (Hx711Gain::B32, 2), (Hx711Gain::A64, 3)] {
+        let (mut sensor, _dout, sck) = hx711(gain);
+        let before = pulses(&sck);
+        assert_eq!(sensor.read_raw(), Ok(0));
+        assert_eq!(pulses(&sck) - before, 24 + extra, "{:?}", gain);
+    }
+}
+
+#[test]
+fn power_up_reapplies_a_non_default_gain() {
+    let (mut sensor, _dout, sck) = hx711(Hx711Gain::A64);
+    sensor.power_down().unwrap();
+    let before = pulses(&sck);
+    sensor.power_up().unwrap();
+    // Conversión descartada: 24 bits + 3 pulsos que vuelven a seleccionar A64
+    assert_eq!(pulses(&sck) - before, 27);
+    assert_eq!(sck.writes().last(), Some(&false));
+
+    let (mut sensor, _dout, sck) = hx711(Hx711Gain::A128);
+    sensor.power_down().unwrap();
+    let before = pulses(&sck);
+    sensor.power_up().unwrap();
+    assert_eq!(pulses(&sck), before);
+    assert_eq!(sck.writes().last(), Some(&false));
+}
+
+#[test]
+fn raw_value_is_sign_extended_and_timeout_is_reported() {
+    let (mut sensor, dout, sck) = hx711(Hx711Gain::A128);
+    // Tras el primer pulso DOUT queda en alto: 24 bits a uno = -1 en 24 bits
+    sck.on_falling_edge(&dout, Waveform::new(true));
+    assert_eq!(sensor.read_raw(), Ok(-1));
+    // DOUT sigue en alto: el HX711 nunca indica dato listo
+    assert_eq!(sensor.read_raw(), Err(SensorError::Timeout));
+}
+
+#[test]
+fn calibration_converts_and_round_trips_through_toml() {
+    let calibration = Hx711Calibration { offset: 8388, scale: 421.5, unit: "g".into() };
+    assert_eq!(calibration.to_mass(8388.0 + 843.0), 2.0);
+
+    let path = std::env::temp_dir().join(format!("lince-hx711-{}.toml", std::process::id()));
+    calibration.save(&path).unwrap();
+    assert_eq!(Hx711Calibration::load(&path), Ok(calibration));
+    std::fs::write(&path, "offset = 0\nscale = 0.0\nunit = \"g\"\n").unwrap();
+    assert_eq!(Hx711Calibration::load(&path), Err(SensorError::InvalidData));
+    std::fs::remove_file(&path).unwrap();
+
+    // Sin carga sobre la báscula la lectura no cambia tras la tara
+    let (mut sensor, _dout, _sck) = hx711(Hx711Gain::A128);
+    sensor.set_samples(2);
+    sensor.tare().unwrap();
+    assert_eq!(sensor.calibration().offset, 0);
+    assert_eq!(sensor.calibrate(1.0), Err(SensorError::InvalidData));
+    assert_eq!(sensor.calibrate(0.0), Err(SensorError::InvalidData));
+}
@@ -0,0 +1,122 @@
+//! Pruebas de los INA219/INA226 sobre un bus simulado con registros de 16 bits:
+//! palabra de configuración, registro de calibración, límites de rango y desbordamiento.
+
+mod common;
+
+use common::MockI2c;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::ina::{InaCalibration, InaModel, InaSensor, DEFAULT_ADDRESS};
+
+const CONFIG: u8 = 0x00;
+const SHUNT: u8 = 0x01;
+const BUS: u8 = 0x02;
+const POWER: u8 = 0x03;
+const CURRENT: u8 = 0x04;
+const CALIBRATION: u8 = 0x05;
+
+/// INA226 simulado con su identificador de fabricante y de chip.
+fn ina226() -> MockI2c {
+    let mut i2c = MockI2c::words(DEFAULT_ADDRESS);
+    i2c.set_word(0xFE, 0x5449);
+    i2c.set_word(0xFF, 0x2260);
+    i2c
+}
+
+fn ina219(shunt_ohms: f32, max_current: f32) -> Result<InaSensor<MockI2c>, SensorError> {
+    let calibration = InaCalibration::new(shunt_ohms, max_current);
+    InaSensor::with_i2c(MockI2c::words(DEFAULT_ADDRESS), DEFAULT_ADDRESS, InaModel::Ina219, calibration)
+}
+
+#[test]
+fn ina219_config_word_and_calibration_register() {
+    let mut sensor = ina219(0.1, 2.0).unwrap();
+    sensor.set_averaging(16).unwrap();
+    assert_eq!(sensor.averaging(), 16);
+    assert_eq!(sensor.set_averaging(3), Err(SensorError::InitializationError));
+
+    let i2c = sensor.release();
+    // Reset, configuración con PGA ±320 mV (0.2 V de shunt) y ADC de 12 bits, y promediado x16
+    assert_eq!(i2c.written_words(CONFIG), vec![0x8000, 0x399F, 0x3E67]);
+    // 0.04096 / (2 A / 2^15 · 0.1 Ω) = 6710.9, con el bit 0 a cero
+    assert_eq!(i2c.written_words(CALIBRATION), vec![6710]);
+}
+
+#[test]
+fn ina226_config_word_calibration_and_id() {
+    let calibration = InaCalibration::new(0.1, 0.8192);
+    let mut sensor = InaSensor::with_i2c(ina226(), DEFAULT_ADDRESS, InaModel::Ina226, calibration).unwrap();
+    sensor.set_averaging(16).unwrap();
+    let i2c = sensor.release();
+    assert_eq!(i2c.written_words(CONFIG), vec![0x8000, 0x4127, 0x4527]);
+    assert_eq!(i2c.written_words(CALIBRATION), vec![2048]);
+
+    let mut wrong_id = ina226();
+    wrong_id.set_word(0xFF, 0x2270);
+    let result = InaSensor::with_i2c(wrong_id, DEFAULT_ADDRESS, InaModel::Ina226, calibration);
+    assert!(matches!(result, Err(SensorError::InitializationError)));
+}
+
+#[test]
+fn calibrations_on_a_range_edge_are_accepted() {
+    // 0.1 · 3.2 = 0.32000002 en f32: límite del PGA de ±320 mV
+    let i2c = ina219(0.1, 3.2).unwrap().release();
+    assert_eq!(i2c.written_words(CONFIG).last().map(|c| (c >> 11) & 0b11), Some(3));
+    // 0.1 · 0.4 = 0.04: el PGA más sensible (±40 mV)
+    let i2c = ina219(0.1, 0.4).unwrap().release();
+    assert_eq!(i2c.written_words(CONFIG).last().map(|c| (c >> 11) & 0b11), Some(0));
+    let i2c = ina219(0.1, 0.8).unwrap().release();
+    assert_eq!(i2c.written_words(CONFIG).last().map(|c| (c >> 11) & 0b11), Some(1));
+
+    assert!(matches!(ina219(0.1, 3.3), Err(SensorError::InitializationError)));
+    assert!(matches!(ina219(0.0, 1.0), Err(SensorError::InitializationError)));
+
+    let calibration = InaCalibration::new(0.1, 0.83);
+    let result = InaSensor::with_i2c(ina226(), DEFAULT_ADDRESS, InaModel::Ina226, calibration);
+    assert!(matches!(result, Err(SensorError::InitializationError)));
+}
+
+#[test]
+fn ina226_calibration_above_15_bits_is_rejected() {
+    // 0.00512 / (0.4 A / 2^15 · 0.01 Ω) = 41943: el bit 15 del INA226 está reservado
+    let calibration = InaCalibration::new(0.01, 0.4);
+    let result = InaSensor::with_i2c(ina226(), DEFAULT_ADDRESS, InaModel::Ina226, calibration);
+    assert!(matches!(result, Err(SensorError::InitializationError)));
+
+    // En el INA219 el mismo shunt da 0.04096 / (0.4 A / 2^15 · 0.01 Ω) = 335544: también fuera
+    assert!(matches!(ina219(0.01, 0.4), Err(SensorError::InitializationError)));
+    // 0.00512 / (0.8 A / 2^15 · 0.01 Ω) = 20971: cabe en 15 bits
+    let calibration = InaCalibration::new(0.01, 0.8);
+    let i2c = InaSensor::with_i2c(ina226(), DEFAULT_ADDRESS, InaModel::Ina226, calibration).unwrap().release();
+    assert_eq!(i2c.written_words(CALIBRATION), vec![20971]);
+}
+
+#[test]
+fn measurement_is_scaled_and_overflow_is_invalid_data() {
+    let sensor = |bus: u16, shunt: i16, current: i16| {
+        let mut i2c = MockI2c::words(DEFAULT_ADDRESS);
+        i2c.set_word(BUS, bus);
+        i2c.set_word(SHUNT, shunt as u16);
+        i2c.set_word(CURRENT, current as u16);
+        i2c.set_word(POWER, 5235);
+        InaSensor::with_i2c(i2c, DEFAULT_ADDRESS, InaModel::Ina219, InaCalibration::new(0.1, 2.0)).unwrap()
+    };
+
+    let mut forward = sensor(3120 << 3, 5120, 8389);
+    let reading = forward.read_measurement().unwrap();
+    assert!((reading.bus_voltage - 12.48).abs() < 1e-4);
+    assert!((reading.shunt_voltage - 0.0512).abs() < 1e-6);
+    assert_eq!(
+        forward.read(),
+        Ok(SensorOutput::Text("Bus: 12.48 V, Corriente: 0.512 A, Potencia: 6.39 W".into()))
+    );
+
+    // Corriente en sentido inverso: registros con signo
+    let mut reverse = sensor(3120 << 3, -5120, -8389);
+    assert!((reverse.read_current().unwrap() + 0.512).abs() < 1e-3);
+    assert!((reverse.read_shunt_voltage().unwrap() + 0.0512).abs() < 1e-6);
+
+    // Bit OVF del registro de bus
+    let mut overflow = sensor((3120 << 3) | 0x0001, 5120, 8389);
+    assert_eq!(overflow.read_measurement(), Err(SensorError::InvalidData));
+}
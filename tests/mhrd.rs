@@ -0,0 +1,76 @@
+//! Pruebas del modo analógico del MH-RD con un ADC simulado: calibración seco/mojado,
+//! histéresis 60/40 y formato de la salida.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::mhrd::{MhRdCalibration, MhRdSensor};
+use lince::drivers::adc::AnalogInput;
+
+/// ADC de 10 bits cuyo valor se fija desde la prueba.
+struct FakeAdc(Rc<Cell<u16>>);
+
+impl AnalogInput for FakeAdc {
+    fn read_raw(&mut self) -> Result<u16, SensorError> {
+        Ok(self.0.get())
+    }
+
+    fn max_raw(&self) -> u16 {
+        1023
+    }
+}
+
+fn text(s: &str) -> SensorOutput {
+    SensorOutput::Text(s.into())
+}
+
+#[test]
+fn wetness_is_calibrated_and_clamped() {
+    // El valor baja con el agua: 1000 seco, 200 mojado
+    let cal = MhRdCalibration::new(1000, 200);
+    assert_eq!(cal.wetness_percent(1000), 0.0);
+    assert_eq!(cal.wetness_percent(600), 50.0);
+    assert_eq!(cal.wetness_percent(200), 100.0);
+    assert_eq!(cal.wetness_percent(1023), 0.0);
+    assert_eq!(cal.wetness_percent(50), 100.0);
+}
+
+#[test]
+fn state_changes_only_when_crossing_opposite_threshold() {
+    let raw = Rc::new(Cell::new(1000));
+    let mut sensor =
+        MhRdSensor::new_analog(Box::new(FakeAdc(raw.clone())), MhRdCalibration::new(1000, 200)).unwrap();
+
+    let steps = [
+        (1000, "SECO (0.0%)"),
+        (600, "SECO (50.0%)"),   // por debajo del 60 %: sigue seco
+        (520, "HÚMEDO (60.0%)"), // alcanza el umbral de mojado
+        (600, "HÚMEDO (50.0%)"), // por encima del 40 %: sigue húmedo
+        (680, "SECO (40.0%)"),   // alcanza el umbral de secado
+        (600, "SECO (50.0%)"),
+    ];
+    for (value, expected) in steps {
+        raw.set(value);
+        assert_eq!(sensor.read().unwrap(), text(expected), "raw = {}", value);
+    }
+    assert_eq!(sensor.read_raw().unwrap(), 600);
+    assert_eq!(sensor.read_wetness().unwrap(), 50.0);
+}
+
+#[test]
+fn incoherent_calibration_is_rejected() {
+    let adc = || Box::new(FakeAdc(Rc::new(Cell::new(0))));
+
+    let same = MhRdCalibration::new(500, 500);
+    assert!(matches!(MhRdSensor::new_analog(adc(), same), Err(SensorError::InitializationError)));
+
+    let mut inverted = MhRdCalibration::new(1000, 200);
+    inverted.dry_threshold = 70.0;
+    assert!(matches!(MhRdSensor::new_analog(adc(), inverted), Err(SensorError::InitializationError)));
+
+    // Referencia fuera del rango del ADC de 10 bits
+    let out_of_range = MhRdCalibration::new(4000, 200);
+    assert!(matches!(MhRdSensor::new_analog(adc(), out_of_range), Err(SensorError::InitializationError)));
+}
@@ -0,0 +1,86 @@
+//! Pruebas del MH-Z19 sobre un puerto serie simulado: checksum, tramas de comando,
+//! decodificación de la respuesta y precalentamiento.
+
+mod common;
+
+use std::time::Duration;
+
+use common::FakeSerial;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::mhz19::{checksum, decode_response, Mhz19Reading, Mhz19Sensor};
+
+/// Respuesta a la lectura: 608 ppm y 31 °C (0x47 − 40), con su checksum.
+fn co2_response() -> [u8; 9] {
+    let mut frame = [0xFF, 0x86, 0x02, 0x60, 0x47, 0x00, 0x00, 0x00, 0x00];
+    frame[8] = checksum(&frame);
+    frame
+}
+
+/// Sensor ya precalentado que responde `reply` a cada comando de lectura.
+fn sensor(reply: Vec<u8>) -> Mhz19Sensor<FakeSerial> {
+    let port = FakeSerial::new(move |frame| if frame[2] == 0x86 { reply.clone() } else { Vec::new() });
+    let mut sensor = Mhz19Sensor::with_port(port);
+    sensor.set_warmup(Duration::ZERO);
+    sensor
+}
+
+#[test]
+fn checksum_matches_datasheet_frames() {
+    assert_eq!(checksum(&[0xFF, 0x01, 0x86, 0, 0, 0, 0, 0, 0]), 0x79);
+    // Calibración de cero: FF 01 87 00 00 00 00 00 78
+    assert_eq!(checksum(&[0xFF, 0x01, 0x87, 0, 0, 0, 0, 0, 0]), 0x78);
+    assert_eq!(co2_response()[8], 0xD1);
+}
+
+#[test]
+fn response_is_decoded_and_validated() {
+    let frame = co2_response();
+    assert_eq!(decode_response(&frame), Ok(Mhz19Reading { co2_ppm: 608, temperature: 31 }));
+
+    let mut bad_checksum = frame;
+    bad_checksum[8] ^= 0x01;
+    assert_eq!(decode_response(&bad_checksum), Err(SensorError::InvalidData));
+
+    let mut bad_command = frame;
+    bad_command[1] = 0x87;
+    bad_command[8] = checksum(&bad_command);
+    assert_eq!(decode_response(&bad_command), Err(SensorError::InvalidData));
+}
+
+#[test]
+fn read_sends_the_command_and_decodes_the_reply() {
+    let mut sensor = sensor(co2_response().to_vec());
+    assert_eq!(sensor.read(), Ok(SensorOutput::Int(608)));
+    sensor.set_abc(false).unwrap();
+    sensor.calibrate_span(2000).unwrap();
+    assert_eq!(sensor.set_detection_range(3000), Err(SensorError::InitializationError));
+    sensor.set_detection_range(5000).unwrap();
+
+    let written = sensor.release().written;
+    assert_eq!(
+        written,
+        vec![
+            vec![0xFF, 0x01, 0x86, 0x00, 0x00, 0x00, 0x00, 0x00, 0x79],
+            vec![0xFF, 0x01, 0x79, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86],
+            vec![0xFF, 0x01, 0x88, 0x07, 0xD0, 0x00, 0x00, 0x00, 0xA0],
+            vec![0xFF, 0x01, 0x99, 0x00, 0x00, 0x00, 0x13, 0x88, 0xCB],
+        ]
+    );
+}
+
+#[test]
+fn warmup_silence_and_corrupt_replies_are_errors() {
+    let mut warming = Mhz19Sensor::with_port(FakeSerial::new(|_| Vec::new()));
+    assert_eq!(warming.read(), Err(SensorError::InitializationError));
+    assert!(warming.warmup_remaining() > Duration::from_secs(170));
+    // Durante el precalentamiento no se envía nada al sensor
+    assert!(warming.release().written.is_empty());
+
+    assert_eq!(sensor(Vec::new()).read(), Err(SensorError::Timeout));
+    assert_eq!(sensor(co2_response()[..5].to_vec()).read(), Err(SensorError::Timeout));
+
+    let mut corrupt = co2_response();
+    corrupt[3] ^= 0x10;
+    assert_eq!(sensor(corrupt.to_vec()).read(), Err(SensorError::InvalidData));
+}
@@ -0,0 +1,150 @@
+//! Pruebas del cliente Modbus TCP y de `ModbusSensor` contra un servidor local que
+//! simula un contador de energía con un mapa de registros en memoria.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::modbus::{ModbusRegister, ModbusSensor, RegisterFormat, RegisterKind, WordOrder};
+use lince::drivers::modbus::{ModbusClient, ModbusTcpClient};
+
+/// Mapa de registros del servidor: (función de lectura, dirección) → valor.
+type Registers = Arc<Mutex<HashMap<(u8, u16), u16>>>;
+
+/// Lanza un servidor Modbus TCP en un puerto libre y devuelve su dirección.
+///
+/// Admite las funciones 0x03, 0x04 y 0x06; un registro ausente produce la
+/// excepción 0x02 (dirección ilegal).
+fn spawn_server(registers: Registers) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let registers = registers.clone();
+            thread::spawn(move || serve(stream.unwrap(), registers));
+        }
+    });
+    addr
+}
+
+fn serve(mut stream: TcpStream, registers: Registers) {
+    loop {
+        let mut header = [0u8; 7];
+        if stream.read_exact(&mut header).is_err() {
+            return;
+        }
+        let len = u16::from_be_bytes([header[4], header[5]]) as usize;
+        let mut pdu = vec![0u8; len - 1];
+        stream.read_exact(&mut pdu).unwrap();
+
+        let function = pdu[0];
+        let address = u16::from_be_bytes([pdu[1], pdu[2]]);
+        let value = u16::from_be_bytes([pdu[3], pdu[4]]);
+        let mut map = registers.lock().unwrap();
+        let response = match function {
+            0x03 | 0x04 => {
+                let words: Option<Vec<u16>> =
+                    (address..address + value).map(|a| map.get(&(function, a)).copied()).collect();
+                match words {
+                    Some(words) => {
+                        let mut r = vec![function, (words.len() * 2) as u8];
+                        words.iter().for_each(|w| r.extend_from_slice(&w.to_be_bytes()));
+                        r
+                    }
+                    None => vec![function | 0x80, 0x02],
+                }
+            }
+            0x06 => {
+                map.insert((0x03, address), value);
+                pdu.clone()
+            }
+            _ => vec![function | 0x80, 0x01],
+        };
+        drop(map);
+
+        let mut frame = header[..4].to_vec();
+        frame.extend_from_slice(&(response.len() as u16 + 1).to_be_bytes());
+        frame.push(header[6]);
+        frame.extend_from_slice(&response);
+        stream.write_all(&frame).unwrap();
+    }
+}
+
+/// Contador simulado: tensión f32 (ABCD), energía u32 (CDAB, en centésimas de kWh)
+/// y potencia reactiva i32 negativa.
+fn meter() -> Registers {
+    let mut map = HashMap::new();
+    let [v_hi, v_lo] = [(230.1f32.to_bits() >> 16) as u16, 230.1f32.to_bits() as u16];
+    map.insert((0x04, 0), v_hi);
+    map.insert((0x04, 1), v_lo);
+    map.insert((0x03, 10), 0x86A0);
+    map.insert((0x03, 11), 0x0001);
+    let q = (-1500i32) as u32;
+    map.insert((0x04, 20), (q >> 16) as u16);
+    map.insert((0x04, 21), q as u16);
+    Arc::new(Mutex::new(map))
+}
+
+#[test]
+fn sensor_decodes_formats_word_order_and_scale() {
+    let addr = spawn_server(meter());
+    let client = ModbusTcpClient::connect(addr.as_str()).unwrap();
+    let registers = vec![
+        ModbusRegister::new("Tensión", RegisterKind::Input, 0, RegisterFormat::F32).with_unit("V"),
+        ModbusRegister::new("Energía", RegisterKind::Holding, 10, RegisterFormat::U32)
+            .with_word_order(WordOrder::LowFirst)
+            .with_scale(0.01)
+            .with_unit("kWh"),
+        ModbusRegister::new("Reactiva", RegisterKind::Input, 20, RegisterFormat::I32).with_unit("var"),
+    ];
+    let mut sensor = ModbusSensor::new(client, 1, registers).unwrap();
+
+    let values = sensor.read_values().unwrap();
+    // 230.1 no es exacto en f32: el par conserva el valor ensanchado a f64...
+    assert_eq!(values[0], ("Tensión".to_string(), 230.1f32 as f64));
+    assert_eq!(values[1], ("Energía".to_string(), 1000.0));
+    assert_eq!(values[2], ("Reactiva".to_string(), -1500.0));
+
+    match sensor.read().unwrap() {
+        SensorOutput::Text(text) => {
+            // ...pero el texto se escribe con la precisión de f32
+            assert_eq!(text, "Tensión: 230.1 V, Energía: 1000 kWh, Reactiva: -1500 var")
+        }
+        other => panic!("salida inesperada: {:?}", other),
+    }
+}
+
+#[test]
+fn single_register_is_reported_as_float() {
+    let addr = spawn_server(meter());
+    let client = ModbusTcpClient::connect(addr.as_str()).unwrap();
+    let reg = ModbusRegister::new("Tensión", RegisterKind::Input, 0, RegisterFormat::F32);
+    let mut sensor = ModbusSensor::new(client, 1, vec![reg]).unwrap();
+
+    assert!(matches!(sensor.read(), Ok(SensorOutput::Float(v)) if v == 230.1));
+}
+
+#[test]
+fn exception_response_is_a_device_fault() {
+    let addr = spawn_server(meter());
+    let mut client = ModbusTcpClient::connect(addr.as_str()).unwrap();
+
+    assert!(matches!(client.read_holding_registers(1, 500, 2), Err(SensorError::DeviceFault)));
+    // La conexión sigue siendo utilizable después de la excepción
+    assert_eq!(client.read_holding_registers(1, 10, 2).unwrap(), vec![0x86A0, 0x0001]);
+}
+
+#[test]
+fn write_single_register_round_trips() {
+    let registers = meter();
+    let addr = spawn_server(registers.clone());
+    let mut client = ModbusTcpClient::connect(addr.as_str()).unwrap();
+
+    client.write_single_register(1, 40, 0xBEEF).unwrap();
+    assert_eq!(registers.lock().unwrap().get(&(0x03, 40)), Some(&0xBEEF));
+    assert_eq!(client.read_holding_registers(1, 40, 1).unwrap(), vec![0xBEEF]);
+}
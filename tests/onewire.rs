@@ -0,0 +1,58 @@
+//! Pruebas del maestro 1-Wire por software con esclavos simulados: búsqueda de ROM
+//! con bifurcaciones, READ ROM y CRC inválido.
+
+mod common;
+
+use common::{OneWireSim, OneWireSlave};
+use lince::core::SensorError;
+use lince::drivers::onewire::{OneWireBus, RomId};
+
+fn slave(rom: RomId) -> OneWireSlave {
+    OneWireSlave { rom: rom.0, scratchpad: [0; 9] }
+}
+
+fn rom(id: &str) -> RomId {
+    id.parse().unwrap()
+}
+
+#[test]
+fn search_finds_every_rom_across_branches() {
+    // Las tres ROMs se separan en varias posiciones del árbol de búsqueda
+    let roms = [rom("28-0316a2794aff"), rom("28-0316a2794a00"), rom("10-000801b6a2c4")];
+    let mut bus = OneWireBus::with_pin(OneWireSim::new(roms.iter().copied().map(slave).collect()));
+
+    assert_eq!(bus.reset(), Ok(true));
+    let mut found = bus.search().unwrap();
+    found.sort();
+    let mut expected = roms.to_vec();
+    expected.sort();
+    assert_eq!(found, expected);
+
+    let mut ds18b20 = bus.search_family(0x28).unwrap();
+    ds18b20.sort();
+    assert_eq!(ds18b20, vec![roms[1], roms[0]]);
+}
+
+#[test]
+fn invalid_rom_crc_is_reported() {
+    let good = rom("28-0316a2794aff");
+    let mut bad = good;
+    bad.0[7] ^= 0x01;
+
+    let mut bus = OneWireBus::with_pin(OneWireSim::new(vec![slave(good), slave(bad)]));
+    assert_eq!(bus.search(), Err(SensorError::InvalidData));
+
+    let mut bus = OneWireBus::with_pin(OneWireSim::new(vec![slave(bad)]));
+    assert_eq!(bus.read_rom(), Err(SensorError::InvalidData));
+
+    let mut bus = OneWireBus::with_pin(OneWireSim::new(vec![slave(good)]));
+    assert_eq!(bus.read_rom(), Ok(good));
+}
+
+#[test]
+fn empty_bus_has_no_presence() {
+    let mut bus = OneWireBus::with_pin(OneWireSim::new(Vec::new()));
+    assert_eq!(bus.reset(), Ok(false));
+    assert_eq!(bus.search(), Ok(Vec::new()));
+    assert_eq!(bus.select(&rom("28-0316a2794aff")), Err(SensorError::Timeout));
+}
@@ -0,0 +1,178 @@
+//! Pruebas de los sensores de partículas alimentando tramas grabadas a través de
+//! un pseudo-terminal: el driver abre el extremo esclavo como si fuera `/dev/serial0`
+//! y la prueba hace de sensor en el extremo maestro.
+
+use std::ffi::CStr;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::os::fd::FromRawFd;
+use std::ptr;
+use std::thread;
+use std::time::Duration;
+
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::particulate::{ParticleCounts, Pms5003Sensor, Sds011Sensor};
+
+/// Trama PMS5003 grabada: PM1.0/PM2.5/PM10 = 5/8/9 µg/m³.
+const PMS_FRAME: [u8; 32] = [
+    0x42, 0x4D, 0x00, 0x1C, 0x00, 0x05, 0x00, 0x08, 0x00, 0x09, 0x00, 0x05, 0x00, 0x08, 0x00, 0x09,
+    0x03, 0xE8, 0x01, 0x2C, 0x00, 0x32, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x97, 0x00, 0x02, 0xBE,
+];
+
+/// Respuesta del PMS5003 a un comando de sueño en modo pasivo.
+const PMS_SLEEP_REPLY: [u8; 8] = [0x42, 0x4D, 0x00, 0x04, 0xE4, 0x00, 0x01, 0x77];
+
+/// Trama SDS011 grabada: PM2.5 = 4.2 µg/m³, PM10 = 7.9 µg/m³.
+const SDS_FRAME: [u8; 10] = [0xAA, 0xC0, 0x2A, 0x00, 0x4F, 0x00, 0x12, 0x34, 0xBF, 0xAB];
+
+/// Respuesta del SDS011 a un comando de configuración.
+const SDS_REPLY: [u8; 10] = [0xAA, 0xC5, 0x02, 0x01, 0x01, 0x00, 0x12, 0x34, 0x4A, 0xAB];
+
+/// Par de pseudo-terminal: `master` simula el sensor y `path` es el puerto del driver.
+struct Pty {
+    master: File,
+    _slave: File,
+    path: String,
+}
+
+fn open_pty() -> Pty {
+    let (mut master, mut slave) = (0, 0);
+    let rc = unsafe { libc::openpty(&mut master, &mut slave, ptr::null_mut(), ptr::null(), ptr::null()) };
+    assert_eq!(rc, 0, "openpty falló");
+    let path = unsafe { CStr::from_ptr(libc::ttyname(slave)) }.to_str().unwrap().to_owned();
+    unsafe {
+        Pty {
+            master: File::from_raw_fd(master),
+            _slave: File::from_raw_fd(slave),
+            path,
+        }
+    }
+}
+
+/// Escribe `bytes` en el maestro tras una pausa (después de que el driver vacíe la entrada).
+fn send_later(master: &File, bytes: Vec<u8>) -> thread::JoinHandle<()> {
+    let mut master = master.try_clone().unwrap();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        master.write_all(&bytes).unwrap();
+    })
+}
+
+#[test]
+fn pms5003_active_frame_is_decoded_after_garbage() {
+    let pty = open_pty();
+    let mut sensor = Pms5003Sensor::new(&pty.path).unwrap();
+
+    let mut stream = vec![0x00, 0x42, 0x13];
+    stream.extend_from_slice(&PMS_FRAME);
+    let device = send_later(&pty.master, stream);
+
+    let reading = sensor.read_measurement().unwrap();
+    device.join().unwrap();
+
+    assert_eq!(reading.pm1_0, Some(5.0));
+    assert_eq!(reading.pm2_5, 8.0);
+    assert_eq!(reading.pm10, 9.0);
+    assert_eq!(
+        reading.counts,
+        Some(ParticleCounts { over_0_3: 1000, over_0_5: 300, over_1_0: 50, over_2_5: 5, over_5_0: 1, over_10: 0 })
+    );
+}
+
+#[test]
+fn pms5003_bad_checksum_is_rejected() {
+    let pty = open_pty();
+    let mut sensor = Pms5003Sensor::new(&pty.path).unwrap();
+
+    let mut frame = PMS_FRAME.to_vec();
+    frame[31] ^= 0x01;
+    let device = send_later(&pty.master, frame);
+
+    assert_eq!(sensor.read(), Err(SensorError::InvalidData));
+    device.join().unwrap();
+}
+
+#[test]
+fn pms5003_passive_duty_cycle_wakes_reads_and_sleeps() {
+    let pty = open_pty();
+    let mut sensor = Pms5003Sensor::new(&pty.path).unwrap();
+
+    let mut master = pty.master.try_clone().unwrap();
+    let device = thread::spawn(move || {
+        let mut commands = Vec::new();
+        for _ in 0..5 {
+            let mut cmd = [0u8; 7];
+            master.read_exact(&mut cmd).unwrap();
+            if cmd[2] == 0xE2 {
+                master.write_all(&PMS_SLEEP_REPLY).unwrap();
+                master.write_all(&PMS_FRAME).unwrap();
+            }
+            commands.push(cmd);
+        }
+        commands
+    });
+
+    sensor.set_passive_mode(true).unwrap();
+    sensor.set_duty_cycle(Some(Duration::from_millis(10))).unwrap();
+    let output = sensor.read().unwrap();
+    let commands = device.join().unwrap();
+
+    assert_eq!(
+        output,
+        SensorOutput::Text("PM1.0: 5.0 µg/m³, PM2.5: 8.0 µg/m³, PM10: 9.0 µg/m³".into())
+    );
+    // Modo pasivo, dormir, despertar, leer, dormir
+    let sequence: Vec<(u8, u8)> = commands.iter().map(|c| (c[2], c[4])).collect();
+    assert_eq!(sequence, vec![(0xE1, 0), (0xE4, 0), (0xE4, 1), (0xE2, 0), (0xE4, 0)]);
+    // Comando de lectura del datasheet
+    assert_eq!(commands[3], [0x42, 0x4D, 0xE2, 0x00, 0x00, 0x01, 0x71]);
+}
+
+#[test]
+fn sds011_query_mode_skips_command_replies() {
+    let pty = open_pty();
+    let mut sensor = Sds011Sensor::new(&pty.path).unwrap();
+
+    let mut master = pty.master.try_clone().unwrap();
+    let device = thread::spawn(move || {
+        let mut commands = Vec::new();
+        for _ in 0..3 {
+            let mut cmd = [0u8; 19];
+            master.read_exact(&mut cmd).unwrap();
+            master.write_all(&SDS_REPLY).unwrap();
+            if cmd[2] == 0x04 {
+                master.write_all(&SDS_FRAME).unwrap();
+            }
+            commands.push(cmd);
+        }
+        commands
+    });
+
+    sensor.set_query_mode(true).unwrap();
+    let reading = sensor.read_measurement().unwrap();
+    sensor.sleep().unwrap();
+    let commands = device.join().unwrap();
+
+    assert_eq!(reading.pm1_0, None);
+    assert!((reading.pm2_5 - 4.2).abs() < 1e-6);
+    assert!((reading.pm10 - 7.9).abs() < 1e-6);
+
+    // Tramas de consulta y de sueño del datasheet
+    let mut query = [0u8; 19];
+    query[..3].copy_from_slice(&[0xAA, 0xB4, 0x04]);
+    query[15..].copy_from_slice(&[0xFF, 0xFF, 0x02, 0xAB]);
+    assert_eq!(commands[1], query);
+
+    let mut sleep = [0u8; 19];
+    sleep[..4].copy_from_slice(&[0xAA, 0xB4, 0x06, 0x01]);
+    sleep[15..].copy_from_slice(&[0xFF, 0xFF, 0x05, 0xAB]);
+    assert_eq!(commands[2], sleep);
+}
+
+#[test]
+fn silent_sensor_times_out() {
+    let pty = open_pty();
+    let mut sensor = Sds011Sensor::new(&pty.path).unwrap();
+    assert_eq!(sensor.read(), Err(SensorError::Timeout));
+}
@@ -0,0 +1,91 @@
+//! Pruebas de `ProcessSensor` con pequeños scripts de `sh` que hacen de driver externo.
+
+use std::time::Duration;
+
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::process::{ProcessMode, ProcessSensor};
+
+fn sh(script: &str, mode: ProcessMode) -> ProcessSensor {
+    ProcessSensor::new("sh", &["-c", script], mode)
+}
+
+#[test]
+fn one_shot_parses_last_json_line_and_captures_stderr() {
+    let mut sensor = sh(
+        r#"echo "calentando" >&2; echo "log previo"; echo '{"value": 21.5}'"#,
+        ProcessMode::OneShot,
+    );
+
+    assert_eq!(sensor.read().unwrap(), SensorOutput::Float(21.5));
+    // El hilo de stderr puede terminar justo después de la lectura
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(sensor.stderr_lines(), vec!["calentando".to_string()]);
+}
+
+#[test]
+fn one_shot_is_killed_on_timeout() {
+    let mut sensor = sh("sleep 5; echo 1", ProcessMode::OneShot);
+    sensor.set_timeout(Duration::from_millis(200));
+
+    assert!(matches!(sensor.read(), Err(SensorError::Timeout)));
+}
+
+#[test]
+fn reported_errors_are_mapped() {
+    let mut sensor = sh(r#"echo '{"error": "device_fault"}'"#, ProcessMode::OneShot);
+    assert!(matches!(sensor.read(), Err(SensorError::DeviceFault)));
+
+    let mut sensor = sh("echo 'no es json'", ProcessMode::OneShot);
+    assert!(matches!(sensor.read(), Err(SensorError::InvalidData)));
+}
+
+#[test]
+fn long_lived_answers_requests() {
+    let script = r#"n=0; while read req; do n=$((n+1)); echo "{\"value\": $n}"; done"#;
+    let mut sensor = sh(script, ProcessMode::LongLived { request: Some("read".into()) });
+
+    assert_eq!(sensor.read().unwrap(), SensorOutput::Int(1));
+    assert_eq!(sensor.read().unwrap(), SensorOutput::Int(2));
+}
+
+#[test]
+fn long_lived_is_restarted_after_crash() {
+    // Responde a una petición y termina, simulando un fallo del driver
+    let script = r#"read req; echo true; exit 1"#;
+    let mut sensor = sh(script, ProcessMode::LongLived { request: Some("read".into()) });
+    sensor.set_timeout(Duration::from_secs(2));
+
+    assert_eq!(sensor.read().unwrap(), SensorOutput::Bool(true));
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(sensor.read().unwrap(), SensorOutput::Bool(true));
+    assert_eq!(sensor.restarts(), 0);
+}
+
+#[test]
+fn long_lived_gives_up_after_max_restarts() {
+    // Cada lanzamiento deja una línea en el archivo y termina sin responder
+    let log = std::env::temp_dir().join(format!("lince-process-spawns-{}", std::process::id()));
+    let _ = std::fs::remove_file(&log);
+    let script = format!("echo x >> '{}'; exit 1", log.display());
+    let spawns = || std::fs::read_to_string(&log).map(|s| s.lines().count()).unwrap_or(0);
+
+    let mut sensor = sh(&script, ProcessMode::LongLived { request: None });
+    sensor.set_timeout(Duration::from_millis(500));
+    sensor.set_max_restarts(2);
+
+    for _ in 0..6 {
+        assert!(sensor.read().is_err());
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    // Lanzamiento inicial más dos reinicios; después no se vuelve a lanzar
+    assert_eq!(sensor.restarts(), 2);
+    assert_eq!(spawns(), 3);
+
+    // `stop` rearma el sensor
+    sensor.stop();
+    assert_eq!(sensor.restarts(), 0);
+    assert!(sensor.read().is_err());
+    assert_eq!(spawns(), 4);
+    std::fs::remove_file(&log).unwrap();
+}
@@ -0,0 +1,86 @@
+//! Pruebas de las métricas psicrométricas, del sensor virtual sobre una fuente
+//! `ClimateSensor` simulada y de la decodificación de tramas DHT22.
+
+mod common;
+
+use common::assert_close;
+use lince::core::traits::climate::{ClimateReading, ClimateSensor};
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::dht22::Dht22Sensor;
+use lince::devices::sensors::psychrometric::{
+    absolute_humidity, dew_point, heat_index, vapour_pressure_deficit, PsychrometricSensor,
+};
+
+/// Fuente de clima que devuelve una secuencia fija de resultados.
+struct FakeClimate(Vec<Result<ClimateReading, SensorError>>);
+
+impl ClimateSensor for FakeClimate {
+    fn read_climate(&mut self) -> Result<ClimateReading, SensorError> {
+        self.0.remove(0)
+    }
+}
+
+/// Trama DHT22 con el checksum calculado.
+fn dht22_frame(humidity: u16, temperature: u16) -> [u8; 5] {
+    let [h0, h1] = humidity.to_be_bytes();
+    let [t0, t1] = temperature.to_be_bytes();
+    [h0, h1, t0, t1, h0.wrapping_add(h1).wrapping_add(t0).wrapping_add(t1)]
+}
+
+#[test]
+fn derived_metrics_match_reference_values() {
+    // Magnus (Sonntag): 20 °C y 50 % → rocío 9.26 °C
+    assert_close(dew_point(20.0, 50.0), 9.26, 0.01);
+    assert_close(dew_point(25.0, 100.0), 25.0, 0.01);
+    // 0 % se limita a 0.1 % en lugar de devolver -inf
+    assert!(dew_point(20.0, 0.0).is_finite());
+
+    // Tabla de la NOAA: 86 °F y 70 % → 95 °F (35.0 °C)
+    assert_close(heat_index(30.0, 70.0), 35.04, 0.01);
+    // Por debajo de 80 °F se usa la aproximación de Steadman
+    assert_close(heat_index(20.0, 50.0), 19.36, 0.01);
+    // Ajuste de la NOAA para humedad > 85 % entre 80 y 87 °F
+    assert_close(heat_index(28.0, 90.0), 34.0, 0.01);
+
+    assert_close(absolute_humidity(20.0, 50.0), 8.62, 0.01);
+    assert_close(vapour_pressure_deficit(20.0, 50.0), 1.166, 0.001);
+    assert_close(vapour_pressure_deficit(20.0, 100.0), 0.0, 1e-6);
+}
+
+#[test]
+fn psychrometric_sensor_wraps_any_climate_source() {
+    let mut sensor = PsychrometricSensor::new(FakeClimate(vec![
+        Ok(ClimateReading { temperature: 20.0, humidity: 50.0 }),
+        Ok(ClimateReading { temperature: 20.0, humidity: 50.0 }),
+        Err(SensorError::Timeout),
+    ]));
+
+    let m = sensor.read_metrics().unwrap();
+    assert_eq!((m.temperature, m.humidity), (20.0, 50.0));
+    assert_close(m.dew_point, 9.26, 0.01);
+
+    assert_eq!(
+        sensor.read().unwrap(),
+        SensorOutput::Text(
+            "Temp: 20.0°C, Hum: 50.0%, Rocío: 9.3°C, IC: 19.4°C, HA: 8.6 g/m³, VPD: 1.17 kPa".into()
+        )
+    );
+    assert_eq!(sensor.read(), Err(SensorError::Timeout));
+}
+
+#[test]
+fn dht22_decodes_sign_and_magnitude_temperatures() {
+    let reading = Dht22Sensor::decode_frame(&dht22_frame(652, 251)).unwrap();
+    assert_eq!(reading, ClimateReading { temperature: 25.1, humidity: 65.2 });
+
+    // El bit 15 es el signo; el resto es la magnitud
+    let reading = Dht22Sensor::decode_frame(&dht22_frame(652, 0x8065)).unwrap();
+    assert_eq!(reading.temperature, -10.1);
+    let reading = Dht22Sensor::decode_frame(&dht22_frame(652, 0x8001)).unwrap();
+    assert_eq!(reading.temperature, -0.1);
+
+    let mut corrupt = dht22_frame(652, 251);
+    corrupt[4] ^= 0x01;
+    assert_eq!(Dht22Sensor::decode_frame(&corrupt), Err(SensorError::InvalidData));
+}
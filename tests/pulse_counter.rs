@@ -0,0 +1,128 @@
+//! Pruebas del conteo de pulsos sin GPIO real: anti-rebote sobre un pin simulado,
+//! tasa en una ventana, conversión calibrada y persistencia del total.
+
+mod common;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::{MockPin, Waveform};
+use embedded_hal::digital::v2::InputPin;
+use lince::devices::sensors::pulse_counter::{PulseCalibration, PulseCounterSensor};
+use lince::drivers::edge::Debouncer;
+
+/// Sondea `pin` durante `window` y cuenta sus flancos de bajada en `counter`,
+/// filtrados por `debouncer`, como hace el hilo de interrupciones de `EdgeCounter`.
+fn count_falling_edges(pin: &MockPin, mut debouncer: Debouncer, counter: &AtomicU64, window: Duration) {
+    let start = Instant::now();
+    let mut previous = pin.is_high().unwrap();
+    while start.elapsed() < window {
+        let level = pin.is_high().unwrap();
+        if previous && !level && debouncer.accept(Instant::now()) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+        previous = level;
+    }
+}
+
+/// Contacto reed con pull-up: cierra tres veces y rebota 1 ms en cada cierre.
+fn bouncing_contact() -> Waveform {
+    let ms = Duration::from_millis;
+    let mut waveform = Waveform::new(true);
+    for _ in 0..3 {
+        waveform = waveform
+            .then(true, ms(10))
+            .then(false, ms(1))
+            .then(true, ms(1))
+            .then(false, ms(10));
+    }
+    waveform
+}
+
+#[test]
+fn debouncer_rejects_edges_inside_the_window() {
+    let t0 = Instant::now();
+    let ms = Duration::from_millis;
+    let mut debouncer = Debouncer::new(ms(5));
+    assert!(debouncer.accept(t0));
+    assert!(!debouncer.accept(t0 + ms(2)));
+    // Los flancos rechazados no reinician la ventana
+    assert!(!debouncer.accept(t0 + ms(4)));
+    assert!(debouncer.accept(t0 + ms(5)));
+
+    let mut disabled = Debouncer::new(Duration::ZERO);
+    assert!(disabled.accept(t0));
+    assert!(disabled.accept(t0));
+}
+
+#[test]
+fn bouncing_contact_counts_once_per_closure() {
+    let window = Duration::from_millis(80);
+
+    let pin = MockPin::new(true);
+    pin.play(bouncing_contact());
+    let counter = AtomicU64::new(0);
+    count_falling_edges(&pin, Debouncer::new(Duration::from_millis(5)), &counter, window);
+    assert_eq!(counter.load(Ordering::Relaxed), 3);
+
+    // Sin anti-rebote, cada rebote cuenta como un pulso más
+    pin.play(bouncing_contact());
+    let counter = AtomicU64::new(0);
+    count_falling_edges(&pin, Debouncer::new(Duration::ZERO), &counter, window);
+    assert_eq!(counter.load(Ordering::Relaxed), 6);
+}
+
+#[test]
+fn rate_is_measured_over_the_window_since_the_last_read() {
+    let counter = Arc::new(AtomicU64::new(7));
+    let mut sensor = PulseCounterSensor::with_source(Arc::clone(&counter), PulseCalibration::rain_gauge(0.2));
+
+    counter.fetch_add(10, Ordering::Relaxed);
+    thread::sleep(Duration::from_millis(200));
+    let reading = sensor.read_pulses().unwrap();
+    // Los pulsos previos a crear el sensor cuentan en el total, pero no en la tasa
+    assert_eq!(reading.total_pulses, 17);
+    assert!((reading.total - 3.4).abs() < 1e-9);
+    assert!((20.0..=50.0).contains(&reading.pulses_per_second), "{}", reading.pulses_per_second);
+    assert!((reading.rate - reading.pulses_per_second * 0.2 * 3600.0).abs() < 1e-9);
+
+    // Sin pulsos nuevos la tasa de la siguiente ventana es cero
+    thread::sleep(Duration::from_millis(20));
+    let reading = sensor.read_pulses().unwrap();
+    assert_eq!(reading.total_pulses, 17);
+    assert_eq!(reading.pulses_per_second, 0.0);
+}
+
+#[test]
+fn total_survives_restarts_and_resets() {
+    let path = std::env::temp_dir().join(format!("lince-pulses-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let counter = Arc::new(AtomicU64::new(0));
+    let mut sensor = PulseCounterSensor::with_source(Arc::clone(&counter), PulseCalibration::raw())
+        .with_persistence(&path)
+        .unwrap();
+    counter.store(45, Ordering::Relaxed);
+    assert_eq!(sensor.read_pulses().unwrap().total_pulses, 45);
+
+    // Tras reiniciar, la nueva fuente empieza en 5: el total guardado no se duplica
+    let counter = Arc::new(AtomicU64::new(5));
+    let mut sensor = PulseCounterSensor::with_source(Arc::clone(&counter), PulseCalibration::raw())
+        .with_persistence(&path)
+        .unwrap();
+    assert_eq!(sensor.total_pulses(), 45);
+    counter.fetch_add(3, Ordering::Relaxed);
+    assert_eq!(sensor.read_pulses().unwrap().total_pulses, 48);
+
+    sensor.reset_total().unwrap();
+    assert_eq!(sensor.total_pulses(), 0);
+    counter.fetch_add(2, Ordering::Relaxed);
+    assert_eq!(sensor.total_pulses(), 2);
+
+    std::fs::write(&path, "no es json").unwrap();
+    let result = PulseCounterSensor::with_source(counter, PulseCalibration::raw()).with_persistence(&path);
+    assert!(matches!(result, Err(lince::core::SensorError::InvalidData)));
+    std::fs::remove_file(&path).unwrap();
+}
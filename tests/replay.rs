@@ -0,0 +1,130 @@
+//! Pruebas de ida y vuelta entre `TraceRecorder`/`RecordingSensor` y `ReplaySensor`
+//! sobre archivos temporales, incluida la temporización de la reproducción.
+
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::replay::{RecordingSensor, ReplaySensor, ReplayTiming, TraceRecord, TraceRecorder};
+
+/// Sensor que devuelve una secuencia fija de resultados.
+struct Scripted(Vec<Result<SensorOutput, SensorError>>);
+
+impl Sensor for Scripted {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<SensorOutput, SensorError> {
+        self.0.remove(0)
+    }
+}
+
+fn trace_path(test: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("lince-replay-{}-{}.jsonl", test, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn results() -> Vec<Result<SensorOutput, SensorError>> {
+    vec![
+        Ok(SensorOutput::Text("Temp: 24.3°C, Hum: 58.2%".into())),
+        Err(SensorError::Timeout),
+        Ok(SensorOutput::Float(21.5)),
+        Ok(SensorOutput::Bytes(vec![2, 76, 0, 243, 65])),
+        Err(SensorError::DeviceFault),
+    ]
+}
+
+#[test]
+fn recorded_trace_replays_same_results_and_errors() {
+    let path = trace_path("roundtrip");
+    let mut sensor = RecordingSensor::new(Scripted(results()), TraceRecorder::create(&path).unwrap());
+    let live: Vec<_> = (0..5).map(|_| sensor.read()).collect();
+    assert_eq!(live, results());
+
+    let mut replay = ReplaySensor::from_file(&path, ReplayTiming::Immediate).unwrap();
+    assert_eq!(replay.len(), 5);
+    let replayed: Vec<_> = (0..5).map(|_| replay.read()).collect();
+    assert_eq!(replayed, results());
+    assert!(replay.is_finished());
+    assert_eq!(replay.read(), Err(SensorError::IoError));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn append_continues_relative_time() {
+    let path = trace_path("append");
+    let mut recorder = TraceRecorder::create(&path).unwrap();
+    recorder.record(&Ok(SensorOutput::Float(1.0))).unwrap();
+    thread::sleep(Duration::from_millis(60));
+    recorder.record(&Ok(SensorOutput::Float(2.0))).unwrap();
+    drop(recorder);
+
+    let mut recorder = TraceRecorder::append(&path).unwrap();
+    recorder.record(&Ok(SensorOutput::Float(3.0))).unwrap();
+    thread::sleep(Duration::from_millis(60));
+    recorder.record(&Ok(SensorOutput::Float(4.0))).unwrap();
+    drop(recorder);
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let elapsed: Vec<u64> = contents
+        .lines()
+        .map(|l| serde_json::from_str::<TraceRecord>(l).unwrap().elapsed_ms)
+        .collect();
+    assert_eq!(elapsed.len(), 4);
+    assert!(elapsed.windows(2).all(|w| w[0] <= w[1]), "{:?}", elapsed);
+    assert!(elapsed[3] >= elapsed[1] + 60, "{:?}", elapsed);
+
+    // La sesión añadida conserva su pausa al reproducirse
+    let mut replay = ReplaySensor::from_file(&path, ReplayTiming::Original).unwrap();
+    let start = Instant::now();
+    for _ in 0..4 {
+        replay.read().unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(115));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn timing_modes_scale_the_original_intervals() {
+    let records = || {
+        vec![
+            TraceRecord { elapsed_ms: 1000, unix_ms: 0, result: Ok(SensorOutput::Float(1.0)) },
+            TraceRecord { elapsed_ms: 1200, unix_ms: 0, result: Ok(SensorOutput::Float(2.0)) },
+        ]
+    };
+    let duration = |timing| {
+        let mut sensor = ReplaySensor::from_records(records(), timing);
+        let start = Instant::now();
+        sensor.read().unwrap();
+        sensor.read().unwrap();
+        start.elapsed()
+    };
+
+    assert!(duration(ReplayTiming::Original) >= Duration::from_millis(200));
+    let fast = duration(ReplayTiming::Accelerated(10.0));
+    assert!(fast >= Duration::from_millis(20) && fast < Duration::from_millis(200), "{:?}", fast);
+
+    // Factores degenerados: sin pánico y sin esperas
+    for speed in [1e-30, f32::MIN_POSITIVE, f32::NAN, f32::INFINITY, 0.0, -1.0] {
+        assert!(duration(ReplayTiming::Accelerated(speed)) < Duration::from_millis(100), "{}", speed);
+    }
+}
+
+#[test]
+fn looping_restarts_the_trace() {
+    let records = vec![
+        TraceRecord { elapsed_ms: 0, unix_ms: 0, result: Ok(SensorOutput::Float(1.0)) },
+        TraceRecord { elapsed_ms: 10, unix_ms: 0, result: Err(SensorError::Timeout) },
+    ];
+    let mut sensor = ReplaySensor::from_records(records, ReplayTiming::Immediate).with_loop(true);
+    for _ in 0..3 {
+        assert_eq!(sensor.read(), Ok(SensorOutput::Float(1.0)));
+        assert_eq!(sensor.read(), Err(SensorError::Timeout));
+    }
+    assert!(!sensor.is_finished());
+    assert_eq!(ReplaySensor::from_records(vec![], ReplayTiming::Immediate).with_loop(true).read(), Err(SensorError::IoError));
+}
@@ -0,0 +1,112 @@
+//! Pruebas del SenseAir S8 sobre un puerto serie simulado que responde como un
+//! esclavo Modbus RTU con un mapa de registros en memoria.
+
+mod common;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use common::FakeSerial;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::senseair_s8::{crc16, SenseairS8Sensor, ANY_ADDRESS};
+
+/// Mapa de registros del sensor: (función de lectura, dirección) → valor.
+type Registers = Rc<RefCell<HashMap<(u8, u16), u16>>>;
+
+/// Añade el CRC-16/MODBUS al final de `body`.
+fn with_crc(mut body: Vec<u8>) -> Vec<u8> {
+    let crc = crc16(&body);
+    body.extend_from_slice(&crc.to_le_bytes());
+    body
+}
+
+/// Esclavo Modbus RTU con las funciones 0x03, 0x04 y 0x06 del S8. Un registro ausente
+/// produce la excepción 0x02 (dirección ilegal). Si `corrupt` está activo, invierte un
+/// bit del CRC de cada respuesta.
+fn s8(registers: Registers, corrupt: bool) -> SenseairS8Sensor<FakeSerial> {
+    let port = FakeSerial::new(move |frame| {
+        assert_eq!(crc16(&frame[..frame.len() - 2]).to_le_bytes(), frame[frame.len() - 2..]);
+        let (unit, function) = (frame[0], frame[1]);
+        let address = u16::from_be_bytes([frame[2], frame[3]]);
+        let value = u16::from_be_bytes([frame[4], frame[5]]);
+        let mut map = registers.borrow_mut();
+        let mut response = match function {
+            0x03 | 0x04 => match map.get(&(function, address)) {
+                Some(word) => {
+                    let [hi, lo] = word.to_be_bytes();
+                    with_crc(vec![unit, function, 2, hi, lo])
+                }
+                None => with_crc(vec![unit, function | 0x80, 0x02]),
+            },
+            0x06 => {
+                map.insert((0x03, address), value);
+                frame.to_vec()
+            }
+            _ => with_crc(vec![unit, function | 0x80, 0x01]),
+        };
+        if corrupt {
+            *response.last_mut().unwrap() ^= 0x01;
+        }
+        response
+    });
+    let mut sensor = SenseairS8Sensor::with_port(port, ANY_ADDRESS);
+    sensor.set_warmup(Duration::ZERO);
+    sensor
+}
+
+fn registers(entries: &[((u8, u16), u16)]) -> Registers {
+    Rc::new(RefCell::new(entries.iter().copied().collect()))
+}
+
+#[test]
+fn co2_and_status_are_read_from_input_registers() {
+    let map = registers(&[((0x04, 0x0003), 645), ((0x04, 0x0000), 0x0004)]);
+    let mut sensor = s8(map, false);
+    assert_eq!(sensor.read(), Ok(SensorOutput::Int(645)));
+    assert_eq!(sensor.read_status(), Ok(0x0004));
+
+    let written = sensor.release().written;
+    // Trama de lectura de CO2 del manual del S8
+    assert_eq!(written[0], vec![0xFE, 0x04, 0x00, 0x03, 0x00, 0x01, 0xD5, 0xC5]);
+    assert_eq!(written[1], with_crc(vec![0xFE, 0x04, 0x00, 0x00, 0x00, 0x01]));
+}
+
+#[test]
+fn abc_period_is_written_and_read_back_from_holding_registers() {
+    let map = registers(&[]);
+    let mut sensor = s8(Rc::clone(&map), false);
+    sensor.set_abc(true).unwrap();
+    assert_eq!(sensor.abc_period(), Ok(180));
+    sensor.set_abc_period(0).unwrap();
+    assert_eq!(sensor.abc_period(), Ok(0));
+    assert_eq!(map.borrow().get(&(0x03, 0x001F)), Some(&0));
+}
+
+#[test]
+fn zero_calibration_checks_the_acknowledgement() {
+    // El esclavo simulado no pone el bit de confirmación por sí solo
+    let map = registers(&[((0x03, 0x0000), 0)]);
+    let mut sensor = s8(Rc::clone(&map), false);
+    assert_eq!(sensor.calibrate_zero(), Err(SensorError::DeviceFault));
+    assert_eq!(map.borrow().get(&(0x03, 0x0001)), Some(&0x7C06));
+}
+
+#[test]
+fn warmup_silence_bad_crc_and_exceptions_are_errors() {
+    let mut warming = SenseairS8Sensor::with_port(FakeSerial::new(|_| Vec::new()), ANY_ADDRESS);
+    assert_eq!(warming.read(), Err(SensorError::InitializationError));
+
+    let mut silent = SenseairS8Sensor::with_port(FakeSerial::new(|_| Vec::new()), ANY_ADDRESS);
+    silent.set_warmup(Duration::ZERO);
+    assert_eq!(silent.read(), Err(SensorError::Timeout));
+
+    let mut corrupt = s8(registers(&[((0x04, 0x0003), 645)]), true);
+    assert_eq!(corrupt.read(), Err(SensorError::InvalidData));
+
+    // Excepción Modbus del sensor
+    let mut missing = s8(registers(&[]), false);
+    assert_eq!(missing.read(), Err(SensorError::DeviceFault));
+}
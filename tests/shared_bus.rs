@@ -0,0 +1,152 @@
+//! Pruebas del gestor de bus compartido con varios dispositivos simulados en un mismo
+//! bus I2C y accesos desde hilos distintos, y del chip select de los proxies SPI.
+
+mod common;
+
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use common::{MockBus, MockI2c, MockPin};
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+use embedded_hal::blocking::spi;
+use lince::core::SensorError;
+use lince::drivers::mcp23017::Mcp23017;
+use lince::drivers::shared_bus::SharedBus;
+
+/// MCP23017 simulado en estado de reset: todos los pines como entradas.
+fn mcp23017(address: u8) -> MockI2c {
+    let mut i2c = MockI2c::new(address);
+    i2c.set(0x00, &[0xFF, 0xFF]);
+    i2c
+}
+
+#[test]
+fn devices_in_separate_threads_share_the_bus() {
+    let bus = SharedBus::new(MockBus::default().with(mcp23017(0x20)).with(mcp23017(0x21)));
+
+    let handles: Vec<_> = [(0x20u8, 0x00FFu16), (0x21, 0xAA55)]
+        .into_iter()
+        .map(|(address, value)| {
+            let mut expander = Mcp23017::with_i2c(bus.proxy(), address).unwrap();
+            thread::spawn(move || {
+                for i in 0..100u16 {
+                    expander.write_outputs(value ^ i).unwrap();
+                }
+                expander.write_outputs(value).unwrap();
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut proxy = bus.proxy();
+    let olat = proxy
+        .transaction(|b| {
+            let a = &b.devices[&0x20].registers;
+            let c = &b.devices[&0x21].registers;
+            ((a[0x14], a[0x15]), (c[0x14], c[0x15]))
+        })
+        .unwrap();
+    assert_eq!(olat, ((0xFF, 0x00), (0x55, 0xAA)));
+}
+
+#[test]
+fn busy_bus_times_out_per_proxy() {
+    let bus = SharedBus::new(MockBus::default().with(mcp23017(0x20)));
+    let mut holder = bus.proxy();
+    let mut impatient = bus.proxy_with_timeout(Duration::from_millis(20));
+    let mut patient = bus.proxy_with_timeout(Duration::from_secs(2));
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let worker = thread::spawn(move || {
+        holder
+            .transaction(|_| {
+                tx.send(()).unwrap();
+                thread::sleep(Duration::from_millis(200));
+            })
+            .unwrap();
+    });
+    rx.recv().unwrap();
+
+    let mut buf = [0u8; 2];
+    assert_eq!(impatient.write_read(0x20, &[0x00], &mut buf), Err(SensorError::Timeout));
+    patient.write_read(0x20, &[0x00], &mut buf).unwrap();
+    assert_eq!(buf, [0xFF, 0xFF]);
+    worker.join().unwrap();
+}
+
+#[test]
+fn bus_is_returned_after_panic_and_errors_map_to_io() {
+    let bus = SharedBus::new(MockBus::default().with(mcp23017(0x20)));
+    let mut proxy = bus.proxy();
+
+    let mut crashing = bus.proxy();
+    let result = thread::spawn(move || crashing.transaction(|_| panic!("fallo del driver"))).join();
+    assert!(result.is_err());
+
+    // El bus sigue disponible y un NACK llega como IoError
+    let mut buf = [0u8; 1];
+    assert_eq!(proxy.read(0x30, &mut buf), Err(SensorError::IoError));
+    proxy.write(0x20, &[0x14, 0x0F]).unwrap();
+}
+
+/// Transferencias registradas: palabras enviadas y chip select activos en ese momento.
+type SpiLog = Arc<Mutex<Vec<(Vec<u8>, Vec<bool>)>>>;
+
+/// Bus SPI simulado: registra cada transferencia junto con los chip select activos.
+struct FakeSpi {
+    chip_selects: Vec<MockPin>,
+    log: SpiLog,
+}
+
+impl FakeSpi {
+    fn record(&self, words: &[u8]) {
+        let selected = self.chip_selects.iter().map(|cs| !cs.level()).collect();
+        self.log.lock().unwrap().push((words.to_vec(), selected));
+    }
+}
+
+impl spi::Transfer<u8> for FakeSpi {
+    type Error = Infallible;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.record(words);
+        words.iter_mut().for_each(|w| *w = !*w);
+        Ok(words)
+    }
+}
+
+impl spi::Write<u8> for FakeSpi {
+    type Error = Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.record(words);
+        Ok(())
+    }
+}
+
+#[test]
+fn spi_proxies_select_only_their_device() {
+    let (cs0, cs1) = (MockPin::new(false), MockPin::new(false));
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let bus = SharedBus::new(FakeSpi { chip_selects: vec![cs0.clone(), cs1.clone()], log: log.clone() });
+
+    let mut adc = bus.spi_proxy(cs0.clone()).unwrap();
+    let mut display = bus.spi_proxy(cs1.clone()).unwrap();
+    // Ambos chip select quedan desactivados (en alto) al crear los proxies
+    assert!(cs0.level() && cs1.level());
+
+    let mut words = [0x01, 0x80];
+    assert_eq!(spi::Transfer::transfer(&mut adc, &mut words), Ok(&[0xFE, 0x7F][..]));
+    spi::Write::write(&mut display, &[0xAE]).unwrap();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![(vec![0x01, 0x80], vec![true, false]), (vec![0xAE], vec![false, true])]
+    );
+    assert_eq!(cs0.writes(), vec![true, false, true]);
+    assert_eq!(cs1.writes(), vec![true, false, true]);
+}
@@ -0,0 +1,147 @@
+//! Pruebas del driver SHT3x/SHT4x con un sensor I2C simulado orientado a comandos:
+//! bytes enviados, validación de CRC y conversiones de ambas familias.
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::assert_close;
+use embedded_hal::blocking::i2c::{Read, Write};
+use lince::core::traits::climate::ClimateSensor;
+use lince::core::traits::sensor::Sensor;
+use lince::core::SensorError;
+use lince::devices::sensors::sht::{crc8, PeriodicRate, Repeatability, ShtMode, ShtModel, ShtSensor, DEFAULT_ADDRESS};
+
+/// Error del sensor simulado (NACK).
+#[derive(Debug)]
+struct Nack;
+
+/// Sensor Sensirion simulado: responde a cada lectura según el último comando recibido.
+#[derive(Default)]
+struct FakeSht {
+    /// Comandos recibidos, en orden.
+    commands: Vec<Vec<u8>>,
+    /// Respuesta de 6 bytes por comando.
+    responses: HashMap<Vec<u8>, [u8; 6]>,
+}
+
+impl FakeSht {
+    fn respond(mut self, command: &[u8], response: [u8; 6]) -> Self {
+        self.responses.insert(command.to_vec(), response);
+        self
+    }
+}
+
+impl Write for FakeSht {
+    type Error = Nack;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Nack> {
+        if address != DEFAULT_ADDRESS {
+            return Err(Nack);
+        }
+        self.commands.push(bytes.to_vec());
+        Ok(())
+    }
+}
+
+impl Read for FakeSht {
+    type Error = Nack;
+
+    fn read(&mut self, _address: u8, buffer: &mut [u8]) -> Result<(), Nack> {
+        let last = self.commands.last().ok_or(Nack)?;
+        buffer.copy_from_slice(self.responses.get(last).ok_or(Nack)?);
+        Ok(())
+    }
+}
+
+/// Respuesta de dos palabras con sus CRC.
+fn words(w0: u16, w1: u16) -> [u8; 6] {
+    let [a, b] = w0.to_be_bytes();
+    let [c, d] = w1.to_be_bytes();
+    [a, b, crc8(&[a, b]), c, d, crc8(&[c, d])]
+}
+
+#[test]
+fn sht3x_commands_and_conversion() {
+    let fake = FakeSht::default()
+        .respond(&[0x37, 0x80], words(0x1A2B, 0x3C4D))
+        .respond(&[0x24, 0x00], words(0x6666, 0x8000))
+        .respond(&[0x24, 0x16], words(0x0000, 0xFFFF));
+    let mut sensor = ShtSensor::with_i2c(fake, DEFAULT_ADDRESS, ShtModel::Sht31).unwrap();
+    assert_eq!(sensor.identity(), "sht31-1a2b3c4d");
+
+    let reading = sensor.read_climate().unwrap();
+    assert_close(reading.temperature, 25.0, 0.01); // -45 + 175 · 0.4
+    assert_close(reading.humidity, 50.0, 0.01);
+
+    sensor.set_repeatability(Repeatability::Low).unwrap();
+    let reading = sensor.read_climate().unwrap();
+    assert_close(reading.temperature, -45.0, 0.01);
+    assert_close(reading.humidity, 100.0, 0.01);
+
+    let commands = sensor.release().commands;
+    assert_eq!(commands, vec![vec![0x30, 0xA2], vec![0x37, 0x80], vec![0x24, 0x00], vec![0x24, 0x16]]);
+}
+
+#[test]
+fn sht3x_periodic_mode_and_heater() {
+    let fake = FakeSht::default()
+        .respond(&[0x37, 0x80], words(1, 2))
+        .respond(&[0xE0, 0x00], words(0x6666, 0x4000));
+    let mut sensor = ShtSensor::with_i2c(fake, DEFAULT_ADDRESS, ShtModel::Sht35).unwrap();
+
+    sensor.set_mode(ShtMode::Periodic(PeriodicRate::Mps1)).unwrap();
+    assert_close(sensor.read_climate().unwrap().humidity, 25.0, 0.01);
+    sensor.set_mode(ShtMode::SingleShot).unwrap();
+    sensor.set_heater(true).unwrap();
+    sensor.set_heater(false).unwrap();
+
+    let commands = sensor.release().commands;
+    assert_eq!(
+        commands[2..],
+        [vec![0x21, 0x30], vec![0xE0, 0x00], vec![0x30, 0x93], vec![0x30, 0x6D], vec![0x30, 0x66]]
+    );
+}
+
+#[test]
+fn sht4x_commands_and_clamped_humidity() {
+    let fake = FakeSht::default()
+        .respond(&[0x89], words(0x0BAD, 0xBEEF))
+        .respond(&[0xFD], words(0x6666, 0xFFFF))
+        .respond(&[0xE0], words(0x6666, 0x0000));
+    let mut sensor = ShtSensor::with_i2c(fake, DEFAULT_ADDRESS, ShtModel::Sht40).unwrap();
+    assert_eq!(sensor.serial_number(), 0x0BAD_BEEF);
+
+    // -6 + 125 · RH: los extremos se recortan a 0–100 %
+    assert_close(sensor.read_climate().unwrap().humidity, 100.0, 0.01);
+    sensor.set_repeatability(Repeatability::Low).unwrap();
+    assert_close(sensor.read_climate().unwrap().humidity, 0.0, 0.01);
+
+    // Sin modo periódico ni calentador continuo en el SHT4x
+    assert_eq!(sensor.set_mode(ShtMode::Periodic(PeriodicRate::Mps1)), Err(SensorError::InitializationError));
+    assert_eq!(sensor.set_heater(true), Err(SensorError::InitializationError));
+
+    let commands = sensor.release().commands;
+    assert_eq!(commands, vec![vec![0x94], vec![0x89], vec![0xFD], vec![0xE0]]);
+}
+
+#[test]
+fn bad_crc_is_rejected() {
+    let mut corrupt = words(0x6666, 0x8000);
+    corrupt[5] ^= 0xFF;
+
+    let fake = FakeSht::default().respond(&[0x37, 0x80], corrupt);
+    assert!(matches!(
+        ShtSensor::with_i2c(fake, DEFAULT_ADDRESS, ShtModel::Sht31),
+        Err(SensorError::InvalidData)
+    ));
+
+    let fake = FakeSht::default()
+        .respond(&[0x37, 0x80], words(1, 2))
+        .respond(&[0x24, 0x00], corrupt);
+    let mut sensor = ShtSensor::with_i2c(fake, DEFAULT_ADDRESS, ShtModel::Sht31).unwrap();
+    assert_eq!(sensor.read(), Err(SensorError::InvalidData));
+
+    // Sin respuesta en la dirección: error de bus
+    assert!(matches!(ShtSensor::with_i2c(FakeSht::default(), 0x45, ShtModel::Sht31), Err(SensorError::IoError)));
+}
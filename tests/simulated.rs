@@ -0,0 +1,85 @@
+//! Pruebas de los sensores simulados con generadores de semilla fija: tasas de fallo
+//! inyectadas, límites del camino aleatorio y perfil sinusoidal.
+
+use std::time::Duration;
+
+use lince::core::traits::climate::ClimateSensor;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::simulated::{
+    FailureInjection, SimulatedClimateSensor, SimulatedRainSensor, SineWaveSensor,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SAMPLES: usize = 10_000;
+
+#[test]
+fn failure_rates_match_configured_probabilities() {
+    let mut sensor = SimulatedClimateSensor::new(22.0, 55.0)
+        .with_failures(FailureInjection::new(0.1, 0.2))
+        .with_seed(7);
+
+    let (mut timeouts, mut invalid) = (0, 0);
+    for _ in 0..SAMPLES {
+        match sensor.read() {
+            Err(SensorError::Timeout) => timeouts += 1,
+            Err(SensorError::InvalidData) => invalid += 1,
+            Err(e) => panic!("error inesperado: {:?}", e),
+            Ok(_) => {}
+        }
+    }
+    let rate = |n: usize| n as f64 / SAMPLES as f64;
+    assert!((rate(timeouts) - 0.1).abs() < 0.02, "timeouts: {}", rate(timeouts));
+    assert!((rate(invalid) - 0.2).abs() < 0.02, "invalid: {}", rate(invalid));
+
+    // Sin fallos y con fallo seguro; las probabilidades se recortan a 0–1
+    let mut sano = SineWaveSensor::new(0.0, 1.0, Duration::from_secs(1)).with_seed(1);
+    assert!((0..1000).all(|_| sano.read().is_ok()));
+    let mut roto = SimulatedRainSensor::new(0.5).with_failures(FailureInjection::new(3.0, 0.0)).with_seed(1);
+    assert!((0..1000).all(|_| roto.read() == Err(SensorError::Timeout)));
+}
+
+#[test]
+fn same_seed_gives_same_series() {
+    let series = |rng: StdRng| {
+        let mut sensor = SimulatedClimateSensor::new(22.0, 55.0).with_rng(rng);
+        (0..50).map(|_| sensor.read().unwrap()).collect::<Vec<_>>()
+    };
+    assert_eq!(series(StdRng::seed_from_u64(3)), series(StdRng::seed_from_u64(3)));
+    assert_ne!(series(StdRng::seed_from_u64(3)), series(StdRng::seed_from_u64(4)));
+}
+
+#[test]
+fn random_walk_steps_are_bounded_and_clamped() {
+    let mut sensor = SimulatedClimateSensor::new(79.0, 99.0).with_steps(0.5, 2.0).with_seed(11);
+    let mut previous = sensor.read_climate().unwrap();
+    for _ in 0..SAMPLES {
+        let reading = sensor.read_climate().unwrap();
+        assert!((reading.temperature - previous.temperature).abs() <= 0.5 + 1e-4);
+        assert!((reading.humidity - previous.humidity).abs() <= 2.0 + 1e-4);
+        assert!((-40.0..=80.0).contains(&reading.temperature));
+        assert!((0.0..=100.0).contains(&reading.humidity));
+        previous = reading;
+    }
+}
+
+#[test]
+fn sine_wave_follows_profile_within_noise() {
+    let sensor = SineWaveSensor::new(20.0, 5.0, Duration::from_secs(4));
+    let at = |ms| sensor.value_at(Duration::from_millis(ms));
+    assert!((at(0) - 20.0).abs() < 1e-5);
+    assert!((at(1000) - 25.0).abs() < 1e-5);
+    assert!((at(3000) - 15.0).abs() < 1e-5);
+    assert!((at(4000) - 20.0).abs() < 1e-4);
+    assert_eq!(SineWaveSensor::new(3.0, 5.0, Duration::ZERO).value_at(Duration::from_secs(1)), 3.0);
+
+    // Periodo muy largo: la señal apenas cambia y el ruido queda dentro de ±noise
+    let mut noisy = SineWaveSensor::new(20.0, 5.0, Duration::from_secs(86_400)).with_noise(0.5).with_seed(5);
+    for _ in 0..1000 {
+        match noisy.read().unwrap() {
+            SensorOutput::Float(v) => assert!((v - 20.0).abs() <= 0.51, "{}", v),
+            other => panic!("salida inesperada: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::TempRoot;
+use lince::core::traits::climate::ClimateSensor;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::sysfs::{
+    discover, discover_hwmon, discover_iio, Quantity, SysfsClimateSensor, SysfsSensor, SysfsSubsystem,
+};
+
+fn write(dir: &Path, file: &str, contents: &str) {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join(file), contents).unwrap();
+}
+
+#[test]
+fn hwmon_channels_are_scaled_and_labelled() {
+    let root = TempRoot::new("sysfs-hwmon");
+    let dev = root.join("sys/class/hwmon/hwmon0");
+    write(&dev, "name", "cpu_thermal\n");
+    write(&dev, "temp1_input", "51540\n");
+    write(&dev, "temp1_label", "CPU\n");
+    write(&dev, "in0_input", "3300\n");
+    write(&dev, "update_interval", "1000\n");
+
+    let channels = discover_hwmon(&root);
+    assert_eq!(channels.len(), 2);
+
+    let temp = channels.iter().find(|c| c.channel == "temp1").unwrap();
+    assert_eq!(temp.subsystem, SysfsSubsystem::Hwmon);
+    assert_eq!(temp.quantity, Quantity::Temperature);
+    assert_eq!(temp.display_name(), "CPU");
+    assert!((temp.read_value().unwrap() - 51.54).abs() < 1e-9);
+
+    let mut volts = SysfsSensor::find(&root, "cpu_thermal", "in0").unwrap();
+    assert_eq!(volts.read(), Ok(SensorOutput::Float(3.3)));
+}
+
+#[test]
+fn iio_raw_channels_apply_offset_and_scale() {
+    let root = TempRoot::new("sysfs-iio");
+    let dev = root.join("sys/bus/iio/devices/iio:device0");
+    write(&dev, "name", "ads1015\n");
+    write(&dev, "in_voltage0_raw", "1000\n");
+    write(&dev, "in_voltage_scale", "2.000000\n");
+    write(&dev, "in_voltage1_raw", "10\n");
+    write(&dev, "in_voltage1_scale", "3.0\n");
+    write(&dev, "in_voltage1_offset", "-5\n");
+    write(&dev, "in_temp_raw", "100\n");
+    write(&dev, "in_temp_input", "25000\n");
+
+    let channels = discover_iio(&root);
+    assert_eq!(channels.len(), 3);
+
+    let v0 = channels.iter().find(|c| c.channel == "voltage0").unwrap();
+    assert!((v0.read_value().unwrap() - 2.0).abs() < 1e-9); // 1000 · 2 mV
+    let v1 = channels.iter().find(|c| c.channel == "voltage1").unwrap();
+    assert!((v1.read_value().unwrap() - 0.015).abs() < 1e-9); // (10 − 5) · 3 mV
+    let t = channels.iter().find(|c| c.channel == "temp").unwrap();
+    assert!(t.value_path.ends_with("in_temp_input"));
+    assert!((t.read_value().unwrap() - 25.0).abs() < 1e-9);
+}
+
+#[test]
+fn kernel_dht11_is_a_climate_sensor() {
+    let root = TempRoot::new("sysfs-dht11");
+    let dev = root.join("sys/bus/iio/devices/iio:device1");
+    write(&dev, "name", "dht11\n");
+    write(&dev, "in_temp_input", "23000\n");
+    write(&dev, "in_humidityrelative_input", "41000\n");
+
+    assert_eq!(discover(&root).len(), 2);
+    let mut sensor = SysfsClimateSensor::find(&root, "dht11").unwrap();
+    let r = sensor.read_climate().unwrap();
+    assert_eq!((r.temperature, r.humidity), (23.0, 41.0));
+    assert_eq!(sensor.read(), Ok(SensorOutput::Text("Temp: 23.0°C, Hum: 41.0%".into())));
+
+    write(&dev, "in_temp_input", "garbage\n");
+    assert_eq!(sensor.read_climate(), Err(SensorError::InvalidData));
+
+    assert!(matches!(
+        SysfsClimateSensor::find(&root, "bme280"),
+        Err(SensorError::InitializationError)
+    ));
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::TempRoot;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::system::SystemSensor;
+
+/// Crea un árbol `/sys` + `/proc` falso en un directorio temporal único.
+fn fake_root(name: &str) -> TempRoot {
+    let root = TempRoot::new(&format!("system-{}", name));
+    fs::create_dir_all(root.join("proc")).unwrap();
+    fs::write(root.join("proc/loadavg"), "0.42 0.35 0.30 1/123 4567\n").unwrap();
+    fs::write(
+        root.join("proc/meminfo"),
+        "MemTotal:        4000000 kB\nMemFree:          500000 kB\nMemAvailable:    3000000 kB\n",
+    )
+    .unwrap();
+    root
+}
+
+fn add_zone(root: &Path, zone: u32, millis: i64) {
+    let dir = root.join(format!("sys/class/thermal/thermal_zone{}", zone));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("temp"), format!("{}\n", millis)).unwrap();
+}
+
+#[test]
+fn reads_metrics_from_fake_tree() {
+    let root = fake_root("metrics");
+    add_zone(&root, 0, 52_123);
+    add_zone(&root, 1, 48_000);
+    let firmware = root.join("sys/devices/platform/soc/soc:firmware");
+    fs::create_dir_all(&firmware).unwrap();
+    fs::write(firmware.join("get_throttled"), "0x50005\n").unwrap();
+
+    let sensor = SystemSensor::with_root(&root);
+    let m = sensor.read_metrics().unwrap();
+
+    assert!((m.cpu_temperature.unwrap() - 52.123).abs() < 1e-3);
+    assert_eq!(m.load_average, [0.42, 0.35, 0.30]);
+    assert_eq!(m.memory_total_kb, 4_000_000);
+    assert_eq!(m.memory_available_kb, 3_000_000);
+    assert!((m.memory_used_percent() - 25.0).abs() < 1e-3);
+    assert!(m.disk_total_bytes > 0);
+
+    let t = m.throttle.unwrap();
+    assert!(t.under_voltage());
+    assert!(!t.frequency_capped());
+    assert!(t.throttled());
+    assert!(t.occurred_since_boot());
+}
+
+#[test]
+fn missing_optional_sources_are_reported_as_unavailable() {
+    let root = fake_root("optional");
+    let mut sensor = SystemSensor::with_root(&root);
+
+    match sensor.read().unwrap() {
+        SensorOutput::Text(text) => {
+            assert!(text.starts_with("CPU: N/D, Carga: 0.42/0.35/0.30, Mem: 25.0%"));
+            assert!(text.ends_with("Throttling: N/D"));
+        }
+        other => panic!("salida inesperada: {:?}", other),
+    }
+}
+
+#[test]
+fn malformed_proc_files_are_invalid_data() {
+    let root = fake_root("malformed");
+    fs::write(root.join("proc/loadavg"), "abc\n").unwrap();
+    assert_eq!(SystemSensor::with_root(&root).read_metrics(), Err(SensorError::InvalidData));
+
+    fs::remove_file(root.join("proc/loadavg")).unwrap();
+    assert_eq!(SystemSensor::with_root(&root).read_metrics(), Err(SensorError::IoError));
+}
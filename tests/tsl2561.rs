@@ -0,0 +1,105 @@
+//! Pruebas del TSL2561: tramos de la razón IR/visible del cálculo de lux y lectura
+//! sobre un mapa de registros simulado (identificación, escalado, saturación, auto-ajuste).
+
+mod common;
+
+use common::{assert_close, MockI2c};
+use lince::core::SensorError;
+use lince::devices::sensors::tsl2561::{
+    calculate_lux, Tsl2561Gain, Tsl2561Integration, Tsl2561Package, Tsl2561Sensor, DEFAULT_ADDRESS,
+};
+
+/// Registro TIMING tal como lo direcciona el byte de comando.
+const TIMING: u8 = 0x81;
+
+/// TSL2561 simulado con encapsulado T y las cuentas indicadas en ambos canales.
+fn mock(ch0: u16, ch1: u16) -> MockI2c {
+    let mut i2c = MockI2c::new(DEFAULT_ADDRESS);
+    i2c.set(0x8A, &[0x50]);
+    i2c.set(0xAC, &ch0.to_le_bytes());
+    i2c.set(0xAE, &ch1.to_le_bytes());
+    i2c
+}
+
+#[test]
+fn lux_formula_switches_at_each_ratio_boundary() {
+    let t = |ch1| calculate_lux(1000.0, ch1, Tsl2561Package::T);
+    // Cada límite pertenece al tramo inferior; el siguiente valor usa el tramo superior
+    assert_close(t(500.0), 6.906, 1e-3);
+    assert_close(t(510.0), 6.590, 1e-3);
+    assert_close(t(610.0), 3.490, 1e-3);
+    assert_close(t(620.0), 3.314, 1e-3);
+    assert_close(t(800.0), 0.560, 1e-3);
+    assert_close(t(810.0), 0.5528, 1e-3);
+    assert_close(t(1300.0), 0.004, 1e-3);
+    assert_eq!(t(1310.0), 0.0);
+
+    let cs = |ch1| calculate_lux(1000.0, ch1, Tsl2561Package::Cs);
+    assert_close(cs(520.0), 31.5 - 59.3 * 0.52f32.powf(1.4), 1e-3);
+    assert_close(cs(530.0), 22.9 - 0.0291 * 530.0, 1e-3);
+    assert_close(cs(650.0), 22.9 - 0.0291 * 650.0, 1e-3);
+    assert_close(cs(660.0), 15.7 - 0.0180 * 660.0, 1e-3);
+    assert_eq!(cs(1310.0), 0.0);
+
+    assert_eq!(calculate_lux(0.0, 10.0, Tsl2561Package::T), 0.0);
+}
+
+#[test]
+fn channels_are_scaled_to_402_ms_and_16x() {
+    let mut sensor = Tsl2561Sensor::with_i2c(mock(1000, 250), DEFAULT_ADDRESS).unwrap();
+    assert_eq!(sensor.package(), Tsl2561Package::T);
+    sensor.set_integration_time(Tsl2561Integration::Ms13).unwrap();
+
+    assert_eq!(sensor.read_channels(), Ok((1000, 250)));
+    let scale = 322.0 / 11.0 * 16.0;
+    let expected = calculate_lux(1000.0 * scale, 250.0 * scale, Tsl2561Package::T);
+    assert_close(sensor.read_lux().unwrap(), expected, 1e-3);
+    // Ganancia 1x, 13.7 ms
+    assert_eq!(sensor.release().written(TIMING).last(), Some(&0x00));
+
+    let mut i2c = mock(0, 0);
+    i2c.set(0x8A, &[0x10]);
+    let sensor = Tsl2561Sensor::with_i2c(i2c, DEFAULT_ADDRESS).unwrap();
+    assert_eq!(sensor.package(), Tsl2561Package::Cs);
+
+    let mut i2c = mock(0, 0);
+    i2c.set(0x8A, &[0x30]);
+    assert!(matches!(Tsl2561Sensor::with_i2c(i2c, DEFAULT_ADDRESS), Err(SensorError::InitializationError)));
+}
+
+#[test]
+fn saturated_channel_is_invalid_data() {
+    let mut sensor = Tsl2561Sensor::with_i2c(mock(5047, 100), DEFAULT_ADDRESS).unwrap();
+    sensor.set_integration_time(Tsl2561Integration::Ms13).unwrap();
+    assert_eq!(sensor.read_lux(), Err(SensorError::InvalidData));
+
+    let mut sensor = Tsl2561Sensor::with_i2c(mock(100, 5047), DEFAULT_ADDRESS).unwrap();
+    sensor.set_integration_time(Tsl2561Integration::Ms13).unwrap();
+    assert_eq!(sensor.read_lux(), Err(SensorError::InvalidData));
+}
+
+#[test]
+fn auto_range_switches_gain_on_low_signal_and_saturation() {
+    // 200 cuentas < 5047/20: sube a 16x
+    let mut sensor = Tsl2561Sensor::with_i2c(mock(200, 20), DEFAULT_ADDRESS).unwrap();
+    sensor.set_integration_time(Tsl2561Integration::Ms13).unwrap();
+    sensor.set_auto_range(true);
+    sensor.read_lux().unwrap();
+    assert_eq!(sensor.gain(), Tsl2561Gain::X16);
+    assert_eq!(sensor.release().written(TIMING).last(), Some(&0x10));
+
+    // Saturado en 16x: vuelve a 1x (y sigue saturado, por lo que la lectura falla)
+    let mut sensor = Tsl2561Sensor::with_i2c(mock(5047, 20), DEFAULT_ADDRESS).unwrap();
+    sensor.set_integration_time(Tsl2561Integration::Ms13).unwrap();
+    sensor.set_gain(Tsl2561Gain::X16).unwrap();
+    sensor.set_auto_range(true);
+    assert_eq!(sensor.read_lux(), Err(SensorError::InvalidData));
+    assert_eq!(sensor.gain(), Tsl2561Gain::X1);
+
+    // Señal suficiente en 1x: no cambia la ganancia
+    let mut sensor = Tsl2561Sensor::with_i2c(mock(300, 20), DEFAULT_ADDRESS).unwrap();
+    sensor.set_integration_time(Tsl2561Integration::Ms13).unwrap();
+    sensor.set_auto_range(true);
+    sensor.read_lux().unwrap();
+    assert_eq!(sensor.gain(), Tsl2561Gain::X1);
+}
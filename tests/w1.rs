@@ -0,0 +1,98 @@
+//! Pruebas de los dispositivos 1-Wire del kernel (DS2413, DS2438, DS2408) sobre
+//! un árbol `/sys/bus/w1/devices` falso.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::TempRoot;
+use lince::core::traits::sensor::Sensor;
+use lince::core::{SensorError, SensorOutput};
+use lince::devices::sensors::ds2408::Ds2408Sensor;
+use lince::devices::sensors::ds2413::{Ds2413Channel, Ds2413Sensor};
+use lince::devices::sensors::ds2438::Ds2438Sensor;
+
+fn write(dir: &Path, file: &str, data: &[u8]) {
+    fs::write(dir.join(file), data).unwrap();
+}
+
+#[test]
+fn ds2413_reads_pins_and_preserves_other_output() {
+    let root = TempRoot::new("w1-ds2413");
+    let dir = root.w1_device("3a-0000001c5d7e");
+    // PIOA: latch activado (0), pin bajo; PIOB: latch abierto, pin alto
+    write(&dir, "state", &[0x3C]);
+
+    let mut sw = Ds2413Sensor::with_root(&root, "3a-0000001c5d7e").unwrap();
+    let state = sw.read_state().unwrap();
+    assert!(state.output_on(Ds2413Channel::A));
+    assert!(!state.output_on(Ds2413Channel::B));
+    assert_eq!(sw.read().unwrap(), SensorOutput::Text("A: BAJO, B: ALTO".into()));
+
+    // Activar B mantiene A activada: ambos latches a 0
+    sw.set_output(Ds2413Channel::B, true).unwrap();
+    assert_eq!(fs::read(dir.join("output")).unwrap(), vec![0xFC]);
+
+    write(&dir, "state", &[0x00]);
+    assert_eq!(sw.read_state(), Err(SensorError::InvalidData));
+}
+
+#[test]
+fn ds2438_converts_kernel_units() {
+    let root = TempRoot::new("w1-ds2438");
+    let dir = root.w1_device("26-000001a2b3c4");
+    write(&dir, "temperature", b"5504\n");
+    write(&dir, "vdd", b"398\n");
+    write(&dir, "vad", b"123\n");
+    // Registro de corriente = -1024 → -1024 / (4096 · 0.05) = -5 A
+    let current = (-1024i16).to_le_bytes();
+    write(&dir, "page0", &[0x0F, 0, 0, 0, 0, current[0], current[1], 0]);
+
+    let mut bat = Ds2438Sensor::with_root(&root, "26-000001a2b3c4").unwrap();
+    assert_eq!(bat.read_current(), Err(SensorError::InitializationError));
+    bat.set_sense_resistor(Some(0.05));
+
+    let r = bat.read_measurement().unwrap();
+    assert!((r.temperature - 21.5).abs() < 1e-3);
+    assert!((r.vdd - 3.98).abs() < 1e-4);
+    assert!((r.vad - 1.23).abs() < 1e-4);
+    assert!((r.current.unwrap() + 5.0).abs() < 1e-4);
+    assert_eq!(
+        bat.read().unwrap(),
+        SensorOutput::Text("Temp: 21.50 °C, VDD: 3.98 V, VAD: 1.23 V, Corriente: -5.000 A".into())
+    );
+
+    bat.set_current_measurement(true).unwrap();
+    assert_eq!(fs::read(dir.join("iad")).unwrap(), b"1");
+}
+
+#[test]
+fn ds2408_sets_single_output() {
+    let root = TempRoot::new("w1-ds2408");
+    let dir = root.w1_device("29-0000002b5f11");
+    write(&dir, "state", &[0b1111_0111]);
+    write(&dir, "output", &[0xFF]);
+
+    let mut io = Ds2408Sensor::with_root(&root, "29-0000002b5f11").unwrap();
+    io.set_output(3, true).unwrap();
+    assert_eq!(io.read_latches().unwrap(), 0b1111_0111);
+    io.set_output(3, false).unwrap();
+    assert_eq!(io.read_latches().unwrap(), 0xFF);
+    assert_eq!(io.set_output(8, true), Err(SensorError::InvalidData));
+    assert_eq!(io.read().unwrap(), SensorOutput::Text("PIO7-0: 11110111".into()));
+}
+
+#[test]
+fn wrong_family_or_missing_device_is_rejected() {
+    let root = TempRoot::new("w1-family");
+    root.w1_device("3a-0000001c5d7e");
+    assert!(matches!(
+        Ds2408Sensor::with_root(&root, "3a-0000001c5d7e"),
+        Err(SensorError::InitializationError)
+    ));
+    assert!(matches!(
+        Ds2413Sensor::with_root(&root, "3a-000000000000"),
+        Err(SensorError::InitializationError)
+    ));
+}